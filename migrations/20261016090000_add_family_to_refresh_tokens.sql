@@ -0,0 +1,12 @@
+-- Track refresh token rotation chains so a replayed token can revoke its whole family
+ALTER TABLE refresh_tokens
+    ADD COLUMN family_id UUID,
+    ADD COLUMN parent_id UUID,
+    ADD COLUMN rotated_at TIMESTAMPTZ;
+
+-- Existing tokens start their own family
+UPDATE refresh_tokens SET family_id = id WHERE family_id IS NULL;
+
+ALTER TABLE refresh_tokens ALTER COLUMN family_id SET NOT NULL;
+
+CREATE INDEX idx_refresh_tokens_family_id ON refresh_tokens(family_id);
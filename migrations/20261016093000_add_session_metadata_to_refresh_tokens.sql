@@ -0,0 +1,8 @@
+-- Capture where a refresh token family (session) is being used from
+ALTER TABLE refresh_tokens
+    ADD COLUMN user_agent TEXT,
+    ADD COLUMN ip_address VARCHAR(45),
+    ADD COLUMN device_name VARCHAR(255),
+    ADD COLUMN last_used_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+CREATE INDEX idx_refresh_tokens_user_sessions ON refresh_tokens(user_id, user_type) WHERE rotated_at IS NULL;
@@ -0,0 +1,19 @@
+-- Individually revoked access tokens, kept until the token would expire anyway
+CREATE TABLE access_token_denylist (
+    jti VARCHAR(64) PRIMARY KEY,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_access_token_denylist_expires_at ON access_token_denylist(expires_at);
+
+-- Subjects whose access tokens issued up to `revoked_before` are revoked
+CREATE TABLE access_token_subject_denylist (
+    user_id UUID NOT NULL,
+    user_type VARCHAR(50) NOT NULL,
+    revoked_before TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, user_type)
+);
+
+CREATE INDEX idx_access_token_subject_denylist_expires_at ON access_token_subject_denylist(expires_at);
@@ -0,0 +1,14 @@
+-- Single-use tokens sent out of band, e.g. password reset links
+CREATE TABLE one_time_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    user_type VARCHAR(50) NOT NULL,
+    purpose VARCHAR(50) NOT NULL,
+    token_hash VARCHAR(255) NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_one_time_tokens_user ON one_time_tokens(user_id, user_type, purpose);
+CREATE INDEX idx_one_time_tokens_expires_at ON one_time_tokens(expires_at);
@@ -0,0 +1,21 @@
+-- TOTP second factor of administrators; unconfirmed until the first valid code
+CREATE TABLE administrator_totp (
+    administrator_id UUID PRIMARY KEY REFERENCES user_administrators(id) ON DELETE CASCADE,
+    secret VARCHAR(64) NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    -- Last accepted time step, codes of this step or earlier are rejected as replays
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Single-use recovery codes, only the SHA-256 hash is stored
+CREATE TABLE administrator_recovery_codes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    administrator_id UUID NOT NULL REFERENCES user_administrators(id) ON DELETE CASCADE,
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_administrator_recovery_codes_administrator_id ON administrator_recovery_codes(administrator_id);
@@ -0,0 +1,16 @@
+-- WebAuthn credentials (passkeys) of administrators, ES256 only
+CREATE TABLE administrator_passkeys (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    administrator_id UUID NOT NULL REFERENCES user_administrators(id) ON DELETE CASCADE,
+    -- Base64url encoded credential ID chosen by the authenticator
+    credential_id VARCHAR(1400) NOT NULL UNIQUE,
+    -- Base64url encoded uncompressed SEC1 P-256 point
+    public_key VARCHAR(255) NOT NULL,
+    -- Signature counter reported by the authenticator, used to detect cloned keys
+    sign_count BIGINT NOT NULL DEFAULT 0,
+    name VARCHAR(255) NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_administrator_passkeys_administrator_id ON administrator_passkeys(administrator_id);
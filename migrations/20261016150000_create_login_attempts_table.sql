@@ -0,0 +1,13 @@
+-- Failed login counters per account (email) and per client IP
+CREATE TABLE login_attempts (
+    scope VARCHAR(20) NOT NULL,
+    key VARCHAR(255) NOT NULL,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    last_failed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,
+    -- The counter starts over once this passes without another failure
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX idx_login_attempts_expires_at ON login_attempts(expires_at);
@@ -0,0 +1,27 @@
+-- Tell every instance to drop cached permissions when role assignments or role
+-- permissions change. The payload is the affected administrator id, or '*' when
+-- a role changed and any of its holders may be affected.
+CREATE FUNCTION notify_administrator_roles_change() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify(
+        'permission_changes',
+        COALESCE(NEW.administrator_id, OLD.administrator_id)::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION notify_role_permissions_change() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('permission_changes', '*');
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER administrator_roles_notify_permission_changes
+AFTER INSERT OR UPDATE OR DELETE ON administrator_roles
+FOR EACH ROW EXECUTE FUNCTION notify_administrator_roles_change();
+
+CREATE TRIGGER role_permissions_notify_permission_changes
+AFTER INSERT OR UPDATE OR DELETE ON role_permissions
+FOR EACH ROW EXECUTE FUNCTION notify_role_permissions_change();
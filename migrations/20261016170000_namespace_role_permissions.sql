@@ -0,0 +1,16 @@
+-- Replace the coarse permissions with their dot-namespaced equivalents
+INSERT INTO role_permissions (role_id, permission, created_at)
+SELECT rp.role_id, mapped.permission, rp.created_at
+FROM role_permissions rp
+JOIN (
+    VALUES
+        ('administrator_management', 'administrators.*'),
+        ('administrator_management', 'users.*'),
+        ('administrator_management', 'permissions.*'),
+        ('role_management', 'roles.*'),
+        ('role_management', 'permissions.*')
+) AS mapped(legacy, permission) ON mapped.legacy = rp.permission
+ON CONFLICT (role_id, permission) DO NOTHING;
+
+DELETE FROM role_permissions
+WHERE permission IN ('administrator_management', 'role_management');
@@ -0,0 +1,34 @@
+CREATE TABLE IF NOT EXISTS user_roles (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    assigned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, role_id)
+);
+
+CREATE INDEX idx_user_roles_user_id ON user_roles(user_id);
+CREATE INDEX idx_user_roles_role_id ON user_roles(role_id);
+
+-- Administrator and user ids share the payload, so prefix them with the scope
+CREATE OR REPLACE FUNCTION notify_administrator_roles_change() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify(
+        'permission_changes',
+        'administrator:' || COALESCE(NEW.administrator_id, OLD.administrator_id)::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION notify_user_roles_change() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify(
+        'permission_changes',
+        'user:' || COALESCE(NEW.user_id, OLD.user_id)::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER user_roles_notify_permission_changes
+AFTER INSERT OR UPDATE OR DELETE ON user_roles
+FOR EACH ROW EXECUTE FUNCTION notify_user_roles_change();
@@ -0,0 +1,41 @@
+CREATE TABLE groups (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_groups_name ON groups(name);
+CREATE INDEX idx_groups_created_at ON groups(created_at DESC);
+
+CREATE TABLE group_members (
+    group_id UUID NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (group_id, user_id)
+);
+
+CREATE INDEX idx_group_members_user_id ON group_members(user_id);
+
+-- Roles created before groups existed keep their grouping
+INSERT INTO groups (id, name)
+SELECT DISTINCT group_id, 'Group ' || group_id FROM roles WHERE group_id IS NOT NULL;
+
+ALTER TABLE roles
+    ADD CONSTRAINT fk_roles_group_id FOREIGN KEY (group_id) REFERENCES groups(id) ON DELETE CASCADE;
+
+-- Group roles only apply to members, so membership changes affect permissions
+CREATE FUNCTION notify_group_members_change() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify(
+        'permission_changes',
+        'user:' || COALESCE(NEW.user_id, OLD.user_id)::text
+    );
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER group_members_notify_permission_changes
+AFTER INSERT OR UPDATE OR DELETE ON group_members
+FOR EACH ROW EXECUTE FUNCTION notify_group_members_change();
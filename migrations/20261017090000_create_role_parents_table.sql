@@ -0,0 +1,15 @@
+-- Roles inherit every permission of their parent roles, transitively
+CREATE TABLE role_parents (
+    role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    parent_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (role_id, parent_id),
+    CONSTRAINT role_parents_not_self CHECK (role_id <> parent_id)
+);
+
+CREATE INDEX idx_role_parents_parent_id ON role_parents(parent_id);
+
+-- Any holder of the role or of its descendants may be affected
+CREATE TRIGGER role_parents_notify_permission_changes
+AFTER INSERT OR UPDATE OR DELETE ON role_parents
+FOR EACH ROW EXECUTE FUNCTION notify_role_permissions_change();
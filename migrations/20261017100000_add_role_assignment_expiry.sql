@@ -0,0 +1,8 @@
+-- Temporary role assignments and who granted them. Expired assignments grant
+-- nothing and are purged by the maintenance scheduler.
+ALTER TABLE administrator_roles
+    ADD COLUMN expires_at TIMESTAMPTZ,
+    ADD COLUMN assigned_by UUID REFERENCES user_administrators(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_administrator_roles_expires_at ON administrator_roles(expires_at)
+    WHERE expires_at IS NOT NULL;
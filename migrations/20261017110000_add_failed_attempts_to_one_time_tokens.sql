@@ -0,0 +1,4 @@
+-- Wrong codes entered for a token, e.g. an MFA challenge. The token is burned
+-- once the limit of its use case is reached.
+ALTER TABLE one_time_tokens
+    ADD COLUMN failed_attempts INTEGER NOT NULL DEFAULT 0;
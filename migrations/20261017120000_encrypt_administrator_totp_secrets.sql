@@ -0,0 +1,3 @@
+-- TOTP secrets are stored encrypted (`v1:` + base64 of nonce and ciphertext) under
+-- TOTP_ENCRYPTION_KEY; remaining plaintext secrets are encrypted when next read
+ALTER TABLE administrator_totp ALTER COLUMN secret TYPE TEXT;
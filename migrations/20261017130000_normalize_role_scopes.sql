@@ -0,0 +1,3 @@
+-- Scopes are compared as written by the application, the seeded roles used upper case
+UPDATE roles SET scope = LOWER(scope) WHERE scope <> LOWER(scope);
+ALTER TABLE roles ALTER COLUMN scope SET DEFAULT 'administrator';
@@ -0,0 +1,25 @@
+-- Append-only record of administrator role grants, revocations and expiries. It
+-- outlives the assignment, which is purged once it expires.
+CREATE TABLE administrator_role_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    administrator_id UUID NOT NULL,
+    role_id UUID NOT NULL,
+    event VARCHAR(20) NOT NULL,
+    expires_at TIMESTAMPTZ,
+    -- Administrator who granted or revoked the role, NULL for expiries
+    actor_id UUID,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_administrator_role_history_administrator_id
+    ON administrator_role_history(administrator_id, occurred_at DESC);
+
+CREATE FUNCTION reject_administrator_role_history_change() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'administrator_role_history is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER administrator_role_history_append_only
+    BEFORE UPDATE OR DELETE ON administrator_role_history
+    FOR EACH ROW EXECUTE FUNCTION reject_administrator_role_history_change();
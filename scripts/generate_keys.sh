@@ -2,18 +2,30 @@
 
 # Generate ES256 (ECDSA P-256) key pair for JWT authentication
 # This script generates a private and public key pair and saves them as PEM files
+#
+# Usage:
+#   ./scripts/generate_keys.sh          # keys/private_key.pem + keys/public_key.pem
+#   ./scripts/generate_keys.sh <kid>    # keys/ring/<kid>.private.pem + keys/ring/<kid>.public.pem
 
 set -e
 
 SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
 KEYS_DIR="${SCRIPT_DIR}/../keys"
 
+KID="$1"
+
+if [ -n "$KID" ]; then
+    KEYS_DIR="${KEYS_DIR}/ring"
+    PRIVATE_KEY_FILE="${KEYS_DIR}/${KID}.private.pem"
+    PUBLIC_KEY_FILE="${KEYS_DIR}/${KID}.public.pem"
+else
+    PRIVATE_KEY_FILE="${KEYS_DIR}/private_key.pem"
+    PUBLIC_KEY_FILE="${KEYS_DIR}/public_key.pem"
+fi
+
 # Create keys directory if it doesn't exist
 mkdir -p "$KEYS_DIR"
 
-PRIVATE_KEY_FILE="${KEYS_DIR}/private_key.pem"
-PUBLIC_KEY_FILE="${KEYS_DIR}/public_key.pem"
-
 echo "Generating ES256 key pair..."
 
 # Generate private key (EC prime256v1 is the P-256 curve)
@@ -29,8 +41,13 @@ echo "Public key:  $PUBLIC_KEY_FILE"
 echo ""
 echo "Add these to your .env file:"
 echo ""
-echo "JWT_PRIVATE_KEY_PATH=keys/private_key.pem"
-echo "JWT_PUBLIC_KEY_PATH=keys/public_key.pem"
+if [ -n "$KID" ]; then
+    echo "JWT_KEYS_DIR=keys/ring"
+    echo "JWT_CURRENT_KID=${KID}"
+else
+    echo "JWT_PRIVATE_KEY_PATH=keys/private_key.pem"
+    echo "JWT_PUBLIC_KEY_PATH=keys/public_key.pem"
+fi
 echo "JWT_ACCESS_TOKEN_EXPIRY=900"
 echo "JWT_REFRESH_TOKEN_EXPIRY=604800"
 echo ""
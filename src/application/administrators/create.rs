@@ -1,6 +1,7 @@
 use crate::domain::administrators::{Administrator, AdministratorRepository, NewAdministrator};
 use crate::domain::password::PasswordHashingService;
 use crate::shared::error::{AppError, FieldError};
+use crate::shared::validation::PasswordPolicy;
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -18,7 +19,7 @@ pub struct CreateAdministratorRequest {
     pub contact_number: Option<String>,
     #[validate(email)]
     pub email: String,
-    #[validate(length(min = 8))]
+    /// Checked against the configured password policy
     pub password: String,
 }
 
@@ -41,6 +42,7 @@ impl CreateAdministratorRequest {
 pub struct CreateAdministratorUseCase {
     repo: Arc<dyn AdministratorRepository>,
     password_service: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
 }
 
 impl CreateAdministratorUseCase {
@@ -51,13 +53,23 @@ impl CreateAdministratorUseCase {
         Self {
             repo,
             password_service,
+            password_policy: Arc::default(),
         }
     }
 
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
     pub async fn execute(
         &self,
         req: CreateAdministratorRequest,
     ) -> Result<Administrator, AppError> {
+        self.password_policy
+            .validate("password", &req.password, &[&req.email])?;
+
         // Check if email already exists
         req.validate_unique_email(&self.repo).await?;
 
@@ -1,15 +1,26 @@
 use crate::domain::administrators::AdministratorRepository;
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
 use crate::shared::error::AppError;
 use std::sync::Arc;
 use uuid::Uuid;
 
 pub struct DeleteAdministratorUseCase {
     repo: Arc<dyn AdministratorRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
 }
 
 impl DeleteAdministratorUseCase {
-    pub fn new(repo: Arc<dyn AdministratorRepository>) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: Arc<dyn AdministratorRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            repo,
+            refresh_token_repo,
+            access_token_denylist,
+        }
     }
 
     pub async fn execute(&self, id: Uuid) -> Result<bool, AppError> {
@@ -19,6 +30,19 @@ impl DeleteAdministratorUseCase {
             .await
             .map_err(AppError::InternalServerError)?;
 
+        // A deleted administrator must not keep using tokens issued earlier
+        if deleted {
+            self.access_token_denylist
+                .deny_subject(id, "admin")
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            self.refresh_token_repo
+                .delete_by_user_id(id, "admin")
+                .await
+                .map_err(AppError::InternalServerError)?;
+        }
+
         Ok(deleted)
     }
 }
@@ -3,4 +3,5 @@ pub mod delete;
 pub mod get;
 pub mod list;
 pub mod roles;
+pub mod unlock;
 pub mod update;
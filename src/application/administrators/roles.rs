@@ -1,21 +1,69 @@
-use crate::domain::administrators::AdministratorRepository;
+use crate::domain::access_scope::AccessScope;
+use crate::domain::administrators::{AdministratorRepository, AssignedRole, RoleAssignment};
+use crate::domain::roles::RoleRepository;
+use crate::shared::error::{AppError, FieldError};
 use std::sync::Arc;
+use time::OffsetDateTime;
 use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+/// Assign administrator roles to an administrator, user-scoped and group roles are rejected
 #[derive(Clone)]
 pub struct AttachRoles {
     admin_repo: Arc<dyn AdministratorRepository>,
+    role_repo: Arc<dyn RoleRepository>,
 }
 
 impl AttachRoles {
-    pub fn new(admin_repo: Arc<dyn AdministratorRepository>) -> Self {
-        Self { admin_repo }
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+    ) -> Self {
+        Self {
+            admin_repo,
+            role_repo,
+        }
     }
 
-    pub async fn execute(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
-        self.admin_repo.attach_roles(admin_id, role_ids).await?;
+    /// Assign roles on behalf of `assigned_by`, until `req.expires_at` if set
+    pub async fn execute(
+        &self,
+        admin_id: Uuid,
+        req: AttachRolesRequest,
+        assigned_by: Uuid,
+    ) -> Result<(), AppError> {
+        if req
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= OffsetDateTime::now_utc())
+        {
+            return Err(AppError::ValidationError(vec![FieldError::new(
+                "expires_at",
+                "Expiry must be in the future",
+            )]));
+        }
+
+        for role_id in &req.role_ids {
+            self.role_repo
+                .find_by_id(*role_id)
+                .await?
+                .filter(|role| role.scope == AccessScope::Administrator && role.group_id.is_none())
+                .ok_or_else(|| {
+                    AppError::ValidationError(vec![FieldError::new(
+                        "role_ids",
+                        format!("Role {} is not an administrator role", role_id),
+                    )])
+                })?;
+        }
+
+        let assignment = RoleAssignment {
+            expires_at: req.expires_at,
+            assigned_by: Some(assigned_by),
+        };
+
+        self.admin_repo
+            .attach_roles(admin_id, req.role_ids, assignment)
+            .await?;
         Ok(())
     }
 }
@@ -30,16 +78,50 @@ impl DetachRoles {
         Self { admin_repo }
     }
 
-    pub async fn execute(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
-        self.admin_repo.detach_roles(admin_id, role_ids).await?;
+    /// Remove roles on behalf of `revoked_by`
+    pub async fn execute(
+        &self,
+        admin_id: Uuid,
+        role_ids: Vec<Uuid>,
+        revoked_by: Uuid,
+    ) -> Result<(), anyhow::Error> {
+        self.admin_repo
+            .detach_roles(admin_id, role_ids, revoked_by)
+            .await?;
         Ok(())
     }
 }
 
+/// Roles an administrator holds, with who assigned them and until when
+#[derive(Clone)]
+pub struct ListRoleAssignments {
+    admin_repo: Arc<dyn AdministratorRepository>,
+}
+
+impl ListRoleAssignments {
+    pub fn new(admin_repo: Arc<dyn AdministratorRepository>) -> Self {
+        Self { admin_repo }
+    }
+
+    pub async fn execute(&self, admin_id: Uuid) -> Result<Vec<AssignedRole>, AppError> {
+        self.admin_repo
+            .find_by_id(admin_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Administrator not found".to_string()))?;
+
+        Ok(self.admin_repo.find_role_assignments(admin_id).await?)
+    }
+}
+
 #[derive(Debug, serde::Deserialize, Validate, ToSchema)]
 pub struct AttachRolesRequest {
     #[validate(length(min = 1, message = "At least one role must be provided"))]
     pub role_ids: Vec<Uuid>,
+    /// Temporary assignment, the roles stop granting permissions at this time.
+    /// Roles the administrator already holds permanently stay permanent.
+    #[serde(default, with = "time::serde::iso8601::option")]
+    #[schema(value_type = Option<String>, example = "2026-10-17T18:00:00Z")]
+    pub expires_at: Option<OffsetDateTime>,
 }
 
 #[derive(Debug, serde::Deserialize, Validate, ToSchema)]
@@ -0,0 +1,42 @@
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::domain::administrators::AdministratorRepository;
+use crate::domain::login_attempts::AttemptScope;
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Lift the login and password change lockouts of an administrator
+pub struct UnlockAdministratorUseCase {
+    repo: Arc<dyn AdministratorRepository>,
+    login_throttle: Arc<LoginThrottle>,
+}
+
+impl UnlockAdministratorUseCase {
+    pub fn new(repo: Arc<dyn AdministratorRepository>, login_throttle: Arc<LoginThrottle>) -> Self {
+        Self {
+            repo,
+            login_throttle,
+        }
+    }
+
+    /// Returns whether the administrator had failed attempts to clear
+    pub async fn execute(&self, id: Uuid) -> Result<bool, AppError> {
+        let admin = self
+            .repo
+            .find_by_id(id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(|| AppError::NotFound("Administrator not found".to_string()))?;
+
+        let login = self
+            .login_throttle
+            .unlock(AttemptScope::Administrator, &admin.email)
+            .await?;
+        let password_change = self
+            .login_throttle
+            .unlock(AttemptScope::AdministratorPasswordChange, &admin.email)
+            .await?;
+
+        Ok(login || password_change)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::domain::administrators::{Administrator, AdministratorRepository, UpdateAdministrator};
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
 use crate::domain::password::PasswordHashingService;
 use crate::shared::error::{AppError, FieldError};
+use crate::shared::validation::PasswordPolicy;
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -19,8 +21,9 @@ pub struct UpdateAdministratorRequest {
     pub contact_number: Option<String>,
     #[validate(email)]
     pub email: Option<String>,
-    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
-    #[schema(example = "newpassword123", min_length = 6)]
+    /// Rejected unless the password policy allows changes without the current
+    /// password, use `POST /api/v1/admin/me/password` instead
+    #[schema(example = "correct horse battery staple")]
     pub password: Option<String>,
 }
 
@@ -47,19 +50,33 @@ impl UpdateAdministratorRequest {
 pub struct UpdateAdministratorUseCase {
     repo: Arc<dyn AdministratorRepository>,
     password_service: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
 }
 
 impl UpdateAdministratorUseCase {
     pub fn new(
         repo: Arc<dyn AdministratorRepository>,
         password_service: Arc<dyn PasswordHashingService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
     ) -> Self {
         Self {
             repo,
             password_service,
+            password_policy: Arc::default(),
+            refresh_token_repo,
+            access_token_denylist,
         }
     }
 
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
     pub async fn execute(
         &self,
         id: Uuid,
@@ -71,16 +88,27 @@ impl UpdateAdministratorUseCase {
             .await
             .map_err(AppError::InternalServerError)?;
 
-        if administrator.is_none() {
-            return Err(AppError::ValidationError(vec![FieldError::new(
-                "email",
-                "Administrator not found",
-            )]));
-        }
+        let Some(administrator) = administrator else {
+            return Err(AppError::NotFound("Administrator not found".to_string()));
+        };
 
         // Validate unique email using custom validator (ignoring current user)
         req.validate_unique_email(&self.repo, id).await?;
 
+        if let Some(password) = &req.password {
+            // Without the current password a stolen access token could take over the account
+            if !self.password_policy.allow_change_without_current_password {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "password",
+                    "Password can only be changed together with the current password",
+                )]));
+            }
+
+            let email = req.email.as_deref().unwrap_or(&administrator.email);
+            self.password_policy
+                .validate("password", password, &[email])?;
+        }
+
         let password_hash = if let Some(password) = req.password {
             Some(
                 self.password_service
@@ -91,6 +119,8 @@ impl UpdateAdministratorUseCase {
             None
         };
 
+        let password_changed = password_hash.is_some();
+
         let update_struct = UpdateAdministrator {
             first_name: req.first_name,
             middle_name: req.middle_name,
@@ -107,6 +137,19 @@ impl UpdateAdministratorUseCase {
             .await
             .map_err(AppError::InternalServerError)?;
 
+        // Sessions started with the old password must stop working
+        if password_changed {
+            self.access_token_denylist
+                .deny_subject(id, "admin")
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            self.refresh_token_repo
+                .delete_by_user_id(id, "admin")
+                .await
+                .map_err(AppError::InternalServerError)?;
+        }
+
         Ok(admin)
     }
 }
@@ -1,10 +1,17 @@
-use crate::application::auth::token_utils::{TokenResponse, generate_and_store_tokens};
-use crate::domain::administrators::AdministratorRepository;
-use crate::domain::auth::{AuthService, RefreshTokenRepository};
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::application::auth::mfa::{MfaChallenge, MfaChallengeIssuer};
+use crate::application::auth::token_utils::{
+    SessionContext, TokenResponse, generate_and_store_tokens,
+};
+use crate::domain::administrators::{AdministratorRepository, UpdateAdministrator};
+use crate::domain::auth::{AuthService, RefreshTokenRepository, SessionMetadata};
+use crate::domain::login_attempts::AttemptScope;
 use crate::domain::password::PasswordHashingService;
+use crate::domain::permissions::Permission;
 use crate::shared::error::AppError;
 use serde::Deserialize;
 use std::sync::Arc;
+use uuid::Uuid;
 use validator::Validate;
 
 #[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
@@ -16,7 +23,17 @@ pub struct AdminLoginRequest {
     pub password: String,
 }
 
-pub type AdminLoginResponse = TokenResponse;
+/// Outcome of the password step of an administrator login
+#[derive(Debug)]
+pub enum AdminLoginResponse {
+    /// No second factor is enrolled, tokens are issued right away
+    Authenticated(TokenResponse),
+    /// The challenge has to be exchanged with a TOTP or recovery code
+    MfaRequired(MfaChallenge),
+    /// Holders of `*` never sign in with a password alone, the challenge only
+    /// allows enrolling TOTP, which then issues the tokens
+    MfaEnrollmentRequired(MfaChallenge),
+}
 
 pub struct AdminLoginUseCase {
     admin_repo: Arc<dyn AdministratorRepository>,
@@ -25,6 +42,9 @@ pub struct AdminLoginUseCase {
     password_service: Arc<dyn PasswordHashingService>,
     access_token_expiry: i64,
     refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
+    login_throttle: Option<Arc<LoginThrottle>>,
+    mfa: Option<MfaChallengeIssuer>,
 }
 
 impl AdminLoginUseCase {
@@ -43,6 +63,71 @@ impl AdminLoginUseCase {
             password_service,
             access_token_expiry,
             refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
+            login_throttle: None,
+            mfa: None,
+        }
+    }
+
+    /// Require the second factor of administrators who enrolled one, and its
+    /// enrollment from holders of `*`
+    pub fn with_mfa(mut self, mfa: MfaChallengeIssuer) -> Self {
+        self.mfa = Some(mfa);
+        self
+    }
+
+    /// Delay and lock out repeated failed attempts per account and IP
+    pub fn with_login_throttle(mut self, login_throttle: Arc<LoginThrottle>) -> Self {
+        self.login_throttle = Some(login_throttle);
+        self
+    }
+
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
+    /// Count a failed attempt against the account and IP, then return `error`
+    async fn failed_attempt(&self, email: &str, error: AppError) -> AppError {
+        if let Some(throttle) = &self.login_throttle
+            && let Err(e) = throttle
+                .record_failure(
+                    AttemptScope::Administrator,
+                    email,
+                    self.session_metadata.ip_address.as_deref(),
+                )
+                .await
+        {
+            return e;
+        }
+        error
+    }
+
+    /// Store a hash with the current parameters, a failure only postpones the upgrade
+    async fn upgrade_password_hash(&self, admin_id: Uuid, password: &str) {
+        let result = match self.password_service.hash_password(password) {
+            Ok(password_hash) => self
+                .admin_repo
+                .update(
+                    admin_id,
+                    UpdateAdministrator {
+                        first_name: None,
+                        middle_name: None,
+                        last_name: None,
+                        suffix: None,
+                        contact_number: None,
+                        email: None,
+                        password_hash: Some(password_hash),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::warn!("Failed to upgrade administrator password hash: {}", e);
         }
     }
 
@@ -50,8 +135,19 @@ impl AdminLoginUseCase {
     pub async fn execute(&self, req: AdminLoginRequest) -> Result<AdminLoginResponse, AppError> {
         tracing::info!("Attempting admin login for email: {}", req.email);
 
+        // Throttled attempts are rejected before the password is checked
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .check(
+                    AttemptScope::Administrator,
+                    &req.email,
+                    self.session_metadata.ip_address.as_deref(),
+                )
+                .await?;
+        }
+
         // Find administrator by email
-        let admin = self
+        let Some(admin) = self
             .admin_repo
             .find_by_email(&req.email)
             .await
@@ -59,10 +155,11 @@ impl AdminLoginUseCase {
                 tracing::error!("Database error finding administrator: {}", e);
                 AppError::InternalServerError(e)
             })?
-            .ok_or_else(|| {
-                tracing::warn!("Administrator not found for email: {}", req.email);
-                AppError::Unauthorized("Invalid credentials".to_string())
-            })?;
+        else {
+            tracing::warn!("Administrator not found for email: {}", req.email);
+            let error = AppError::Unauthorized("Invalid credentials".to_string());
+            return Err(self.failed_attempt(&req.email, error).await);
+        };
 
         tracing::info!(
             "Administrator found: {} (ID: {}). Verifying password...",
@@ -84,22 +181,54 @@ impl AdminLoginUseCase {
                 "Password verification failed for administrator: {}",
                 admin.email
             );
-            return Err(AppError::Unauthorized(
-                "Invalid email or password".to_string(),
-            ));
+            let error = AppError::Unauthorized("Invalid email or password".to_string());
+            return Err(self.failed_attempt(&req.email, error).await);
+        }
+
+        // Raised hashing costs reach existing administrators as they sign in
+        if self.password_service.needs_rehash(&admin.password_hash) {
+            tracing::info!("Upgrading password hash of administrator {}", admin.id);
+            self.upgrade_password_hash(admin.id, &req.password).await;
+        }
+
+        if let Some(mfa) = &self.mfa {
+            if let Some(challenge) = mfa.challenge_for(admin.id).await? {
+                tracing::info!("Admin password verified. Second factor required.");
+                return Ok(AdminLoginResponse::MfaRequired(challenge));
+            }
+
+            let permissions = self.admin_repo.get_permissions(admin.id).await?;
+            if permissions.contains(&Permission::WILDCARD) {
+                tracing::warn!(
+                    "Administrator {} holds the wildcard permission but has no second factor, enrollment required",
+                    admin.id
+                );
+                let challenge = mfa.enrollment_challenge_for(admin.id).await?;
+                return Ok(AdminLoginResponse::MfaEnrollmentRequired(challenge));
+            }
+        }
+
+        // Failed attempts are kept while a second factor is pending, so wrong codes add up
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .record_success(AttemptScope::Administrator, &req.email)
+                .await?;
         }
 
         tracing::info!("Admin password verified. Generating tokens.");
 
         // Generate and store token pair with "admin" user type
-        generate_and_store_tokens(
+        let tokens = generate_and_store_tokens(
             admin.id,
             "admin".to_string(),
             &self.auth_service,
             &self.refresh_token_repo,
+            SessionContext::new_session(&self.session_metadata),
             self.access_token_expiry,
             self.refresh_token_expiry,
         )
-        .await
+        .await?;
+
+        Ok(AdminLoginResponse::Authenticated(tokens))
     }
 }
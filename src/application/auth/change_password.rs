@@ -0,0 +1,129 @@
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
+use crate::domain::password::PasswordHashingService;
+use crate::shared::error::{AppError, FieldError};
+use crate::shared::validation::PasswordPolicy;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 1, message = "Current password is required"))]
+    pub current_password: String,
+
+    /// Checked against the configured password policy
+    #[schema(example = "correct horse battery staple")]
+    pub new_password: String,
+}
+
+/// Sets a new password after checking the current one and signs the account out everywhere.
+///
+/// A stolen access token alone is not enough to take over the account.
+pub struct ChangePasswordUseCase {
+    accounts: PasswordAccounts,
+    password_hasher: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    login_throttle: Option<Arc<LoginThrottle>>,
+}
+
+impl ChangePasswordUseCase {
+    pub fn new(
+        accounts: PasswordAccounts,
+        password_hasher: Arc<dyn PasswordHashingService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            accounts,
+            password_hasher,
+            password_policy: Arc::default(),
+            refresh_token_repo,
+            access_token_denylist,
+            login_throttle: None,
+        }
+    }
+
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    /// Throttle wrong current passwords, so a stolen token cannot guess them. They are
+    /// counted apart from failed logins and cannot lock the owner out of signing in.
+    pub fn with_login_throttle(mut self, login_throttle: Arc<LoginThrottle>) -> Self {
+        self.login_throttle = Some(login_throttle);
+        self
+    }
+
+    /// Returns the number of revoked refresh tokens
+    #[tracing::instrument(skip(self, req), fields(user_type = self.accounts.user_type()))]
+    pub async fn execute(&self, id: Uuid, req: ChangePasswordRequest) -> Result<u64, AppError> {
+        let user_type = self.accounts.user_type();
+        let scope = self.accounts.password_change_scope();
+
+        let account = self
+            .accounts
+            .find(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Account not found".to_string()))?;
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle.check(scope, &account.email, None).await?;
+        }
+
+        let valid_password = self
+            .password_hasher
+            .verify_password(&req.current_password, &account.password_hash)
+            .map_err(AppError::InternalServerError)?;
+        if !valid_password {
+            if let Some(throttle) = &self.login_throttle {
+                throttle.record_failure(scope, &account.email, None).await?;
+            }
+            return Err(AppError::ValidationError(vec![FieldError::new(
+                "current_password",
+                "Current password is incorrect",
+            )]));
+        }
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle.record_success(scope, &account.email).await?;
+        }
+
+        if req.new_password == req.current_password {
+            return Err(AppError::ValidationError(vec![FieldError::new(
+                "new_password",
+                "New password must differ from the current password",
+            )]));
+        }
+
+        let identifiers: Vec<&str> = account.identifiers.iter().map(String::as_str).collect();
+        self.password_policy
+            .validate("new_password", &req.new_password, &identifiers)?;
+
+        let password_hash = self
+            .password_hasher
+            .hash_password(&req.new_password)
+            .map_err(AppError::InternalServerError)?;
+
+        if !self.accounts.update_password(id, password_hash).await? {
+            return Err(AppError::NotFound("Account not found".to_string()));
+        }
+
+        // Every existing session ends, including the one used for this request
+        self.access_token_denylist
+            .deny_subject(id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        self.refresh_token_repo
+            .delete_by_user_id(id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
@@ -0,0 +1,156 @@
+use crate::application::auth::token_utils::{generate_opaque_token, hash_token};
+use crate::domain::notifications::{Notification, NotificationService};
+use crate::domain::one_time_tokens::{NewOneTimeToken, OneTimeTokenRepository, TokenPurpose};
+use crate::domain::users::{User, UserRepository};
+use crate::shared::error::AppError;
+use serde::Deserialize;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use validator::Validate;
+
+/// How long an email verification token stays valid by default
+pub const DEFAULT_VERIFICATION_TOKEN_TTL: Duration = Duration::hours(24);
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "Verification token is required"))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct ResendVerificationRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
+/// Issues email verification tokens and sends them to the user's current address
+#[derive(Clone)]
+pub struct EmailVerificationSender {
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    notification_service: Arc<dyn NotificationService>,
+    token_ttl: Duration,
+}
+
+impl EmailVerificationSender {
+    pub fn new(
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self {
+            token_repo,
+            notification_service,
+            token_ttl: DEFAULT_VERIFICATION_TOKEN_TTL,
+        }
+    }
+
+    /// Override how long issued tokens stay valid
+    pub fn with_token_ttl(mut self, token_ttl: Duration) -> Self {
+        self.token_ttl = token_ttl;
+        self
+    }
+
+    /// Replace any pending token of the user with a new one and send it
+    pub async fn send(&self, user: &User) -> Result<(), AppError> {
+        self.token_repo
+            .delete_for_user(user.id, "user", TokenPurpose::EmailVerification)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        let token = generate_opaque_token();
+        let expires_at = OffsetDateTime::now_utc() + self.token_ttl;
+
+        self.token_repo
+            .create(NewOneTimeToken {
+                user_id: user.id,
+                user_type: "user".to_string(),
+                purpose: TokenPurpose::EmailVerification,
+                token_hash: hash_token(&token),
+                expires_at,
+            })
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        self.notification_service
+            .send(Notification::EmailVerification {
+                email: user.email.clone(),
+                token,
+                expires_at,
+            })
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
+
+/// Marks a user's email address as verified using a verification token
+pub struct VerifyEmailUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+}
+
+impl VerifyEmailUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+    ) -> Self {
+        Self {
+            user_repo,
+            token_repo,
+        }
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: VerifyEmailRequest) -> Result<(), AppError> {
+        let invalid_token =
+            || AppError::BadRequest("Invalid or expired verification token".to_string());
+
+        let stored_token = self
+            .token_repo
+            .find_valid(&hash_token(&req.token), TokenPurpose::EmailVerification)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(invalid_token)?;
+
+        let consumed = self
+            .token_repo
+            .consume(stored_token.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed {
+            return Err(invalid_token());
+        }
+
+        // The account may have been deleted since the token was sent
+        if !self
+            .user_repo
+            .mark_email_verified(stored_token.user_id)
+            .await?
+        {
+            return Err(invalid_token());
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends a new verification token to an unverified account.
+///
+/// Unknown and already verified addresses are ignored silently so the endpoint
+/// cannot be used to find out which accounts exist.
+pub struct ResendVerificationEmailUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    sender: EmailVerificationSender,
+}
+
+impl ResendVerificationEmailUseCase {
+    pub fn new(user_repo: Arc<dyn UserRepository>, sender: EmailVerificationSender) -> Self {
+        Self { user_repo, sender }
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: ResendVerificationRequest) -> Result<(), AppError> {
+        match self.user_repo.find_by_email(&req.email).await? {
+            Some(user) if user.email_verified_at.is_none() => self.sender.send(&user).await,
+            _ => Ok(()),
+        }
+    }
+}
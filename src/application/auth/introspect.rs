@@ -0,0 +1,132 @@
+use crate::application::auth::token_utils::hash_token;
+use crate::domain::auth::{AccessTokenDenylist, AuthService, Claims, RefreshTokenRepository};
+use crate::shared::error::AppError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use time::OffsetDateTime;
+use validator::Validate;
+
+/// Introspection request (RFC 7662, section 2.1)
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct IntrospectionRequest {
+    #[validate(length(min = 1, message = "Token is required"))]
+    pub token: String,
+    /// "access_token" or "refresh_token"; the token is inspected either way
+    pub token_type_hint: Option<String>,
+}
+
+/// Introspection response (RFC 7662, section 2.2).
+/// Inactive tokens only report `active: false`.
+#[derive(Debug, Default, Serialize, utoipa::ToSchema)]
+pub struct IntrospectionResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    /// "access_token" or "refresh_token"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+impl IntrospectionResponse {
+    fn inactive() -> Self {
+        Self::default()
+    }
+
+    fn active(claims: Claims) -> Self {
+        let token_type = format!("{}_token", claims.token_type);
+
+        Self {
+            active: true,
+            sub: Some(claims.sub),
+            token_type: Some(token_type),
+            user_type: Some(claims.user_type),
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            nbf: Some(claims.nbf),
+            iss: Some(claims.iss),
+            aud: Some(claims.aud),
+            jti: Some(claims.jti),
+        }
+    }
+}
+
+/// Tells resource servers whether a token is currently active
+pub struct IntrospectTokenUseCase {
+    auth_service: Arc<dyn AuthService>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+}
+
+impl IntrospectTokenUseCase {
+    pub fn new(
+        auth_service: Arc<dyn AuthService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            auth_service,
+            refresh_token_repo,
+            access_token_denylist,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        req: IntrospectionRequest,
+    ) -> Result<IntrospectionResponse, AppError> {
+        // Invalid, expired or foreign tokens are simply inactive
+        let Ok(claims) = self.auth_service.validate_token(&req.token) else {
+            return Ok(IntrospectionResponse::inactive());
+        };
+
+        let active = match claims.token_type.as_str() {
+            "access" => !self
+                .access_token_denylist
+                .is_denied(&claims)
+                .await
+                .map_err(AppError::InternalServerError)?,
+            "refresh" => self.refresh_token_is_active(&req.token, &claims).await?,
+            _ => false,
+        };
+
+        if active {
+            Ok(IntrospectionResponse::active(claims))
+        } else {
+            Ok(IntrospectionResponse::inactive())
+        }
+    }
+
+    /// Refresh tokens are only active while stored, unrotated and unexpired
+    async fn refresh_token_is_active(
+        &self,
+        token: &str,
+        claims: &Claims,
+    ) -> Result<bool, AppError> {
+        let stored_token = self
+            .refresh_token_repo
+            .find_by_hash(&hash_token(token))
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(stored_token.is_some_and(|stored| {
+            stored.rotated_at.is_none()
+                && stored.expires_at > OffsetDateTime::now_utc()
+                && stored.user_id.to_string() == claims.sub
+                && stored.user_type == claims.user_type
+        }))
+    }
+}
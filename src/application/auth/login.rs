@@ -1,9 +1,14 @@
-use crate::application::auth::token_utils::{TokenResponse, generate_and_store_tokens};
-use crate::domain::auth::{AuthService, RefreshTokenRepository};
-use crate::domain::users::UserRepository;
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::application::auth::token_utils::{
+    SessionContext, TokenResponse, generate_and_store_tokens,
+};
+use crate::domain::auth::{AuthService, RefreshTokenRepository, SessionMetadata};
+use crate::domain::login_attempts::AttemptScope;
+use crate::domain::users::{UpdateUser, UserRepository};
 use crate::shared::error::AppError;
 use serde::Deserialize;
 use std::sync::Arc;
+use uuid::Uuid;
 use validator::Validate;
 
 #[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
@@ -26,6 +31,9 @@ pub struct LoginUseCase {
     password_service: Arc<dyn PasswordHashingService>,
     access_token_expiry: i64,
     refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
+    login_throttle: Option<Arc<LoginThrottle>>,
+    require_verified_email: bool,
 }
 
 impl LoginUseCase {
@@ -44,18 +52,92 @@ impl LoginUseCase {
             password_service,
             access_token_expiry,
             refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
+            login_throttle: None,
+            require_verified_email: false,
+        }
+    }
+
+    /// Reject users who have not verified their email address yet
+    pub fn with_require_verified_email(mut self, require_verified_email: bool) -> Self {
+        self.require_verified_email = require_verified_email;
+        self
+    }
+
+    /// Delay and lock out repeated failed attempts per account and IP
+    pub fn with_login_throttle(mut self, login_throttle: Arc<LoginThrottle>) -> Self {
+        self.login_throttle = Some(login_throttle);
+        self
+    }
+
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
+    /// Count a failed attempt against the account and IP, then return `error`
+    async fn failed_attempt(&self, email: &str, error: AppError) -> AppError {
+        if let Some(throttle) = &self.login_throttle
+            && let Err(e) = throttle
+                .record_failure(
+                    AttemptScope::User,
+                    email,
+                    self.session_metadata.ip_address.as_deref(),
+                )
+                .await
+        {
+            return e;
+        }
+        error
+    }
+
+    /// Store a hash with the current parameters, a failure only postpones the upgrade
+    async fn upgrade_password_hash(&self, user_id: Uuid, password: &str) {
+        let result = match self.password_service.hash_password(password) {
+            Ok(password_hash) => self
+                .user_repo
+                .update(
+                    user_id,
+                    UpdateUser {
+                        username: None,
+                        email: None,
+                        password_hash: Some(password_hash),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            tracing::warn!("Failed to upgrade password hash: {}", e);
         }
     }
 
     #[tracing::instrument(skip(self, req), fields(email = %req.email))]
     pub async fn execute(&self, req: LoginRequest) -> Result<LoginResponse, AppError> {
+        // Throttled attempts are rejected before the password is checked
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .check(
+                    AttemptScope::User,
+                    &req.email,
+                    self.session_metadata.ip_address.as_deref(),
+                )
+                .await?;
+        }
+
         // Find user by email
-        let user = self
+        let Some(user) = self
             .user_repo
             .find_by_email(&req.email)
             .await
             .map_err(AppError::InternalServerError)?
-            .ok_or(AppError::Unauthorized("Invalid credentials".to_string()))?;
+        else {
+            let error = AppError::Unauthorized("Invalid credentials".to_string());
+            return Err(self.failed_attempt(&req.email, error).await);
+        };
 
         // Verify password
         let valid_password = self
@@ -64,8 +146,25 @@ impl LoginUseCase {
             .map_err(AppError::InternalServerError)?;
 
         if !valid_password {
-            return Err(AppError::Unauthorized(
-                "Invalid email or password".to_string(),
+            let error = AppError::Unauthorized("Invalid email or password".to_string());
+            return Err(self.failed_attempt(&req.email, error).await);
+        }
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .record_success(AttemptScope::User, &req.email)
+                .await?;
+        }
+
+        // Raised hashing costs reach existing accounts as they sign in
+        if self.password_service.needs_rehash(&user.password_hash) {
+            self.upgrade_password_hash(user.id, &req.password).await;
+        }
+
+        // Checked after the password so the response does not reveal unverified accounts
+        if self.require_verified_email && user.email_verified_at.is_none() {
+            return Err(AppError::Forbidden(
+                "Email address has not been verified".to_string(),
             ));
         }
 
@@ -75,6 +174,7 @@ impl LoginUseCase {
             "user".to_string(),
             &self.auth_service,
             &self.refresh_token_repo,
+            SessionContext::new_session(&self.session_metadata),
             self.access_token_expiry,
             self.refresh_token_expiry,
         )
@@ -0,0 +1,239 @@
+use crate::domain::login_attempts::{AttemptScope, LoginAttemptRepository, LoginAttempts};
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// JSON:API error code while attempts are delayed by the back-off
+pub const LOGIN_THROTTLED_CODE: &str = "login_throttled";
+
+/// JSON:API error code while an account or IP is locked out
+pub const ACCOUNT_LOCKED_CODE: &str = "account_locked";
+
+/// Failure counts at which back-off and lockout kick in
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottleLimits {
+    /// Failures after which every attempt has to wait for the back-off delay
+    pub backoff_after: i32,
+    /// Failures after which attempts are rejected for the lockout duration
+    pub lockout_after: i32,
+}
+
+/// How failed logins are throttled
+#[derive(Debug, Clone)]
+pub struct LoginThrottlePolicy {
+    pub account: ThrottleLimits,
+    /// Limits per client IP, higher since many users may share an address
+    pub ip: ThrottleLimits,
+    /// Delay after reaching `backoff_after`, doubled with every further failure
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub lockout_duration: Duration,
+    /// Counters start over after this long without a failure
+    pub failure_window: Duration,
+}
+
+impl Default for LoginThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            account: ThrottleLimits {
+                backoff_after: 3,
+                lockout_after: 10,
+            },
+            ip: ThrottleLimits {
+                backoff_after: 20,
+                lockout_after: 100,
+            },
+            base_delay: Duration::seconds(1),
+            max_delay: Duration::minutes(5),
+            lockout_duration: Duration::minutes(15),
+            failure_window: Duration::hours(1),
+        }
+    }
+}
+
+impl LoginThrottlePolicy {
+    fn limits(&self, scope: AttemptScope) -> ThrottleLimits {
+        match scope {
+            AttemptScope::Ip => self.ip,
+            AttemptScope::User
+            | AttemptScope::Administrator
+            | AttemptScope::UserPasswordChange
+            | AttemptScope::AdministratorPasswordChange => self.account,
+        }
+    }
+
+    /// Back-off delay before the next attempt is allowed
+    pub fn delay_after(&self, scope: AttemptScope, failed_count: i32) -> Duration {
+        let over = failed_count - self.limits(scope).backoff_after;
+        if over < 0 {
+            return Duration::ZERO;
+        }
+
+        // Capped exponent keeps the multiplication from overflowing
+        let delay = self.base_delay * 2i32.pow(over.min(20) as u32);
+        delay.min(self.max_delay)
+    }
+}
+
+/// Tracks failed logins per account and per IP and rejects attempts that come too fast.
+///
+/// Accounts are keyed by email, so unknown emails are throttled the same way as
+/// existing ones and the responses do not reveal which accounts exist.
+pub struct LoginThrottle {
+    attempt_repo: Arc<dyn LoginAttemptRepository>,
+    policy: LoginThrottlePolicy,
+}
+
+impl LoginThrottle {
+    pub fn new(attempt_repo: Arc<dyn LoginAttemptRepository>) -> Self {
+        Self {
+            attempt_repo,
+            policy: LoginThrottlePolicy::default(),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: LoginThrottlePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Normalized account key, emails are compared case-insensitively
+    fn account_key(email: &str) -> String {
+        email.trim().to_lowercase()
+    }
+
+    fn subjects<'a>(
+        scope: AttemptScope,
+        account_key: &'a str,
+        ip_address: Option<&'a str>,
+    ) -> impl Iterator<Item = (AttemptScope, &'a str)> {
+        std::iter::once((scope, account_key)).chain(ip_address.map(|ip| (AttemptScope::Ip, ip)))
+    }
+
+    fn rejection(
+        &self,
+        scope: AttemptScope,
+        attempts: &LoginAttempts,
+        now: OffsetDateTime,
+    ) -> Option<AppError> {
+        if let Some(locked_until) = attempts.locked_until.filter(|until| *until > now) {
+            return Some(AppError::TooManyAttempts {
+                code: ACCOUNT_LOCKED_CODE,
+                detail: "Too many failed login attempts, try again later".to_string(),
+                retry_after_secs: (locked_until - now).whole_seconds(),
+            });
+        }
+
+        let retry_at =
+            attempts.last_failed_at + self.policy.delay_after(scope, attempts.failed_count);
+        if retry_at > now {
+            return Some(AppError::TooManyAttempts {
+                code: LOGIN_THROTTLED_CODE,
+                detail: "Too many failed login attempts, slow down".to_string(),
+                retry_after_secs: (retry_at - now).whole_seconds(),
+            });
+        }
+
+        None
+    }
+
+    /// Reject the attempt if the account or IP is locked or still backing off
+    pub async fn check(
+        &self,
+        scope: AttemptScope,
+        email: &str,
+        ip_address: Option<&str>,
+    ) -> Result<(), AppError> {
+        let account_key = Self::account_key(email);
+        let now = OffsetDateTime::now_utc();
+
+        for (scope, key) in Self::subjects(scope, &account_key, ip_address) {
+            let attempts = self
+                .attempt_repo
+                .find(scope, key)
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            if let Some(error) = attempts.and_then(|a| self.rejection(scope, &a, now)) {
+                tracing::warn!(scope = scope.as_str(), "Login attempt throttled");
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Count a failed attempt and lock the account or IP once the limit is reached
+    pub async fn record_failure(
+        &self,
+        scope: AttemptScope,
+        email: &str,
+        ip_address: Option<&str>,
+    ) -> Result<(), AppError> {
+        let account_key = Self::account_key(email);
+
+        for (scope, key) in Self::subjects(scope, &account_key, ip_address) {
+            let attempts = self
+                .attempt_repo
+                .record_failure(scope, key, self.policy.failure_window)
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            if attempts.failed_count >= self.policy.limits(scope).lockout_after {
+                tracing::warn!(
+                    scope = scope.as_str(),
+                    failed_count = attempts.failed_count,
+                    "Locking out after repeated failed logins"
+                );
+                self.attempt_repo
+                    .lock(
+                        scope,
+                        key,
+                        OffsetDateTime::now_utc() + self.policy.lockout_duration,
+                    )
+                    .await
+                    .map_err(AppError::InternalServerError)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forget the failed attempts of an account after a successful login
+    pub async fn record_success(&self, scope: AttemptScope, email: &str) -> Result<(), AppError> {
+        self.unlock(scope, email).await.map(|_| ())
+    }
+
+    /// Lift a lockout of an account, returns false if it had no failed attempts
+    pub async fn unlock(&self, scope: AttemptScope, email: &str) -> Result<bool, AppError> {
+        self.attempt_repo
+            .reset(scope, &Self::account_key(email))
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_grows_exponentially_and_is_capped() {
+        let policy = LoginThrottlePolicy::default();
+
+        assert_eq!(policy.delay_after(AttemptScope::User, 2), Duration::ZERO);
+        assert_eq!(
+            policy.delay_after(AttemptScope::User, 3),
+            Duration::seconds(1)
+        );
+        assert_eq!(
+            policy.delay_after(AttemptScope::User, 5),
+            Duration::seconds(4)
+        );
+        assert_eq!(
+            policy.delay_after(AttemptScope::Administrator, 100),
+            policy.max_delay
+        );
+        assert_eq!(policy.delay_after(AttemptScope::Ip, 5), Duration::ZERO);
+    }
+}
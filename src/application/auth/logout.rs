@@ -0,0 +1,99 @@
+use crate::application::auth::token_utils::hash_token;
+use crate::domain::auth::{AccessTokenDenylist, Claims, RefreshTokenRepository};
+use crate::shared::error::AppError;
+use serde::Deserialize;
+use std::sync::Arc;
+use time::OffsetDateTime;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct LogoutRequest {
+    #[validate(length(min = 1, message = "Refresh token is required"))]
+    pub refresh_token: String,
+}
+
+/// Revokes the presented refresh token (and its rotation family) for the authenticated user,
+/// along with the access token used to make the request
+pub struct LogoutUseCase {
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+}
+
+impl LogoutUseCase {
+    pub fn new(
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            refresh_token_repo,
+            access_token_denylist,
+        }
+    }
+
+    pub async fn execute(&self, claims: &Claims, req: LogoutRequest) -> Result<(), AppError> {
+        let user_id = claims.user_id().map_err(AppError::InternalServerError)?;
+        let token_hash = hash_token(&req.refresh_token);
+
+        let stored_token = self
+            .refresh_token_repo
+            .find_by_hash(&token_hash)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(|| {
+                AppError::Unauthorized("Refresh token not found or expired".to_string())
+            })?;
+
+        // Only the owner of the refresh token may revoke it
+        if stored_token.user_id != user_id || stored_token.user_type != claims.user_type {
+            return Err(AppError::Unauthorized("Token user mismatch".to_string()));
+        }
+
+        // Revoke the whole rotation chain so no descendant token survives
+        self.refresh_token_repo
+            .delete_by_family_id(stored_token.family_id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        let expires_at = OffsetDateTime::from_unix_timestamp(claims.exp)
+            .map_err(|e| AppError::InternalServerError(e.into()))?;
+        self.access_token_denylist
+            .deny_token(&claims.jti, expires_at)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(())
+    }
+}
+
+/// Revokes every refresh token and every issued access token of the authenticated user
+pub struct LogoutAllUseCase {
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+}
+
+impl LogoutAllUseCase {
+    pub fn new(
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            refresh_token_repo,
+            access_token_denylist,
+        }
+    }
+
+    /// Returns the number of revoked refresh tokens
+    pub async fn execute(&self, claims: &Claims) -> Result<u64, AppError> {
+        let user_id = claims.user_id().map_err(AppError::InternalServerError)?;
+
+        self.access_token_denylist
+            .deny_subject(user_id, &claims.user_type)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        self.refresh_token_repo
+            .delete_by_user_id(user_id, &claims.user_type)
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
@@ -0,0 +1,798 @@
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::application::auth::token_utils::{
+    SessionContext, TokenResponse, generate_and_store_tokens, generate_opaque_token, hash_token,
+};
+use crate::application::auth::totp;
+use crate::domain::administrators::AdministratorRepository;
+use crate::domain::auth::{AuthService, RefreshTokenRepository, SessionMetadata};
+use crate::domain::login_attempts::AttemptScope;
+use crate::domain::mfa::{MfaRepository, TotpEnrollment};
+use crate::domain::one_time_tokens::{
+    NewOneTimeToken, OneTimeToken, OneTimeTokenRepository, TokenPurpose,
+};
+use crate::shared::error::AppError;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use data_encoding::BASE32_NOPAD;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+/// Number of recovery codes issued at once
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// How long the password step of a login stays valid by default
+pub const DEFAULT_MFA_CHALLENGE_TTL: Duration = Duration::minutes(5);
+
+/// Wrong codes after which an MFA challenge is burned and the login has to start over
+pub const MAX_MFA_CHALLENGE_ATTEMPTS: i32 = 3;
+
+/// Issuer shown in authenticator apps by default
+pub const DEFAULT_TOTP_ISSUER: &str = "Caxur";
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct TotpCodeRequest {
+    #[validate(length(min = 1, message = "Code is required"))]
+    #[schema(example = "123456")]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct MfaVerifyRequest {
+    #[validate(length(min = 1, message = "Challenge token is required"))]
+    pub challenge_token: String,
+
+    /// A TOTP code or one of the recovery codes
+    #[validate(length(min = 1, message = "Code is required"))]
+    #[schema(example = "123456")]
+    pub code: String,
+}
+
+/// Enrollment challenge of an administrator who has to set up TOTP before signing in
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct MfaEnrollmentRequest {
+    #[validate(length(min = 1, message = "Challenge token is required"))]
+    pub challenge_token: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TotpEnrollmentResponse {
+    /// Base32 secret for manual entry
+    pub secret: String,
+    /// `otpauth://` URI to render as a QR code
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// Shown once; only hashes are stored
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct MfaStatusResponse {
+    pub totp_enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+/// Returned by the password step when a second factor is required or has to be enrolled
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct MfaChallenge {
+    pub challenge_token: String,
+    pub expires_in: i64,
+}
+
+/// Tokens and the first recovery codes of an administrator who enrolled TOTP while signing in
+#[derive(Debug)]
+pub struct MfaEnrollmentCompleted {
+    pub tokens: TokenResponse,
+    pub recovery_codes: RecoveryCodesResponse,
+}
+
+/// Generate a recovery code like `abcd-efgh-ijkl-mnop` (80 bits of entropy)
+fn generate_recovery_code() -> String {
+    let mut bytes = [0u8; 10];
+    OsRng.fill_bytes(&mut bytes);
+    let encoded = BASE32_NOPAD.encode(&bytes).to_lowercase();
+
+    encoded
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Hash a recovery code, ignoring case, dashes and whitespace
+fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_lowercase();
+    hash_token(&normalized)
+}
+
+/// Issue a fresh set of recovery codes, invalidating the previous ones
+async fn issue_recovery_codes(
+    mfa_repo: &Arc<dyn MfaRepository>,
+    administrator_id: Uuid,
+) -> Result<RecoveryCodesResponse, AppError> {
+    let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+        .map(|_| generate_recovery_code())
+        .collect();
+
+    mfa_repo
+        .replace_recovery_codes(
+            administrator_id,
+            recovery_codes
+                .iter()
+                .map(|c| hash_recovery_code(c))
+                .collect(),
+        )
+        .await
+        .map_err(AppError::InternalServerError)?;
+
+    Ok(RecoveryCodesResponse { recovery_codes })
+}
+
+/// Check a TOTP code, rejecting codes of an already used time step.
+/// Falls back to recovery codes when `allow_recovery_code` is set.
+async fn verify_second_factor(
+    mfa_repo: &Arc<dyn MfaRepository>,
+    enrollment: &TotpEnrollment,
+    code: &str,
+    allow_recovery_code: bool,
+) -> Result<bool, AppError> {
+    let administrator_id = enrollment.administrator_id;
+
+    if let Some(step) = totp::verify_code(&enrollment.secret, code, OffsetDateTime::now_utc()) {
+        return mfa_repo
+            .record_totp_step(administrator_id, step)
+            .await
+            .map_err(AppError::InternalServerError);
+    }
+
+    if allow_recovery_code {
+        return mfa_repo
+            .use_recovery_code(administrator_id, &hash_recovery_code(code))
+            .await
+            .map_err(AppError::InternalServerError);
+    }
+
+    Ok(false)
+}
+
+/// Look up a confirmed TOTP enrollment or fail with the given error
+async fn confirmed_enrollment(
+    mfa_repo: &Arc<dyn MfaRepository>,
+    administrator_id: Uuid,
+) -> Result<TotpEnrollment, AppError> {
+    mfa_repo
+        .find_totp(administrator_id)
+        .await
+        .map_err(AppError::InternalServerError)?
+        .filter(TotpEnrollment::is_confirmed)
+        .ok_or_else(|| AppError::BadRequest("TOTP is not enabled".to_string()))
+}
+
+/// Look up an unused enrollment challenge issued to an administrator
+async fn enrollment_challenge(
+    token_repo: &Arc<dyn OneTimeTokenRepository>,
+    challenge_token: &str,
+) -> Result<OneTimeToken, AppError> {
+    token_repo
+        .find_valid(&hash_token(challenge_token), TokenPurpose::MfaEnrollment)
+        .await
+        .map_err(AppError::InternalServerError)?
+        .filter(|c| c.user_type == "admin")
+        .ok_or_else(|| AppError::Unauthorized("Invalid or expired MFA challenge".to_string()))
+}
+
+/// Issues MFA challenges during the password step of an administrator login
+#[derive(Clone)]
+pub struct MfaChallengeIssuer {
+    mfa_repo: Arc<dyn MfaRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    challenge_ttl: Duration,
+}
+
+impl MfaChallengeIssuer {
+    pub fn new(
+        mfa_repo: Arc<dyn MfaRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+    ) -> Self {
+        Self {
+            mfa_repo,
+            token_repo,
+            challenge_ttl: DEFAULT_MFA_CHALLENGE_TTL,
+        }
+    }
+
+    /// Override how long challenges stay valid
+    pub fn with_challenge_ttl(mut self, challenge_ttl: Duration) -> Self {
+        self.challenge_ttl = challenge_ttl;
+        self
+    }
+
+    /// Issue a challenge if the administrator has a confirmed second factor
+    pub async fn challenge_for(
+        &self,
+        administrator_id: Uuid,
+    ) -> Result<Option<MfaChallenge>, AppError> {
+        let enrolled = self
+            .mfa_repo
+            .find_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .is_some_and(|e| e.is_confirmed());
+        if !enrolled {
+            return Ok(None);
+        }
+
+        self.issue(administrator_id, TokenPurpose::MfaChallenge)
+            .await
+            .map(Some)
+    }
+
+    /// Issue a challenge that only allows enrolling TOTP, for administrators who must have one
+    pub async fn enrollment_challenge_for(
+        &self,
+        administrator_id: Uuid,
+    ) -> Result<MfaChallenge, AppError> {
+        self.issue(administrator_id, TokenPurpose::MfaEnrollment)
+            .await
+    }
+
+    async fn issue(
+        &self,
+        administrator_id: Uuid,
+        purpose: TokenPurpose,
+    ) -> Result<MfaChallenge, AppError> {
+        let challenge_token = generate_opaque_token();
+        self.token_repo
+            .create(NewOneTimeToken {
+                user_id: administrator_id,
+                user_type: "admin".to_string(),
+                purpose,
+                token_hash: hash_token(&challenge_token),
+                expires_at: OffsetDateTime::now_utc() + self.challenge_ttl,
+            })
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(MfaChallenge {
+            challenge_token,
+            expires_in: self.challenge_ttl.whole_seconds(),
+        })
+    }
+}
+
+/// Exchanges an MFA challenge and a valid code for a token pair
+pub struct VerifyMfaChallengeUseCase {
+    admin_repo: Arc<dyn AdministratorRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    auth_service: Arc<dyn AuthService>,
+    access_token_expiry: i64,
+    refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
+    login_throttle: Option<Arc<LoginThrottle>>,
+}
+
+impl VerifyMfaChallengeUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        auth_service: Arc<dyn AuthService>,
+        access_token_expiry: i64,
+        refresh_token_expiry: i64,
+    ) -> Self {
+        Self {
+            admin_repo,
+            mfa_repo,
+            token_repo,
+            refresh_token_repo,
+            auth_service,
+            access_token_expiry,
+            refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
+            login_throttle: None,
+        }
+    }
+
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
+    /// Count wrong codes like failed logins, so they lead to the same lockout
+    pub fn with_login_throttle(mut self, login_throttle: Arc<LoginThrottle>) -> Self {
+        self.login_throttle = Some(login_throttle);
+        self
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: MfaVerifyRequest) -> Result<TokenResponse, AppError> {
+        let invalid_challenge =
+            || AppError::Unauthorized("Invalid or expired MFA challenge".to_string());
+
+        let challenge = self
+            .token_repo
+            .find_valid(
+                &hash_token(&req.challenge_token),
+                TokenPurpose::MfaChallenge,
+            )
+            .await
+            .map_err(AppError::InternalServerError)?
+            .filter(|c| c.user_type == "admin")
+            .ok_or_else(invalid_challenge)?;
+
+        let admin = self
+            .admin_repo
+            .find_by_id(challenge.user_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(invalid_challenge)?;
+        let ip_address = self.session_metadata.ip_address.as_deref();
+
+        // A locked out account cannot finish a login it started earlier
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .check(AttemptScope::Administrator, &admin.email, ip_address)
+                .await?;
+        }
+
+        let enrollment = self
+            .mfa_repo
+            .find_totp(challenge.user_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .filter(TotpEnrollment::is_confirmed)
+            .ok_or_else(invalid_challenge)?;
+
+        // A wrong code keeps the challenge usable for a few more attempts
+        if !verify_second_factor(&self.mfa_repo, &enrollment, &req.code, true).await? {
+            tracing::warn!(administrator_id = %challenge.user_id, "Invalid MFA code");
+
+            let burned = self
+                .token_repo
+                .record_failed_attempt(challenge.id, MAX_MFA_CHALLENGE_ATTEMPTS)
+                .await
+                .map_err(AppError::InternalServerError)?;
+            if burned {
+                tracing::warn!(administrator_id = %challenge.user_id, "MFA challenge burned");
+            }
+
+            if let Some(throttle) = &self.login_throttle {
+                throttle
+                    .record_failure(AttemptScope::Administrator, &admin.email, ip_address)
+                    .await?;
+            }
+
+            return Err(AppError::Unauthorized(
+                "Invalid verification code".to_string(),
+            ));
+        }
+
+        let consumed = self
+            .token_repo
+            .consume(challenge.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed {
+            return Err(invalid_challenge());
+        }
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .record_success(AttemptScope::Administrator, &admin.email)
+                .await?;
+        }
+
+        generate_and_store_tokens(
+            challenge.user_id,
+            "admin".to_string(),
+            &self.auth_service,
+            &self.refresh_token_repo,
+            SessionContext::new_session(&self.session_metadata),
+            self.access_token_expiry,
+            self.refresh_token_expiry,
+        )
+        .await
+    }
+}
+
+/// Generates a TOTP secret for the authenticated administrator
+pub struct StartTotpEnrollmentUseCase {
+    admin_repo: Arc<dyn AdministratorRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    issuer: String,
+}
+
+impl StartTotpEnrollmentUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+    ) -> Self {
+        Self {
+            admin_repo,
+            mfa_repo,
+            issuer: DEFAULT_TOTP_ISSUER.to_string(),
+        }
+    }
+
+    /// Name shown next to the account in authenticator apps
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = issuer.into();
+        self
+    }
+
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+    ) -> Result<TotpEnrollmentResponse, AppError> {
+        let admin = self
+            .admin_repo
+            .find_by_id(administrator_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Administrator not found".to_string()))?;
+
+        let existing = self
+            .mfa_repo
+            .find_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if existing.is_some_and(|e| e.is_confirmed()) {
+            return Err(AppError::Conflict("TOTP is already enabled".to_string()));
+        }
+
+        let secret = totp::generate_secret();
+        self.mfa_repo
+            .save_pending_totp(administrator_id, &secret)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(TotpEnrollmentResponse {
+            otpauth_uri: totp::provisioning_uri(&self.issuer, &admin.email, &secret),
+            secret,
+        })
+    }
+}
+
+/// Enables TOTP once the administrator submits a valid code, returning recovery codes
+pub struct ConfirmTotpEnrollmentUseCase {
+    mfa_repo: Arc<dyn MfaRepository>,
+}
+
+impl ConfirmTotpEnrollmentUseCase {
+    pub fn new(mfa_repo: Arc<dyn MfaRepository>) -> Self {
+        Self { mfa_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+        req: TotpCodeRequest,
+    ) -> Result<RecoveryCodesResponse, AppError> {
+        let enrollment = self
+            .mfa_repo
+            .find_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(|| {
+                AppError::BadRequest("TOTP enrollment has not been started".to_string())
+            })?;
+        if enrollment.is_confirmed() {
+            return Err(AppError::Conflict("TOTP is already enabled".to_string()));
+        }
+
+        if !verify_second_factor(&self.mfa_repo, &enrollment, &req.code, false).await? {
+            return Err(AppError::BadRequest(
+                "Invalid verification code".to_string(),
+            ));
+        }
+
+        if !self
+            .mfa_repo
+            .confirm_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+        {
+            return Err(AppError::Conflict("TOTP is already enabled".to_string()));
+        }
+
+        issue_recovery_codes(&self.mfa_repo, administrator_id).await
+    }
+}
+
+/// Replaces the recovery codes after checking a current TOTP code
+pub struct RegenerateRecoveryCodesUseCase {
+    mfa_repo: Arc<dyn MfaRepository>,
+}
+
+impl RegenerateRecoveryCodesUseCase {
+    pub fn new(mfa_repo: Arc<dyn MfaRepository>) -> Self {
+        Self { mfa_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+        req: TotpCodeRequest,
+    ) -> Result<RecoveryCodesResponse, AppError> {
+        let enrollment = confirmed_enrollment(&self.mfa_repo, administrator_id).await?;
+
+        if !verify_second_factor(&self.mfa_repo, &enrollment, &req.code, false).await? {
+            return Err(AppError::BadRequest(
+                "Invalid verification code".to_string(),
+            ));
+        }
+
+        issue_recovery_codes(&self.mfa_repo, administrator_id).await
+    }
+}
+
+/// Turns TOTP off after checking a TOTP or recovery code
+pub struct DisableTotpUseCase {
+    mfa_repo: Arc<dyn MfaRepository>,
+}
+
+impl DisableTotpUseCase {
+    pub fn new(mfa_repo: Arc<dyn MfaRepository>) -> Self {
+        Self { mfa_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+        req: TotpCodeRequest,
+    ) -> Result<(), AppError> {
+        let enrollment = confirmed_enrollment(&self.mfa_repo, administrator_id).await?;
+
+        if !verify_second_factor(&self.mfa_repo, &enrollment, &req.code, true).await? {
+            return Err(AppError::BadRequest(
+                "Invalid verification code".to_string(),
+            ));
+        }
+
+        self.mfa_repo
+            .delete_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(())
+    }
+}
+
+/// Generates a TOTP secret for an administrator holding an enrollment challenge
+pub struct StartRequiredTotpEnrollmentUseCase {
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    enrollment: StartTotpEnrollmentUseCase,
+}
+
+impl StartRequiredTotpEnrollmentUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+    ) -> Self {
+        Self {
+            token_repo,
+            enrollment: StartTotpEnrollmentUseCase::new(admin_repo, mfa_repo),
+        }
+    }
+
+    /// Name shown next to the account in authenticator apps
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.enrollment = self.enrollment.with_issuer(issuer);
+        self
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(
+        &self,
+        req: MfaEnrollmentRequest,
+    ) -> Result<TotpEnrollmentResponse, AppError> {
+        let challenge = enrollment_challenge(&self.token_repo, &req.challenge_token).await?;
+
+        self.enrollment.execute(challenge.user_id).await
+    }
+}
+
+/// Enables TOTP for an administrator holding an enrollment challenge and finishes
+/// the login, returning tokens along with the first recovery codes
+pub struct ConfirmRequiredTotpEnrollmentUseCase {
+    admin_repo: Arc<dyn AdministratorRepository>,
+    mfa_repo: Arc<dyn MfaRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    auth_service: Arc<dyn AuthService>,
+    access_token_expiry: i64,
+    refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
+    login_throttle: Option<Arc<LoginThrottle>>,
+}
+
+impl ConfirmRequiredTotpEnrollmentUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        mfa_repo: Arc<dyn MfaRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        auth_service: Arc<dyn AuthService>,
+        access_token_expiry: i64,
+        refresh_token_expiry: i64,
+    ) -> Self {
+        Self {
+            admin_repo,
+            mfa_repo,
+            token_repo,
+            refresh_token_repo,
+            auth_service,
+            access_token_expiry,
+            refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
+            login_throttle: None,
+        }
+    }
+
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
+    /// Count wrong codes like failed logins, so they lead to the same lockout
+    pub fn with_login_throttle(mut self, login_throttle: Arc<LoginThrottle>) -> Self {
+        self.login_throttle = Some(login_throttle);
+        self
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: MfaVerifyRequest) -> Result<MfaEnrollmentCompleted, AppError> {
+        let challenge = enrollment_challenge(&self.token_repo, &req.challenge_token).await?;
+        let administrator_id = challenge.user_id;
+
+        let admin = self
+            .admin_repo
+            .find_by_id(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(|| {
+                AppError::Unauthorized("Invalid or expired MFA challenge".to_string())
+            })?;
+        let ip_address = self.session_metadata.ip_address.as_deref();
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .check(AttemptScope::Administrator, &admin.email, ip_address)
+                .await?;
+        }
+
+        let enrollment = self
+            .mfa_repo
+            .find_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .filter(|e| !e.is_confirmed())
+            .ok_or_else(|| {
+                AppError::BadRequest("TOTP enrollment has not been started".to_string())
+            })?;
+
+        if !verify_second_factor(&self.mfa_repo, &enrollment, &req.code, false).await? {
+            tracing::warn!(administrator_id = %administrator_id, "Invalid MFA enrollment code");
+
+            self.token_repo
+                .record_failed_attempt(challenge.id, MAX_MFA_CHALLENGE_ATTEMPTS)
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            if let Some(throttle) = &self.login_throttle {
+                throttle
+                    .record_failure(AttemptScope::Administrator, &admin.email, ip_address)
+                    .await?;
+            }
+
+            return Err(AppError::Unauthorized(
+                "Invalid verification code".to_string(),
+            ));
+        }
+
+        let consumed = self
+            .token_repo
+            .consume(challenge.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed
+            || !self
+                .mfa_repo
+                .confirm_totp(administrator_id)
+                .await
+                .map_err(AppError::InternalServerError)?
+        {
+            return Err(AppError::Unauthorized(
+                "Invalid or expired MFA challenge".to_string(),
+            ));
+        }
+
+        let recovery_codes = issue_recovery_codes(&self.mfa_repo, administrator_id).await?;
+
+        if let Some(throttle) = &self.login_throttle {
+            throttle
+                .record_success(AttemptScope::Administrator, &admin.email)
+                .await?;
+        }
+
+        let tokens = generate_and_store_tokens(
+            administrator_id,
+            "admin".to_string(),
+            &self.auth_service,
+            &self.refresh_token_repo,
+            SessionContext::new_session(&self.session_metadata),
+            self.access_token_expiry,
+            self.refresh_token_expiry,
+        )
+        .await?;
+
+        Ok(MfaEnrollmentCompleted {
+            tokens,
+            recovery_codes,
+        })
+    }
+}
+
+/// Reports whether TOTP is enabled and how many recovery codes are left
+pub struct GetMfaStatusUseCase {
+    mfa_repo: Arc<dyn MfaRepository>,
+}
+
+impl GetMfaStatusUseCase {
+    pub fn new(mfa_repo: Arc<dyn MfaRepository>) -> Self {
+        Self { mfa_repo }
+    }
+
+    pub async fn execute(&self, administrator_id: Uuid) -> Result<MfaStatusResponse, AppError> {
+        let totp_enabled = self
+            .mfa_repo
+            .find_totp(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .is_some_and(|e| e.is_confirmed());
+
+        let recovery_codes_remaining = if totp_enabled {
+            self.mfa_repo
+                .count_unused_recovery_codes(administrator_id)
+                .await
+                .map_err(AppError::InternalServerError)?
+        } else {
+            0
+        };
+
+        Ok(MfaStatusResponse {
+            totp_enabled,
+            recovery_codes_remaining,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_code_format_and_normalization() {
+        let code = generate_recovery_code();
+        assert_eq!(code.len(), 19);
+        assert_eq!(code.matches('-').count(), 3);
+
+        let variant = format!(" {} ", code.to_uppercase().replace('-', ""));
+        assert_eq!(hash_recovery_code(&code), hash_recovery_code(&variant));
+        assert_ne!(code, generate_recovery_code());
+    }
+}
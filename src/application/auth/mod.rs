@@ -1,4 +1,15 @@
+pub mod admin_login;
+pub mod change_password;
+pub mod email_verification;
+pub mod introspect;
 pub mod login;
+pub mod login_throttle;
+pub mod logout;
+pub mod mfa;
+pub mod passkeys;
+pub mod password_accounts;
+pub mod password_reset;
 pub mod refresh;
 pub mod token_utils;
-pub mod admin_login;
+pub mod totp;
+pub mod webauthn;
@@ -0,0 +1,515 @@
+use crate::application::auth::token_utils::{
+    SessionContext, TokenResponse, generate_and_store_tokens, generate_opaque_token, hash_token,
+};
+use crate::application::auth::webauthn::{self, Ceremony, RelyingParty};
+use crate::domain::administrators::AdministratorRepository;
+use crate::domain::auth::{AuthService, RefreshTokenRepository, SessionMetadata};
+use crate::domain::one_time_tokens::{NewOneTimeToken, OneTimeTokenRepository, TokenPurpose};
+use crate::domain::passkeys::{NewPasskey, Passkey, PasskeyRepository};
+use crate::shared::error::AppError;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use serde::Deserialize;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+use validator::Validate;
+
+/// How long a registration or login ceremony may take by default
+pub const DEFAULT_CEREMONY_TTL: Duration = Duration::minutes(5);
+
+/// Name given to passkeys registered without one
+const DEFAULT_PASSKEY_NAME: &str = "Passkey";
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct PasskeyRegistrationRequest {
+    /// Label shown when listing passkeys, e.g. "YubiKey"
+    #[validate(length(max = 255, message = "Name must not exceed 255 characters"))]
+    pub name: Option<String>,
+
+    /// Base64url encoded `clientDataJSON`
+    #[validate(length(min = 1, message = "Client data is required"))]
+    pub client_data_json: String,
+
+    /// Base64url encoded `attestationObject`
+    #[validate(length(min = 1, message = "Attestation object is required"))]
+    pub attestation_object: String,
+}
+
+#[derive(Debug, Default, Deserialize, Validate, utoipa::ToSchema)]
+pub struct PasskeyLoginOptionsRequest {
+    /// Limits the login to this administrator's passkeys; omit for discoverable credentials
+    #[validate(email(message = "Invalid email format"))]
+    #[schema(example = "admin@example.com")]
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct PasskeyLoginRequest {
+    /// Base64url encoded credential ID (`rawId`)
+    #[validate(length(min = 1, message = "Credential ID is required"))]
+    pub credential_id: String,
+
+    /// Base64url encoded `clientDataJSON`
+    #[validate(length(min = 1, message = "Client data is required"))]
+    pub client_data_json: String,
+
+    /// Base64url encoded `authenticatorData`
+    #[validate(length(min = 1, message = "Authenticator data is required"))]
+    pub authenticator_data: String,
+
+    /// Base64url encoded DER signature
+    #[validate(length(min = 1, message = "Signature is required"))]
+    pub signature: String,
+}
+
+/// Data for `navigator.credentials.create()`
+#[derive(Debug)]
+pub struct PasskeyRegistrationOptions {
+    pub challenge: String,
+    pub rp_id: String,
+    pub rp_name: String,
+    /// Base64url encoded user handle
+    pub user_id: String,
+    pub user_name: String,
+    pub user_display_name: String,
+    pub exclude_credentials: Vec<String>,
+    pub timeout_ms: i64,
+}
+
+/// Data for `navigator.credentials.get()`
+#[derive(Debug)]
+pub struct PasskeyLoginOptions {
+    pub challenge: String,
+    pub rp_id: String,
+    pub allow_credentials: Vec<String>,
+    pub timeout_ms: i64,
+}
+
+fn decode_field(value: &str, field: &'static str) -> Result<Vec<u8>, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|_| AppError::BadRequest(format!("{} is not valid base64url", field)))
+}
+
+/// Store a ceremony challenge; only its hash is kept like other one-time tokens
+async fn issue_challenge(
+    token_repo: &Arc<dyn OneTimeTokenRepository>,
+    administrator_id: Uuid,
+    purpose: TokenPurpose,
+    ttl: Duration,
+) -> Result<String, AppError> {
+    let challenge = generate_opaque_token();
+    token_repo
+        .create(NewOneTimeToken {
+            user_id: administrator_id,
+            user_type: "admin".to_string(),
+            purpose,
+            token_hash: hash_token(&challenge),
+            expires_at: OffsetDateTime::now_utc() + ttl,
+        })
+        .await
+        .map_err(AppError::InternalServerError)?;
+
+    Ok(challenge)
+}
+
+/// Starts the registration ceremony for the authenticated administrator
+pub struct StartPasskeyRegistrationUseCase {
+    admin_repo: Arc<dyn AdministratorRepository>,
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    relying_party: RelyingParty,
+    ceremony_ttl: Duration,
+}
+
+impl StartPasskeyRegistrationUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        relying_party: RelyingParty,
+    ) -> Self {
+        Self {
+            admin_repo,
+            passkey_repo,
+            token_repo,
+            relying_party,
+            ceremony_ttl: DEFAULT_CEREMONY_TTL,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+    ) -> Result<PasskeyRegistrationOptions, AppError> {
+        let admin = self
+            .admin_repo
+            .find_by_id(administrator_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Administrator not found".to_string()))?;
+
+        // Keeps the authenticator from registering the same credential twice
+        let exclude_credentials = self
+            .passkey_repo
+            .list_for_administrator(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .into_iter()
+            .map(|p| p.credential_id)
+            .collect();
+
+        let challenge = issue_challenge(
+            &self.token_repo,
+            administrator_id,
+            TokenPurpose::WebAuthnRegistration,
+            self.ceremony_ttl,
+        )
+        .await?;
+
+        Ok(PasskeyRegistrationOptions {
+            challenge,
+            rp_id: self.relying_party.id.clone(),
+            rp_name: self.relying_party.name.clone(),
+            user_id: URL_SAFE_NO_PAD.encode(admin.id.as_bytes()),
+            user_name: admin.email,
+            user_display_name: format!("{} {}", admin.first_name, admin.last_name),
+            exclude_credentials,
+            timeout_ms: self.ceremony_ttl.whole_milliseconds() as i64,
+        })
+    }
+}
+
+/// Verifies the authenticator's response and stores the new passkey
+pub struct FinishPasskeyRegistrationUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    relying_party: RelyingParty,
+}
+
+impl FinishPasskeyRegistrationUseCase {
+    pub fn new(
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        relying_party: RelyingParty,
+    ) -> Self {
+        Self {
+            passkey_repo,
+            token_repo,
+            relying_party,
+        }
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(
+        &self,
+        administrator_id: Uuid,
+        req: PasskeyRegistrationRequest,
+    ) -> Result<Passkey, AppError> {
+        let client_data_json = decode_field(&req.client_data_json, "client_data_json")?;
+        let attestation_object = decode_field(&req.attestation_object, "attestation_object")?;
+
+        let challenge = webauthn::verify_client_data(
+            &self.relying_party,
+            &client_data_json,
+            Ceremony::Registration,
+        )
+        .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        let token = self
+            .token_repo
+            .find_valid(&hash_token(&challenge), TokenPurpose::WebAuthnRegistration)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .filter(|t| t.user_type == "admin" && t.user_id == administrator_id)
+            .ok_or_else(|| {
+                AppError::BadRequest("Invalid or expired registration challenge".to_string())
+            })?;
+
+        let (credential, sign_count) =
+            webauthn::verify_registration(&self.relying_party, &attestation_object)
+                .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+        let credential_id = URL_SAFE_NO_PAD.encode(&credential.credential_id);
+        if self
+            .passkey_repo
+            .find_by_credential_id(&credential_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .is_some()
+        {
+            return Err(AppError::Conflict(
+                "Passkey is already registered".to_string(),
+            ));
+        }
+
+        let consumed = self
+            .token_repo
+            .consume(token.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed {
+            return Err(AppError::BadRequest(
+                "Invalid or expired registration challenge".to_string(),
+            ));
+        }
+
+        let passkey = self
+            .passkey_repo
+            .create(NewPasskey {
+                administrator_id,
+                credential_id,
+                public_key: webauthn::encode_public_key(&credential.public_key),
+                sign_count: sign_count as i64,
+                name: req
+                    .name
+                    .filter(|n| !n.trim().is_empty())
+                    .unwrap_or_else(|| DEFAULT_PASSKEY_NAME.to_string()),
+            })
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        tracing::info!(%administrator_id, passkey_id = %passkey.id, "Passkey registered");
+
+        Ok(passkey)
+    }
+}
+
+/// Starts a passkey login, for a given administrator or for discoverable credentials
+pub struct StartPasskeyLoginUseCase {
+    admin_repo: Arc<dyn AdministratorRepository>,
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    relying_party: RelyingParty,
+    ceremony_ttl: Duration,
+}
+
+impl StartPasskeyLoginUseCase {
+    pub fn new(
+        admin_repo: Arc<dyn AdministratorRepository>,
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        relying_party: RelyingParty,
+    ) -> Self {
+        Self {
+            admin_repo,
+            passkey_repo,
+            token_repo,
+            relying_party,
+            ceremony_ttl: DEFAULT_CEREMONY_TTL,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        req: PasskeyLoginOptionsRequest,
+    ) -> Result<PasskeyLoginOptions, AppError> {
+        let admin = match &req.email {
+            Some(email) => self.admin_repo.find_by_email(email).await?,
+            None => None,
+        };
+
+        // Unknown emails get the same answer as discoverable logins, so the
+        // endpoint does not reveal which accounts exist
+        let (administrator_id, allow_credentials) = match admin {
+            Some(admin) => {
+                let credentials = self
+                    .passkey_repo
+                    .list_for_administrator(admin.id)
+                    .await
+                    .map_err(AppError::InternalServerError)?
+                    .into_iter()
+                    .map(|p| p.credential_id)
+                    .collect();
+                (admin.id, credentials)
+            }
+            None => (Uuid::nil(), Vec::new()),
+        };
+
+        let challenge = issue_challenge(
+            &self.token_repo,
+            administrator_id,
+            TokenPurpose::WebAuthnAuthentication,
+            self.ceremony_ttl,
+        )
+        .await?;
+
+        Ok(PasskeyLoginOptions {
+            challenge,
+            rp_id: self.relying_party.id.clone(),
+            allow_credentials,
+            timeout_ms: self.ceremony_ttl.whole_milliseconds() as i64,
+        })
+    }
+}
+
+/// Verifies a passkey assertion and issues a token pair
+pub struct FinishPasskeyLoginUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    auth_service: Arc<dyn AuthService>,
+    relying_party: RelyingParty,
+    access_token_expiry: i64,
+    refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
+}
+
+impl FinishPasskeyLoginUseCase {
+    pub fn new(
+        passkey_repo: Arc<dyn PasskeyRepository>,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        auth_service: Arc<dyn AuthService>,
+        relying_party: RelyingParty,
+        access_token_expiry: i64,
+        refresh_token_expiry: i64,
+    ) -> Self {
+        Self {
+            passkey_repo,
+            token_repo,
+            refresh_token_repo,
+            auth_service,
+            relying_party,
+            access_token_expiry,
+            refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
+        }
+    }
+
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: PasskeyLoginRequest) -> Result<TokenResponse, AppError> {
+        let invalid_passkey = || AppError::Unauthorized("Invalid passkey".to_string());
+
+        let client_data_json = decode_field(&req.client_data_json, "client_data_json")?;
+        let authenticator_data = decode_field(&req.authenticator_data, "authenticator_data")?;
+        let signature = decode_field(&req.signature, "signature")?;
+
+        let challenge = webauthn::verify_client_data(
+            &self.relying_party,
+            &client_data_json,
+            Ceremony::Authentication,
+        )
+        .map_err(|e| AppError::Unauthorized(e.to_string()))?;
+
+        let token = self
+            .token_repo
+            .find_valid(
+                &hash_token(&challenge),
+                TokenPurpose::WebAuthnAuthentication,
+            )
+            .await
+            .map_err(AppError::InternalServerError)?
+            .filter(|t| t.user_type == "admin")
+            .ok_or_else(|| {
+                AppError::Unauthorized("Invalid or expired login challenge".to_string())
+            })?;
+
+        let passkey = self
+            .passkey_repo
+            .find_by_credential_id(req.credential_id.trim_end_matches('='))
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(invalid_passkey)?;
+
+        // A challenge issued for a specific administrator only accepts their passkeys
+        if !token.user_id.is_nil() && token.user_id != passkey.administrator_id {
+            return Err(invalid_passkey());
+        }
+
+        let public_key = webauthn::decode_public_key(&passkey.public_key)
+            .map_err(|e| AppError::InternalServerError(anyhow::anyhow!(e)))?;
+        let assertion = webauthn::verify_assertion(
+            &self.relying_party,
+            &public_key,
+            &authenticator_data,
+            &client_data_json,
+            &signature,
+        )
+        .map_err(|e| {
+            tracing::warn!(passkey_id = %passkey.id, "Passkey assertion rejected: {}", e);
+            invalid_passkey()
+        })?;
+
+        let consumed = self
+            .token_repo
+            .consume(token.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed {
+            return Err(AppError::Unauthorized(
+                "Invalid or expired login challenge".to_string(),
+            ));
+        }
+
+        let counter_advanced = self
+            .passkey_repo
+            .record_use(passkey.id, assertion.sign_count as i64)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !counter_advanced {
+            tracing::warn!(passkey_id = %passkey.id, "Passkey signature counter did not increase, possible cloned authenticator");
+            return Err(invalid_passkey());
+        }
+
+        tracing::info!(administrator_id = %passkey.administrator_id, "Admin passkey verified. Generating tokens.");
+
+        generate_and_store_tokens(
+            passkey.administrator_id,
+            "admin".to_string(),
+            &self.auth_service,
+            &self.refresh_token_repo,
+            SessionContext::new_session(&self.session_metadata),
+            self.access_token_expiry,
+            self.refresh_token_expiry,
+        )
+        .await
+    }
+}
+
+/// Lists the passkeys of an administrator
+pub struct ListPasskeysUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+}
+
+impl ListPasskeysUseCase {
+    pub fn new(passkey_repo: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkey_repo }
+    }
+
+    pub async fn execute(&self, administrator_id: Uuid) -> Result<Vec<Passkey>, AppError> {
+        self.passkey_repo
+            .list_for_administrator(administrator_id)
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
+
+/// Removes a passkey of an administrator
+pub struct DeletePasskeyUseCase {
+    passkey_repo: Arc<dyn PasskeyRepository>,
+}
+
+impl DeletePasskeyUseCase {
+    pub fn new(passkey_repo: Arc<dyn PasskeyRepository>) -> Self {
+        Self { passkey_repo }
+    }
+
+    pub async fn execute(&self, administrator_id: Uuid, id: Uuid) -> Result<(), AppError> {
+        let deleted = self
+            .passkey_repo
+            .delete(administrator_id, id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !deleted {
+            return Err(AppError::NotFound("Passkey not found".to_string()));
+        }
+
+        Ok(())
+    }
+}
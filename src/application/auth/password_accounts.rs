@@ -0,0 +1,111 @@
+use crate::domain::administrators::{AdministratorRepository, UpdateAdministrator};
+use crate::domain::login_attempts::AttemptScope;
+use crate::domain::users::{UpdateUser, UserRepository};
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The kind of account whose password is reset or changed
+#[derive(Clone)]
+pub enum PasswordAccounts {
+    Users(Arc<dyn UserRepository>),
+    Administrators(Arc<dyn AdministratorRepository>),
+}
+
+/// The password related fields of a user or administrator
+pub(crate) struct PasswordAccount {
+    pub email: String,
+    pub password_hash: String,
+    /// Username and email address a new password must not contain
+    pub identifiers: Vec<String>,
+}
+
+impl PasswordAccounts {
+    pub(crate) fn user_type(&self) -> &'static str {
+        match self {
+            PasswordAccounts::Users(_) => "user",
+            PasswordAccounts::Administrators(_) => "admin",
+        }
+    }
+
+    /// Wrong current passwords are counted apart from failed logins
+    pub(crate) fn password_change_scope(&self) -> AttemptScope {
+        match self {
+            PasswordAccounts::Users(_) => AttemptScope::UserPasswordChange,
+            PasswordAccounts::Administrators(_) => AttemptScope::AdministratorPasswordChange,
+        }
+    }
+
+    pub(crate) async fn find_id_by_email(&self, email: &str) -> Result<Option<Uuid>, AppError> {
+        let id = match self {
+            PasswordAccounts::Users(repo) => repo.find_by_email(email).await?.map(|u| u.id),
+            PasswordAccounts::Administrators(repo) => {
+                repo.find_by_email(email).await?.map(|a| a.id)
+            }
+        };
+        Ok(id)
+    }
+
+    pub(crate) async fn find(&self, id: Uuid) -> Result<Option<PasswordAccount>, AppError> {
+        let account = match self {
+            PasswordAccounts::Users(repo) => {
+                repo.find_by_id(id).await?.map(|user| PasswordAccount {
+                    identifiers: vec![user.username, user.email.clone()],
+                    email: user.email,
+                    password_hash: user.password_hash,
+                })
+            }
+            PasswordAccounts::Administrators(repo) => {
+                repo.find_by_id(id).await?.map(|admin| PasswordAccount {
+                    identifiers: vec![admin.email.clone()],
+                    email: admin.email,
+                    password_hash: admin.password_hash,
+                })
+            }
+        };
+        Ok(account)
+    }
+
+    /// Returns false if the account no longer exists
+    pub(crate) async fn update_password(
+        &self,
+        id: Uuid,
+        password_hash: String,
+    ) -> Result<bool, AppError> {
+        match self {
+            PasswordAccounts::Users(repo) => {
+                if repo.find_by_id(id).await?.is_none() {
+                    return Ok(false);
+                }
+                repo.update(
+                    id,
+                    UpdateUser {
+                        username: None,
+                        email: None,
+                        password_hash: Some(password_hash),
+                    },
+                )
+                .await?;
+            }
+            PasswordAccounts::Administrators(repo) => {
+                if repo.find_by_id(id).await?.is_none() {
+                    return Ok(false);
+                }
+                repo.update(
+                    id,
+                    UpdateAdministrator {
+                        first_name: None,
+                        middle_name: None,
+                        last_name: None,
+                        suffix: None,
+                        contact_number: None,
+                        email: None,
+                        password_hash: Some(password_hash),
+                    },
+                )
+                .await?;
+            }
+        }
+        Ok(true)
+    }
+}
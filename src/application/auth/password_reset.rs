@@ -0,0 +1,208 @@
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::application::auth::token_utils::{generate_opaque_token, hash_token};
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
+use crate::domain::notifications::{Notification, NotificationService};
+use crate::domain::one_time_tokens::{NewOneTimeToken, OneTimeTokenRepository, TokenPurpose};
+use crate::domain::password::PasswordHashingService;
+use crate::shared::error::AppError;
+use crate::shared::validation::PasswordPolicy;
+use serde::Deserialize;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use validator::Validate;
+
+/// How long a password reset token stays valid by default
+pub const DEFAULT_RESET_TOKEN_TTL: Duration = Duration::hours(1);
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct ForgotPasswordRequest {
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, utoipa::ToSchema)]
+pub struct ResetPasswordRequest {
+    #[validate(length(min = 1, message = "Reset token is required"))]
+    pub token: String,
+
+    /// Checked against the configured password policy
+    #[schema(example = "correct horse battery staple")]
+    pub password: String,
+}
+
+/// Issues a password reset token and sends it to the account's email address.
+///
+/// Unknown email addresses are ignored silently so the endpoint cannot be used
+/// to find out which accounts exist.
+pub struct RequestPasswordResetUseCase {
+    accounts: PasswordAccounts,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    notification_service: Arc<dyn NotificationService>,
+    token_ttl: Duration,
+}
+
+impl RequestPasswordResetUseCase {
+    pub fn new(
+        accounts: PasswordAccounts,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self {
+            accounts,
+            token_repo,
+            notification_service,
+            token_ttl: DEFAULT_RESET_TOKEN_TTL,
+        }
+    }
+
+    /// Override how long issued tokens stay valid
+    pub fn with_token_ttl(mut self, token_ttl: Duration) -> Self {
+        self.token_ttl = token_ttl;
+        self
+    }
+
+    #[tracing::instrument(skip(self, req), fields(user_type = self.accounts.user_type()))]
+    pub async fn execute(&self, req: ForgotPasswordRequest) -> Result<(), AppError> {
+        let Some(user_id) = self.accounts.find_id_by_email(&req.email).await? else {
+            tracing::debug!("Password reset requested for unknown email");
+            return Ok(());
+        };
+        let user_type = self.accounts.user_type();
+
+        // Only the most recently issued token stays usable
+        self.token_repo
+            .delete_for_user(user_id, user_type, TokenPurpose::PasswordReset)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        let token = generate_opaque_token();
+        let expires_at = OffsetDateTime::now_utc() + self.token_ttl;
+
+        self.token_repo
+            .create(NewOneTimeToken {
+                user_id,
+                user_type: user_type.to_string(),
+                purpose: TokenPurpose::PasswordReset,
+                token_hash: hash_token(&token),
+                expires_at,
+            })
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        self.notification_service
+            .send(Notification::PasswordReset {
+                email: req.email,
+                user_type: user_type.to_string(),
+                token,
+                expires_at,
+            })
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(())
+    }
+}
+
+/// Sets a new password using a reset token and signs the account out everywhere
+pub struct ResetPasswordUseCase {
+    accounts: PasswordAccounts,
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+    password_hasher: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+}
+
+impl ResetPasswordUseCase {
+    pub fn new(
+        accounts: PasswordAccounts,
+        token_repo: Arc<dyn OneTimeTokenRepository>,
+        password_hasher: Arc<dyn PasswordHashingService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    ) -> Self {
+        Self {
+            accounts,
+            token_repo,
+            password_hasher,
+            password_policy: Arc::default(),
+            refresh_token_repo,
+            access_token_denylist,
+        }
+    }
+
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    /// Returns the number of revoked refresh tokens
+    #[tracing::instrument(skip(self, req), fields(user_type = self.accounts.user_type()))]
+    pub async fn execute(&self, req: ResetPasswordRequest) -> Result<u64, AppError> {
+        let invalid_token = || AppError::BadRequest("Invalid or expired reset token".to_string());
+        let user_type = self.accounts.user_type();
+
+        let stored_token = self
+            .token_repo
+            .find_valid(&hash_token(&req.token), TokenPurpose::PasswordReset)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(invalid_token)?;
+
+        // A user token cannot reset an administrator password and vice versa
+        if stored_token.user_type != user_type {
+            return Err(invalid_token());
+        }
+
+        // Checked before the token is claimed so a rejected password can be retried
+        let account = self
+            .accounts
+            .find(stored_token.user_id)
+            .await?
+            .ok_or_else(invalid_token)?;
+        let identifiers: Vec<&str> = account.identifiers.iter().map(String::as_str).collect();
+        self.password_policy
+            .validate("password", &req.password, &identifiers)?;
+
+        // Claim the token atomically so concurrent requests cannot both use it
+        let consumed = self
+            .token_repo
+            .consume(stored_token.id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+        if !consumed {
+            return Err(invalid_token());
+        }
+
+        let password_hash = self
+            .password_hasher
+            .hash_password(&req.password)
+            .map_err(AppError::InternalServerError)?;
+
+        let user_id = stored_token.user_id;
+        if !self
+            .accounts
+            .update_password(user_id, password_hash)
+            .await?
+        {
+            return Err(invalid_token());
+        }
+
+        self.token_repo
+            .delete_for_user(user_id, user_type, TokenPurpose::PasswordReset)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        // Whoever knew the old password must not stay signed in
+        self.access_token_denylist
+            .deny_subject(user_id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        self.refresh_token_repo
+            .delete_by_user_id(user_id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
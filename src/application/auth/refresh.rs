@@ -1,5 +1,7 @@
-use crate::application::auth::token_utils::{TokenResponse, generate_and_store_tokens, hash_token};
-use crate::domain::auth::{AuthService, RefreshTokenRepository};
+use crate::application::auth::token_utils::{
+    SessionContext, TokenResponse, generate_and_store_tokens, hash_token,
+};
+use crate::domain::auth::{AuthService, RefreshToken, RefreshTokenRepository, SessionMetadata};
 use crate::shared::error::AppError;
 use serde::Deserialize;
 use std::sync::Arc;
@@ -18,6 +20,7 @@ pub struct RefreshTokenUseCase {
     auth_service: Arc<dyn AuthService>,
     access_token_expiry: i64,
     refresh_token_expiry: i64,
+    session_metadata: SessionMetadata,
 }
 
 impl RefreshTokenUseCase {
@@ -32,9 +35,16 @@ impl RefreshTokenUseCase {
             auth_service,
             access_token_expiry,
             refresh_token_expiry,
+            session_metadata: SessionMetadata::default(),
         }
     }
 
+    /// Attach client details (user agent, IP, device name) to the issued session
+    pub fn with_session_metadata(mut self, session_metadata: SessionMetadata) -> Self {
+        self.session_metadata = session_metadata;
+        self
+    }
+
     pub async fn execute(
         &self,
         req: RefreshTokenRequest,
@@ -71,21 +81,54 @@ impl RefreshTokenUseCase {
             return Err(AppError::Unauthorized("Token user mismatch".to_string()));
         }
 
-        // Delete old refresh token (rotation)
-        self.refresh_token_repo
-            .delete_by_hash(&token_hash)
+        // A rotated token being presented again means it was replayed
+        if stored_token.rotated_at.is_some() {
+            return Err(self.revoke_family(&stored_token).await);
+        }
+
+        // Mark old refresh token as rotated; losing this race is also a replay
+        let rotated = self
+            .refresh_token_repo
+            .mark_rotated(stored_token.id)
             .await
             .map_err(AppError::InternalServerError)?;
 
+        if !rotated {
+            return Err(self.revoke_family(&stored_token).await);
+        }
+
         // Generate and store new token pair (preserve user_type)
         generate_and_store_tokens(
             user_id,
             claims.user_type,
             &self.auth_service,
             &self.refresh_token_repo,
+            SessionContext::rotation(&stored_token, &self.session_metadata),
             self.access_token_expiry,
             self.refresh_token_expiry,
         )
         .await
     }
+
+    /// Revoke every token in the family of a reused refresh token
+    async fn revoke_family(&self, reused_token: &RefreshToken) -> AppError {
+        tracing::warn!(
+            security_event = "refresh_token_reuse",
+            user_id = %reused_token.user_id,
+            user_type = %reused_token.user_type,
+            family_id = %reused_token.family_id,
+            token_id = %reused_token.id,
+            "Rotated refresh token reused, revoking token family"
+        );
+
+        if let Err(e) = self
+            .refresh_token_repo
+            .delete_by_family_id(reused_token.family_id)
+            .await
+        {
+            return AppError::InternalServerError(e);
+        }
+
+        AppError::Unauthorized("Refresh token reuse detected".to_string())
+    }
 }
@@ -1,5 +1,10 @@
-use crate::domain::auth::{AuthService, NewRefreshToken, RefreshTokenRepository};
+use crate::domain::auth::{
+    AuthService, NewRefreshToken, RefreshToken, RefreshTokenRepository, SessionMetadata,
+};
 use crate::shared::error::AppError;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use serde::Serialize;
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
@@ -15,6 +20,32 @@ pub struct TokenResponse {
     pub expires_in: i64,
 }
 
+/// Session a newly issued token pair belongs to
+pub struct SessionContext<'a> {
+    /// Client details of the current request
+    pub metadata: &'a SessionMetadata,
+    /// Refresh token being rotated; the new token joins its family
+    pub parent: Option<&'a RefreshToken>,
+}
+
+impl<'a> SessionContext<'a> {
+    /// Start a new session (token family)
+    pub fn new_session(metadata: &'a SessionMetadata) -> Self {
+        Self {
+            metadata,
+            parent: None,
+        }
+    }
+
+    /// Continue the session of a rotated refresh token
+    pub fn rotation(parent: &'a RefreshToken, metadata: &'a SessionMetadata) -> Self {
+        Self {
+            metadata,
+            parent: Some(parent),
+        }
+    }
+}
+
 /// Generate SHA-256 hash of a token string
 pub fn hash_token(token: &str) -> String {
     let mut hasher = Sha256::new();
@@ -22,12 +53,20 @@ pub fn hash_token(token: &str) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// Generate a random URL-safe token for out-of-band delivery (256 bits of entropy)
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
 /// Generate and store a complete token pair (access + refresh tokens)
 pub async fn generate_and_store_tokens(
     user_id: Uuid,
     user_type: String,
     auth_service: &Arc<dyn AuthService>,
     refresh_token_repo: &Arc<dyn RefreshTokenRepository>,
+    session: SessionContext<'_>,
     access_token_expiry: i64,
     refresh_token_expiry: i64,
 ) -> Result<TokenResponse, AppError> {
@@ -47,11 +86,24 @@ pub async fn generate_and_store_tokens(
     // Calculate expiration time
     let expires_at = OffsetDateTime::now_utc() + time::Duration::seconds(refresh_token_expiry);
 
+    // A rotated token keeps its device name unless the client sends a new one
+    let parent = session.parent;
+    let device_name = session
+        .metadata
+        .device_name
+        .clone()
+        .or_else(|| parent.and_then(|p| p.device_name.clone()));
+
     // Store refresh token hash in database
     let new_refresh_token = NewRefreshToken {
         user_id,
         user_type,
         token_hash,
+        family_id: parent.map_or_else(Uuid::new_v4, |p| p.family_id),
+        parent_id: parent.map(|p| p.id),
+        user_agent: session.metadata.user_agent.clone(),
+        ip_address: session.metadata.ip_address.clone(),
+        device_name,
         expires_at,
     };
 
@@ -78,4 +130,12 @@ mod tests {
         let hash = hash_token(token);
         assert_eq!(hash.len(), 64); // SHA-256 hex string length
     }
+
+    #[test]
+    fn test_generate_opaque_token_is_unique() {
+        let first = generate_opaque_token();
+        let second = generate_opaque_token();
+        assert_eq!(first.len(), 43);
+        assert_ne!(first, second);
+    }
 }
@@ -0,0 +1,565 @@
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// COSE algorithm identifier of ES256 (ECDSA P-256 with SHA-256)
+pub const COSE_ALG_ES256: i64 = -7;
+
+/// Relying party ID used by default, browsers accept it for local development only
+pub const DEFAULT_RP_ID: &str = "localhost";
+
+/// Relying party name shown by authenticators by default
+pub const DEFAULT_RP_NAME: &str = "Caxur";
+
+/// Origin the browser reports by default
+pub const DEFAULT_ORIGIN: &str = "http://localhost:3000";
+
+// Authenticator data flags (WebAuthn Level 2, section 6.1)
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+// COSE_Key labels and values (RFC 9053)
+const COSE_KEY_TYPE: i128 = 1;
+const COSE_KEY_ALG: i128 = 3;
+const COSE_EC2_CURVE: i128 = -1;
+const COSE_EC2_X: i128 = -2;
+const COSE_EC2_Y: i128 = -3;
+const COSE_KTY_EC2: i128 = 2;
+const COSE_CRV_P256: i128 = 1;
+
+/// Deepest CBOR nesting accepted, attestation objects need three levels
+const MAX_CBOR_DEPTH: usize = 8;
+
+/// Longest credential ID allowed by the WebAuthn specification
+const MAX_CREDENTIAL_ID_LENGTH: usize = 1023;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum WebAuthnError {
+    #[error("Malformed {0}")]
+    Malformed(&'static str),
+    #[error("Unexpected ceremony type")]
+    CeremonyType,
+    #[error("Origin does not match the relying party")]
+    Origin,
+    #[error("Credential is scoped to a different relying party")]
+    RpIdHash,
+    #[error("User presence or verification is missing")]
+    UserVerification,
+    #[error("Only ES256 credentials on P-256 are supported")]
+    UnsupportedKey,
+    #[error("Invalid signature")]
+    Signature,
+}
+
+/// The service passkeys are bound to
+#[derive(Debug, Clone)]
+pub struct RelyingParty {
+    /// Registrable domain, e.g. `example.com`
+    pub id: String,
+    pub name: String,
+    /// Exact origin of the admin frontend, e.g. `https://admin.example.com`
+    pub origin: String,
+}
+
+impl RelyingParty {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, origin: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            origin: origin.into(),
+        }
+    }
+}
+
+impl Default for RelyingParty {
+    fn default() -> Self {
+        Self::new(DEFAULT_RP_ID, DEFAULT_RP_NAME, DEFAULT_ORIGIN)
+    }
+}
+
+/// Which ceremony a client data object belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ceremony {
+    Registration,
+    Authentication,
+}
+
+impl Ceremony {
+    fn client_data_type(&self) -> &'static str {
+        match self {
+            Ceremony::Registration => "webauthn.create",
+            Ceremony::Authentication => "webauthn.get",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony: String,
+    challenge: String,
+    origin: String,
+}
+
+/// Check the type and origin of `clientDataJSON`, returning the challenge it signs
+pub fn verify_client_data(
+    rp: &RelyingParty,
+    client_data_json: &[u8],
+    ceremony: Ceremony,
+) -> Result<String, WebAuthnError> {
+    let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+        .map_err(|_| WebAuthnError::Malformed("client data"))?;
+
+    if client_data.ceremony != ceremony.client_data_type() {
+        return Err(WebAuthnError::CeremonyType);
+    }
+    if client_data.origin != rp.origin {
+        return Err(WebAuthnError::Origin);
+    }
+
+    Ok(client_data.challenge)
+}
+
+/// Credential created during registration
+#[derive(Debug, Clone)]
+pub struct AttestedCredential {
+    pub credential_id: Vec<u8>,
+    pub public_key: VerifyingKey,
+}
+
+/// Parsed authenticator data
+#[derive(Debug, Clone)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: [u8; 32],
+    pub flags: u8,
+    pub sign_count: u32,
+    pub attested_credential: Option<AttestedCredential>,
+}
+
+impl AuthenticatorData {
+    pub fn parse(bytes: &[u8]) -> Result<Self, WebAuthnError> {
+        let malformed = || WebAuthnError::Malformed("authenticator data");
+        if bytes.len() < 37 {
+            return Err(malformed());
+        }
+
+        let rp_id_hash: [u8; 32] = bytes[..32].try_into().map_err(|_| malformed())?;
+        let flags = bytes[32];
+        let sign_count = read_be(&bytes[33..37]) as u32;
+
+        let attested_credential = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+            // AAGUID (16 bytes), credential ID length (2 bytes), credential ID, COSE key
+            let rest = bytes.get(37 + 16..).ok_or_else(malformed)?;
+            let id_len = read_be(rest.get(..2).ok_or_else(malformed)?) as usize;
+            if id_len > MAX_CREDENTIAL_ID_LENGTH {
+                return Err(malformed());
+            }
+            let credential_id = rest.get(2..2 + id_len).ok_or_else(malformed)?.to_vec();
+            let (cose_key, _) = decode_cbor(&rest[2 + id_len..], 0)?;
+
+            Some(AttestedCredential {
+                credential_id,
+                public_key: cose_key_to_verifying_key(&cose_key)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+            attested_credential,
+        })
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_USER_PRESENT != 0
+    }
+
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_USER_VERIFIED != 0
+    }
+
+    /// Check the RP ID hash and that the user was present and verified
+    fn verify(&self, rp: &RelyingParty) -> Result<(), WebAuthnError> {
+        let expected: [u8; 32] = Sha256::digest(rp.id.as_bytes()).into();
+        if self.rp_id_hash != expected {
+            return Err(WebAuthnError::RpIdHash);
+        }
+        if !self.user_present() || !self.user_verified() {
+            return Err(WebAuthnError::UserVerification);
+        }
+
+        Ok(())
+    }
+}
+
+/// Verify the attestation object of a registration and extract the new credential.
+///
+/// Registration options ask for `attestation: "none"`, so the attestation
+/// statement itself is not checked.
+pub fn verify_registration(
+    rp: &RelyingParty,
+    attestation_object: &[u8],
+) -> Result<(AttestedCredential, u32), WebAuthnError> {
+    let (attestation, _) = decode_cbor(attestation_object, 0)?;
+    let auth_data = match attestation.get_text_key("authData") {
+        Some(CborValue::Bytes(bytes)) => bytes,
+        _ => return Err(WebAuthnError::Malformed("attestation object")),
+    };
+
+    let auth_data = AuthenticatorData::parse(auth_data)?;
+    auth_data.verify(rp)?;
+
+    let credential = auth_data
+        .attested_credential
+        .ok_or(WebAuthnError::Malformed("attestation object"))?;
+    Ok((credential, auth_data.sign_count))
+}
+
+/// Verify an assertion signature over `authenticatorData || SHA-256(clientDataJSON)`
+pub fn verify_assertion(
+    rp: &RelyingParty,
+    public_key: &VerifyingKey,
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    signature: &[u8],
+) -> Result<AuthenticatorData, WebAuthnError> {
+    let parsed = AuthenticatorData::parse(authenticator_data)?;
+    parsed.verify(rp)?;
+
+    let signature = Signature::from_der(signature).map_err(|_| WebAuthnError::Signature)?;
+    let mut signed = authenticator_data.to_vec();
+    signed.extend_from_slice(&Sha256::digest(client_data_json));
+
+    public_key
+        .verify(&signed, &signature)
+        .map_err(|_| WebAuthnError::Signature)?;
+
+    Ok(parsed)
+}
+
+/// Encode a public key for storage as an uncompressed SEC1 point (base64url)
+pub fn encode_public_key(public_key: &VerifyingKey) -> String {
+    URL_SAFE_NO_PAD.encode(public_key.to_encoded_point(false).as_bytes())
+}
+
+/// Decode a public key stored by [`encode_public_key`]
+pub fn decode_public_key(encoded: &str) -> Result<VerifyingKey, WebAuthnError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| WebAuthnError::Malformed("public key"))?;
+    VerifyingKey::from_sec1_bytes(&bytes).map_err(|_| WebAuthnError::Malformed("public key"))
+}
+
+fn cose_key_to_verifying_key(key: &CborValue) -> Result<VerifyingKey, WebAuthnError> {
+    let int = |label| match key.get_int_key(label) {
+        Some(CborValue::Integer(value)) => Some(*value),
+        _ => None,
+    };
+    let bytes = |label| match key.get_int_key(label) {
+        Some(CborValue::Bytes(value)) if value.len() == 32 => Some(value.as_slice()),
+        _ => None,
+    };
+
+    if int(COSE_KEY_TYPE) != Some(COSE_KTY_EC2)
+        || int(COSE_KEY_ALG) != Some(COSE_ALG_ES256 as i128)
+        || int(COSE_EC2_CURVE) != Some(COSE_CRV_P256)
+    {
+        return Err(WebAuthnError::UnsupportedKey);
+    }
+
+    let (x, y) = bytes(COSE_EC2_X)
+        .zip(bytes(COSE_EC2_Y))
+        .ok_or(WebAuthnError::UnsupportedKey)?;
+    let mut point = Vec::with_capacity(65);
+    point.push(0x04);
+    point.extend_from_slice(x);
+    point.extend_from_slice(y);
+
+    VerifyingKey::from_sec1_bytes(&point).map_err(|_| WebAuthnError::UnsupportedKey)
+}
+
+/// The subset of CBOR (RFC 8949) used by attestation objects and COSE keys
+#[derive(Debug, Clone, PartialEq)]
+enum CborValue {
+    Integer(i128),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<CborValue>),
+    Map(Vec<(CborValue, CborValue)>),
+    Bool(bool),
+    Null,
+}
+
+impl CborValue {
+    fn get_text_key(&self, key: &str) -> Option<&CborValue> {
+        match self {
+            CborValue::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, CborValue::Text(text) if text == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn get_int_key(&self, key: i128) -> Option<&CborValue> {
+        match self {
+            CborValue::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| *k == CborValue::Integer(key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// Read a big-endian unsigned integer of up to 8 bytes
+fn read_be(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |value, b| (value << 8) | *b as u64)
+}
+
+/// Decode one definite-length CBOR item, returning it and the number of bytes read
+fn decode_cbor(bytes: &[u8], depth: usize) -> Result<(CborValue, usize), WebAuthnError> {
+    let malformed = || WebAuthnError::Malformed("CBOR");
+    if depth > MAX_CBOR_DEPTH {
+        return Err(malformed());
+    }
+
+    let initial = *bytes.first().ok_or_else(malformed)?;
+    let major = initial >> 5;
+    let info = initial & 0x1f;
+
+    let (argument, mut offset) = match info {
+        0..=23 => (info as u64, 1),
+        24..=27 => {
+            let len = 1usize << (info - 24);
+            let argument = read_be(bytes.get(1..1 + len).ok_or_else(malformed)?);
+            (argument, 1 + len)
+        }
+        // Indefinite lengths and reserved values are not used by authenticators
+        _ => return Err(malformed()),
+    };
+
+    let mut take = |len: u64| -> Result<&[u8], WebAuthnError> {
+        let end = usize::try_from(len)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(malformed)?;
+        let slice = bytes.get(offset..end).ok_or_else(malformed)?;
+        offset = end;
+        Ok(slice)
+    };
+
+    let value = match major {
+        0 => CborValue::Integer(argument as i128),
+        1 => CborValue::Integer(-1 - argument as i128),
+        2 => CborValue::Bytes(take(argument)?.to_vec()),
+        3 => CborValue::Text(String::from_utf8(take(argument)?.to_vec()).map_err(|_| malformed())?),
+        4 => {
+            let mut items = Vec::new();
+            for _ in 0..argument {
+                let (item, read) =
+                    decode_cbor(bytes.get(offset..).ok_or_else(malformed)?, depth + 1)?;
+                offset += read;
+                items.push(item);
+            }
+            CborValue::Array(items)
+        }
+        5 => {
+            let mut entries = Vec::new();
+            for _ in 0..argument {
+                let (key, read) =
+                    decode_cbor(bytes.get(offset..).ok_or_else(malformed)?, depth + 1)?;
+                offset += read;
+                let (value, read) =
+                    decode_cbor(bytes.get(offset..).ok_or_else(malformed)?, depth + 1)?;
+                offset += read;
+                entries.push((key, value));
+            }
+            CborValue::Map(entries)
+        }
+        7 => match info {
+            20 => CborValue::Bool(false),
+            21 => CborValue::Bool(true),
+            22 => CborValue::Null,
+            _ => return Err(malformed()),
+        },
+        // Tags are not used by authenticators
+        _ => return Err(malformed()),
+    };
+
+    Ok((value, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::{SigningKey, signature::Signer};
+    use p256::elliptic_curve::rand_core::OsRng;
+
+    fn cose_key(public_key: &VerifyingKey) -> Vec<u8> {
+        let point = public_key.to_encoded_point(false);
+        let mut key = vec![0xa5, 0x01, 0x02, 0x03, 0x26, 0x20, 0x01, 0x21, 0x58, 0x20];
+        key.extend_from_slice(point.x().unwrap());
+        key.extend_from_slice(&[0x22, 0x58, 0x20]);
+        key.extend_from_slice(point.y().unwrap());
+        key
+    }
+
+    fn authenticator_data(
+        rp: &RelyingParty,
+        flags: u8,
+        attested: Option<&VerifyingKey>,
+    ) -> Vec<u8> {
+        let mut data = Sha256::digest(rp.id.as_bytes()).to_vec();
+        data.push(flags);
+        data.extend_from_slice(&7u32.to_be_bytes());
+        if let Some(public_key) = attested {
+            data.extend_from_slice(&[0u8; 16]);
+            data.extend_from_slice(&4u16.to_be_bytes());
+            data.extend_from_slice(b"cred");
+            data.extend_from_slice(&cose_key(public_key));
+        }
+        data
+    }
+
+    #[test]
+    fn test_verify_registration_extracts_credential() {
+        let rp = RelyingParty::default();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let auth_data = authenticator_data(&rp, 0x45, Some(signing_key.verifying_key()));
+
+        // {"fmt": "none", "attStmt": {}, "authData": h'...'}
+        let mut attestation = vec![0xa3, 0x63];
+        attestation.extend_from_slice(b"fmt");
+        attestation.push(0x64);
+        attestation.extend_from_slice(b"none");
+        attestation.push(0x67);
+        attestation.extend_from_slice(b"attStmt");
+        attestation.extend_from_slice(&[0xa0, 0x68]);
+        attestation.extend_from_slice(b"authData");
+        attestation.extend_from_slice(&[0x59, (auth_data.len() >> 8) as u8, auth_data.len() as u8]);
+        attestation.extend_from_slice(&auth_data);
+
+        let (credential, sign_count) = verify_registration(&rp, &attestation).unwrap();
+        assert_eq!(credential.credential_id, b"cred");
+        assert_eq!(&credential.public_key, signing_key.verifying_key());
+        assert_eq!(sign_count, 7);
+
+        let other_rp = RelyingParty::new("example.com", "Example", "https://example.com");
+        assert_eq!(
+            verify_registration(&other_rp, &attestation).unwrap_err(),
+            WebAuthnError::RpIdHash
+        );
+        assert!(verify_registration(&rp, &attestation[..40]).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_oversized_credential_id() {
+        let rp = RelyingParty::default();
+        let signing_key = SigningKey::random(&mut OsRng);
+
+        let mut auth_data = authenticator_data(&rp, 0x45, None);
+        auth_data.extend_from_slice(&[0u8; 16]);
+        auth_data.extend_from_slice(&1024u16.to_be_bytes());
+        auth_data.extend_from_slice(&[0u8; 1024]);
+        auth_data.extend_from_slice(&cose_key(signing_key.verifying_key()));
+
+        assert_eq!(
+            AuthenticatorData::parse(&auth_data).unwrap_err(),
+            WebAuthnError::Malformed("authenticator data")
+        );
+    }
+
+    #[test]
+    fn test_verify_assertion_checks_signature_and_flags() {
+        let rp = RelyingParty::default();
+        let signing_key = SigningKey::random(&mut OsRng);
+        let client_data =
+            br#"{"type":"webauthn.get","challenge":"abc","origin":"http://localhost:3000"}"#;
+
+        let sign = |auth_data: &[u8]| -> Vec<u8> {
+            let mut signed = auth_data.to_vec();
+            signed.extend_from_slice(&Sha256::digest(client_data));
+            let signature: Signature = signing_key.sign(&signed);
+            signature.to_der().as_bytes().to_vec()
+        };
+
+        let auth_data = authenticator_data(&rp, 0x05, None);
+        let parsed = verify_assertion(
+            &rp,
+            signing_key.verifying_key(),
+            &auth_data,
+            client_data,
+            &sign(&auth_data),
+        )
+        .unwrap();
+        assert_eq!(parsed.sign_count, 7);
+
+        let tampered =
+            br#"{"type":"webauthn.get","challenge":"xyz","origin":"http://localhost:3000"}"#;
+        assert_eq!(
+            verify_assertion(
+                &rp,
+                signing_key.verifying_key(),
+                &auth_data,
+                tampered,
+                &sign(&auth_data)
+            )
+            .unwrap_err(),
+            WebAuthnError::Signature
+        );
+
+        let unverified = authenticator_data(&rp, 0x01, None);
+        assert_eq!(
+            verify_assertion(
+                &rp,
+                signing_key.verifying_key(),
+                &unverified,
+                client_data,
+                &sign(&unverified)
+            )
+            .unwrap_err(),
+            WebAuthnError::UserVerification
+        );
+    }
+
+    #[test]
+    fn test_verify_client_data() {
+        let rp = RelyingParty::default();
+        let client_data =
+            br#"{"type":"webauthn.create","challenge":"abc","origin":"http://localhost:3000"}"#;
+
+        assert_eq!(
+            verify_client_data(&rp, client_data, Ceremony::Registration).unwrap(),
+            "abc"
+        );
+        assert_eq!(
+            verify_client_data(&rp, client_data, Ceremony::Authentication).unwrap_err(),
+            WebAuthnError::CeremonyType
+        );
+
+        let other_origin =
+            br#"{"type":"webauthn.create","challenge":"abc","origin":"https://evil.test"}"#;
+        assert_eq!(
+            verify_client_data(&rp, other_origin, Ceremony::Registration).unwrap_err(),
+            WebAuthnError::Origin
+        );
+    }
+
+    #[test]
+    fn test_public_key_round_trip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let encoded = encode_public_key(signing_key.verifying_key());
+        assert_eq!(
+            &decode_public_key(&encoded).unwrap(),
+            signing_key.verifying_key()
+        );
+        assert!(decode_public_key("not-a-key").is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::domain::groups::{Group, GroupRepository, NewGroup};
+use crate::shared::error::{AppError, FieldError};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct CreateGroupRequest {
+    #[validate(length(
+        min = 3,
+        max = 255,
+        message = "Group name must be between 3 and 255 characters"
+    ))]
+    #[schema(example = "Acme Corp", min_length = 3, max_length = 255)]
+    pub name: String,
+    #[schema(example = "Customer organization")]
+    pub description: Option<String>,
+}
+
+impl CreateGroupRequest {
+    /// Custom async validation to check if group name already exists
+    pub async fn validate_unique_name(
+        &self,
+        repo: &Arc<dyn GroupRepository>,
+    ) -> Result<(), AppError> {
+        if repo.find_by_name(&self.name).await?.is_some() {
+            return Err(AppError::ValidationError(vec![FieldError::new(
+                "name",
+                "Group name already exists",
+            )]));
+        }
+        Ok(())
+    }
+}
+
+pub struct CreateGroupUseCase {
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl CreateGroupUseCase {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, req: CreateGroupRequest) -> Result<Group, AppError> {
+        req.validate_unique_name(&self.repo).await?;
+
+        let new_group = NewGroup {
+            name: req.name,
+            description: req.description,
+        };
+
+        Ok(self.repo.create(new_group).await?)
+    }
+}
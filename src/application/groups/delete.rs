@@ -0,0 +1,23 @@
+use crate::domain::groups::GroupRepository;
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DeleteGroupUseCase {
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl DeleteGroupUseCase {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Deleting a group also deletes its roles
+    #[tracing::instrument(skip(self))]
+    pub async fn execute(&self, id: Uuid) -> Result<(), AppError> {
+        if !self.repo.delete(id).await? {
+            return Err(AppError::NotFound("Group not found".to_string()));
+        }
+        Ok(())
+    }
+}
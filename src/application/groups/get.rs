@@ -0,0 +1,22 @@
+use crate::domain::groups::{Group, GroupRepository};
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GetGroupUseCase {
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl GetGroupUseCase {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn execute(&self, id: Uuid) -> Result<Group, AppError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Group not found".to_string()))
+    }
+}
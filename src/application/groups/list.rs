@@ -0,0 +1,22 @@
+use crate::domain::groups::{Group, GroupRepository};
+use crate::shared::error::AppError;
+use std::sync::Arc;
+
+pub struct ListGroupsUseCase {
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl ListGroupsUseCase {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn execute(&self, per_page: i64, page: i64) -> Result<Vec<Group>, AppError> {
+        let per_page = per_page.clamp(1, 100);
+        let page = page.max(1);
+        let offset = (page - 1) * per_page;
+
+        Ok(self.repo.find_all(per_page, offset).await?)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::domain::groups::GroupRepository;
+use crate::domain::users::{User, UserRepository};
+use crate::shared::error::{AppError, FieldError};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, serde::Deserialize, Validate, ToSchema)]
+pub struct GroupMembersRequest {
+    #[validate(length(min = 1, message = "At least one user must be provided"))]
+    pub user_ids: Vec<Uuid>,
+}
+
+pub struct ListGroupMembers {
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl ListGroupMembers {
+    pub fn new(group_repo: Arc<dyn GroupRepository>) -> Self {
+        Self { group_repo }
+    }
+
+    pub async fn execute(&self, group_id: Uuid) -> Result<Vec<User>, AppError> {
+        find_group(&self.group_repo, group_id).await?;
+        Ok(self.group_repo.find_members(group_id).await?)
+    }
+}
+
+pub struct AddGroupMembers {
+    group_repo: Arc<dyn GroupRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl AddGroupMembers {
+    pub fn new(group_repo: Arc<dyn GroupRepository>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self {
+            group_repo,
+            user_repo,
+        }
+    }
+
+    pub async fn execute(&self, group_id: Uuid, user_ids: Vec<Uuid>) -> Result<(), AppError> {
+        find_group(&self.group_repo, group_id).await?;
+
+        for user_id in &user_ids {
+            if self.user_repo.find_by_id(*user_id).await?.is_none() {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "user_ids",
+                    format!("User {} does not exist", user_id),
+                )]));
+            }
+        }
+
+        self.group_repo.add_members(group_id, user_ids).await?;
+        Ok(())
+    }
+}
+
+pub struct RemoveGroupMembers {
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl RemoveGroupMembers {
+    pub fn new(group_repo: Arc<dyn GroupRepository>) -> Self {
+        Self { group_repo }
+    }
+
+    pub async fn execute(&self, group_id: Uuid, user_ids: Vec<Uuid>) -> Result<(), AppError> {
+        find_group(&self.group_repo, group_id).await?;
+        self.group_repo.remove_members(group_id, user_ids).await?;
+        Ok(())
+    }
+}
+
+async fn find_group(repo: &Arc<dyn GroupRepository>, group_id: Uuid) -> Result<(), AppError> {
+    repo.find_by_id(group_id)
+        .await?
+        .map(|_| ())
+        .ok_or_else(|| AppError::NotFound("Group not found".to_string()))
+}
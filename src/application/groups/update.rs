@@ -0,0 +1,55 @@
+use crate::domain::groups::{Group, GroupRepository, UpdateGroup};
+use crate::shared::error::{AppError, FieldError};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, ToSchema)]
+pub struct UpdateGroupRequest {
+    #[validate(length(
+        min = 3,
+        max = 255,
+        message = "Group name must be between 3 and 255 characters"
+    ))]
+    #[schema(example = "Acme Corp", min_length = 3, max_length = 255)]
+    pub name: Option<String>,
+    #[schema(example = "Customer organization")]
+    pub description: Option<String>,
+}
+
+pub struct UpdateGroupUseCase {
+    repo: Arc<dyn GroupRepository>,
+}
+
+impl UpdateGroupUseCase {
+    pub fn new(repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self, req))]
+    pub async fn execute(&self, id: Uuid, req: UpdateGroupRequest) -> Result<Group, AppError> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Group not found".to_string()))?;
+
+        if let Some(ref name) = req.name
+            && let Some(duplicate) = self.repo.find_by_name(name).await?
+            && duplicate.id != id
+        {
+            return Err(AppError::ValidationError(vec![FieldError::new(
+                "name",
+                "Group name already exists",
+            )]));
+        }
+
+        let update = UpdateGroup {
+            name: req.name,
+            description: req.description,
+        };
+
+        Ok(self.repo.update(id, update).await?)
+    }
+}
@@ -0,0 +1 @@
+pub mod purge_expired_tokens;
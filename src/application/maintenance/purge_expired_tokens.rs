@@ -0,0 +1,120 @@
+use crate::domain::administrators::AdministratorRepository;
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
+use crate::domain::login_attempts::LoginAttemptRepository;
+use crate::domain::maintenance::MaintenanceJob;
+use crate::domain::one_time_tokens::OneTimeTokenRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Deletes refresh tokens past their expiry
+pub struct PurgeExpiredRefreshTokensJob {
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+}
+
+impl PurgeExpiredRefreshTokensJob {
+    pub fn new(refresh_token_repo: Arc<dyn RefreshTokenRepository>) -> Self {
+        Self { refresh_token_repo }
+    }
+}
+
+#[async_trait]
+impl MaintenanceJob for PurgeExpiredRefreshTokensJob {
+    fn name(&self) -> &'static str {
+        "purge_expired_refresh_tokens"
+    }
+
+    async fn run(&self) -> Result<u64> {
+        self.refresh_token_repo.delete_expired().await
+    }
+}
+
+/// Deletes access token denylist entries whose tokens have expired anyway
+pub struct PurgeExpiredDenylistEntriesJob {
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+}
+
+impl PurgeExpiredDenylistEntriesJob {
+    pub fn new(access_token_denylist: Arc<dyn AccessTokenDenylist>) -> Self {
+        Self {
+            access_token_denylist,
+        }
+    }
+}
+
+#[async_trait]
+impl MaintenanceJob for PurgeExpiredDenylistEntriesJob {
+    fn name(&self) -> &'static str {
+        "purge_expired_denylist_entries"
+    }
+
+    async fn run(&self) -> Result<u64> {
+        self.access_token_denylist.delete_expired().await
+    }
+}
+
+/// Deletes expired one-time tokens such as password reset tokens
+pub struct PurgeExpiredOneTimeTokensJob {
+    token_repo: Arc<dyn OneTimeTokenRepository>,
+}
+
+impl PurgeExpiredOneTimeTokensJob {
+    pub fn new(token_repo: Arc<dyn OneTimeTokenRepository>) -> Self {
+        Self { token_repo }
+    }
+}
+
+#[async_trait]
+impl MaintenanceJob for PurgeExpiredOneTimeTokensJob {
+    fn name(&self) -> &'static str {
+        "purge_expired_one_time_tokens"
+    }
+
+    async fn run(&self) -> Result<u64> {
+        self.token_repo.delete_expired().await
+    }
+}
+
+/// Deletes failed login counters that are past their window and lockout
+pub struct PurgeExpiredLoginAttemptsJob {
+    attempt_repo: Arc<dyn LoginAttemptRepository>,
+}
+
+impl PurgeExpiredLoginAttemptsJob {
+    pub fn new(attempt_repo: Arc<dyn LoginAttemptRepository>) -> Self {
+        Self { attempt_repo }
+    }
+}
+
+#[async_trait]
+impl MaintenanceJob for PurgeExpiredLoginAttemptsJob {
+    fn name(&self) -> &'static str {
+        "purge_expired_login_attempts"
+    }
+
+    async fn run(&self) -> Result<u64> {
+        self.attempt_repo.delete_expired().await
+    }
+}
+
+/// Deletes temporary administrator role assignments that have expired
+pub struct PurgeExpiredRoleAssignmentsJob {
+    admin_repo: Arc<dyn AdministratorRepository>,
+}
+
+impl PurgeExpiredRoleAssignmentsJob {
+    pub fn new(admin_repo: Arc<dyn AdministratorRepository>) -> Self {
+        Self { admin_repo }
+    }
+}
+
+#[async_trait]
+impl MaintenanceJob for PurgeExpiredRoleAssignmentsJob {
+    fn name(&self) -> &'static str {
+        "purge_expired_role_assignments"
+    }
+
+    async fn run(&self) -> Result<u64> {
+        self.admin_repo.delete_expired_role_assignments().await
+    }
+}
@@ -1,5 +1,8 @@
 pub mod administrators;
 pub mod auth;
+pub mod groups;
+pub mod maintenance;
 pub mod permissions;
 pub mod roles;
+pub mod sessions;
 pub mod users;
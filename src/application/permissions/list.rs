@@ -29,6 +29,9 @@ pub struct ListPermissionsRequest {
     /// Pagination parameters
     #[serde(default)]
     pub page: PageParams,
+    /// Only list permissions assignable to roles of this scope
+    #[serde(default)]
+    pub scope: Option<AccessScope>,
 }
 
 #[derive(Serialize, ToSchema, Clone)]
@@ -109,16 +112,13 @@ mod tests {
         let use_case = ListPermissionsUseCase::new();
         let req = ListPermissionsRequest {
             page: PageParams::default(),
+            scope: None,
         };
         let permissions = use_case.execute(req);
 
-        assert_eq!(permissions.len(), 3);
-        assert!(
-            permissions
-                .iter()
-                .any(|p| p.name == "administrator_management")
-        );
-        assert!(permissions.iter().any(|p| p.name == "role_management"));
+        assert_eq!(permissions.len(), 20);
+        assert!(permissions.iter().any(|p| p.name == "administrators.read"));
+        assert!(permissions.iter().any(|p| p.name == "administrators.*"));
         assert!(permissions.iter().any(|p| p.name == "*"));
     }
 
@@ -127,16 +127,12 @@ mod tests {
         let use_case = ListPermissionsUseCase::new().with_scope(AccessScope::Administrator);
         let req = ListPermissionsRequest {
             page: PageParams::default(),
+            scope: None,
         };
         let permissions = use_case.execute(req);
 
-        // Since all permissions currently have ADMINISTRATOR scope
-        assert_eq!(permissions.len(), 3);
-        assert!(
-            permissions
-                .iter()
-                .any(|p| p.name == "administrator_management")
-        );
+        assert_eq!(permissions.len(), 20);
+        assert!(permissions.iter().any(|p| p.name == "users.read"));
     }
 
     #[test]
@@ -146,6 +142,7 @@ mod tests {
         // Page 1, size 1
         let req1 = ListPermissionsRequest {
             page: PageParams { number: 1, size: 1 },
+            scope: None,
         };
         let permissions1 = use_case.execute(req1);
         assert_eq!(permissions1.len(), 1);
@@ -153,6 +150,7 @@ mod tests {
         // Page 2, size 1
         let req2 = ListPermissionsRequest {
             page: PageParams { number: 2, size: 1 },
+            scope: None,
         };
         let permissions2 = use_case.execute(req2);
         assert_eq!(permissions2.len(), 1);
@@ -164,6 +162,7 @@ mod tests {
                 number: 10,
                 size: 10,
             },
+            scope: None,
         };
         let permissions_empty = use_case.execute(req_empty);
         assert!(permissions_empty.is_empty());
@@ -174,25 +173,41 @@ mod tests {
         let use_case = ListPermissionsUseCase::new();
         let req = ListPermissionsRequest {
             page: PageParams::default(),
+            scope: None,
         };
         let permissions = use_case.execute(req);
 
-        let admin_mgmt = permissions
+        let admin_read = permissions
             .iter()
-            .find(|p| p.name == "administrator_management")
+            .find(|p| p.name == "administrators.read")
             .unwrap();
-        assert_eq!(admin_mgmt.description, "Manage administrators");
+        assert_eq!(admin_read.description, "View administrators");
     }
 
     #[test]
     fn test_count_no_scope() {
         let use_case = ListPermissionsUseCase::new();
-        assert_eq!(use_case.count(), 3);
+        assert_eq!(use_case.count(), Permission::all().len() as i64);
     }
 
     #[test]
     fn test_count_with_scope() {
-        let use_case = ListPermissionsUseCase::new().with_scope(AccessScope::Administrator);
-        assert_eq!(use_case.count(), 3);
+        let administrator = ListPermissionsUseCase::new().with_scope(AccessScope::Administrator);
+        let user = ListPermissionsUseCase::new().with_scope(AccessScope::User);
+
+        assert!(administrator.count() < ListPermissionsUseCase::new().count());
+        assert!(user.count() < administrator.count());
+
+        let user_permissions = user.execute(ListPermissionsRequest {
+            page: PageParams::default(),
+            scope: None,
+        });
+        assert!(user_permissions.iter().any(|p| p.name == "users.delete"));
+        assert!(
+            user_permissions
+                .iter()
+                .any(|p| p.name == "groups.members.add")
+        );
+        assert!(!user_permissions.iter().any(|p| p.name == "users.unlock"));
     }
 }
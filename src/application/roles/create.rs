@@ -1,4 +1,5 @@
 use crate::domain::access_scope::AccessScope;
+use crate::domain::groups::GroupRepository;
 use crate::domain::roles::{NewRole, Role, RoleRepository};
 use crate::shared::error::{AppError, FieldError};
 use serde::Deserialize;
@@ -24,7 +25,7 @@ pub struct CreateRoleRequest {
     #[schema(example = "Administrator role with full permissions")]
     pub description: Option<String>,
     #[serde(default = "default_scope")]
-    #[schema(example = "ADMINISTRATOR")]
+    #[schema(example = "administrator")]
     pub scope: AccessScope,
     #[schema(example = "00000000-0000-0000-0000-000000000000")]
     pub group_id: Option<Uuid>,
@@ -52,11 +53,12 @@ impl CreateRoleRequest {
 
 pub struct CreateRoleUseCase {
     repo: Arc<dyn RoleRepository>,
+    group_repo: Arc<dyn GroupRepository>,
 }
 
 impl CreateRoleUseCase {
-    pub fn new(repo: Arc<dyn RoleRepository>) -> Self {
-        Self { repo }
+    pub fn new(repo: Arc<dyn RoleRepository>, group_repo: Arc<dyn GroupRepository>) -> Self {
+        Self { repo, group_repo }
     }
 
     #[tracing::instrument(skip(self, req))]
@@ -64,6 +66,22 @@ impl CreateRoleUseCase {
         // Validate unique name
         req.validate_unique_name(&self.repo).await?;
 
+        // Group roles are held by group members, which are users
+        if let Some(group_id) = req.group_id {
+            if req.scope != AccessScope::User {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "scope",
+                    "Group roles must have the user scope",
+                )]));
+            }
+            if self.group_repo.find_by_id(group_id).await?.is_none() {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "group_id",
+                    "Group does not exist",
+                )]));
+            }
+        }
+
         let new_role = NewRole {
             name: req.name,
             description: req.description,
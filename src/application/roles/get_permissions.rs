@@ -1,9 +1,20 @@
 use crate::domain::permissions::Permission;
 use crate::domain::roles::RoleRepository;
 use crate::shared::error::AppError;
+use serde::Serialize;
 use std::sync::Arc;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+/// Permissions of a role, split by where they come from
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RolePermissions {
+    /// Attached to the role itself
+    pub direct: Vec<Permission>,
+    /// Inherited from ancestor roles and not attached directly
+    pub inherited: Vec<Permission>,
+}
+
 pub struct GetRolePermissionsUseCase {
     repo: Arc<dyn RoleRepository>,
 }
@@ -14,13 +25,22 @@ impl GetRolePermissionsUseCase {
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn execute(&self, role_id: Uuid) -> Result<Vec<Permission>, AppError> {
+    pub async fn execute(&self, role_id: Uuid) -> Result<RolePermissions, AppError> {
         // Check if role exists
         self.repo
             .find_by_id(role_id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Role with id {} not found", role_id)))?;
 
-        Ok(self.repo.get_permissions(role_id).await?)
+        let direct = self.repo.get_direct_permissions(role_id).await?;
+        let inherited = self
+            .repo
+            .get_permissions(role_id)
+            .await?
+            .into_iter()
+            .filter(|permission| !direct.contains(permission))
+            .collect();
+
+        Ok(RolePermissions { direct, inherited })
     }
 }
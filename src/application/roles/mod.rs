@@ -5,4 +5,5 @@ pub mod detach_permission;
 pub mod get;
 pub mod get_permissions;
 pub mod list;
+pub mod parents;
 pub mod update;
@@ -0,0 +1,87 @@
+use crate::domain::roles::RoleRepository;
+use crate::shared::error::{AppError, FieldError};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ParentRolesRequest {
+    #[validate(length(min = 1, message = "At least one parent role must be provided"))]
+    pub parent_ids: Vec<Uuid>,
+}
+
+/// Make a role inherit the permissions of other roles
+pub struct AttachParentRolesUseCase {
+    repo: Arc<dyn RoleRepository>,
+}
+
+impl AttachParentRolesUseCase {
+    pub fn new(repo: Arc<dyn RoleRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn execute(&self, role_id: Uuid, parent_ids: Vec<Uuid>) -> Result<(), AppError> {
+        let role = self
+            .repo
+            .find_by_id(role_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Role with id {} not found", role_id)))?;
+
+        let invalid = |message: String| {
+            AppError::ValidationError(vec![FieldError::new("parent_ids", message)])
+        };
+
+        for parent_id in &parent_ids {
+            let parent = self
+                .repo
+                .find_by_id(*parent_id)
+                .await?
+                .ok_or_else(|| invalid(format!("Role {} does not exist", parent_id)))?;
+
+            // Group roles may extend global roles, never the roles of another group
+            if parent.scope != role.scope
+                || parent.group_id.is_some_and(|id| Some(id) != role.group_id)
+            {
+                return Err(invalid(format!(
+                    "Role {} can't be inherited by this role",
+                    parent_id
+                )));
+            }
+        }
+
+        // The cycle check runs with the insert so concurrent requests can't race it
+        if let Some(parent_id) = self.repo.attach_parents(role_id, parent_ids).await? {
+            return Err(invalid(format!(
+                "Inheriting from role {} would create a cycle",
+                parent_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct DetachParentRolesUseCase {
+    repo: Arc<dyn RoleRepository>,
+}
+
+impl DetachParentRolesUseCase {
+    pub fn new(repo: Arc<dyn RoleRepository>) -> Self {
+        Self { repo }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn execute(&self, role_id: Uuid, parent_ids: Vec<Uuid>) -> Result<(), AppError> {
+        self.repo
+            .find_by_id(role_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Role with id {} not found", role_id)))?;
+
+        self.repo.detach_parents(role_id, parent_ids).await?;
+
+        Ok(())
+    }
+}
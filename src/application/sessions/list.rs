@@ -0,0 +1,21 @@
+use crate::domain::auth::{RefreshTokenRepository, Session};
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListSessionsUseCase {
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+}
+
+impl ListSessionsUseCase {
+    pub fn new(refresh_token_repo: Arc<dyn RefreshTokenRepository>) -> Self {
+        Self { refresh_token_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, user_type: &str) -> Result<Vec<Session>, AppError> {
+        self.refresh_token_repo
+            .find_sessions(user_id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)
+    }
+}
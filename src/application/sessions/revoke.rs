@@ -0,0 +1,39 @@
+use crate::domain::auth::RefreshTokenRepository;
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeSessionUseCase {
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(refresh_token_repo: Arc<dyn RefreshTokenRepository>) -> Self {
+        Self { refresh_token_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: Uuid,
+        user_type: &str,
+        session_id: Uuid,
+    ) -> Result<(), AppError> {
+        // Only sessions belonging to the given user can be revoked
+        let sessions = self
+            .refresh_token_repo
+            .find_sessions(user_id, user_type)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        if !sessions.iter().any(|session| session.id == session_id) {
+            return Err(AppError::NotFound("Session not found".to_string()));
+        }
+
+        self.refresh_token_repo
+            .delete_by_family_id(session_id)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        Ok(())
+    }
+}
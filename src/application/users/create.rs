@@ -1,6 +1,8 @@
+use crate::application::auth::email_verification::EmailVerificationSender;
 use crate::domain::password::PasswordHashingService;
 use crate::domain::users::{NewUser, User, UserRepository};
 use crate::shared::error::{AppError, FieldError};
+use crate::shared::validation::PasswordPolicy;
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -14,8 +16,8 @@ pub struct CreateUserRequest {
     #[validate(email(message = "Invalid email format"))]
     #[schema(example = "john@example.com")]
     pub email: String,
-    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
-    #[schema(example = "password123", min_length = 6)]
+    /// Checked against the configured password policy
+    #[schema(example = "correct horse battery")]
     pub password: String,
 }
 
@@ -38,6 +40,8 @@ impl CreateUserRequest {
 pub struct CreateUserUseCase {
     repo: Arc<dyn UserRepository>,
     password_hasher: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
+    email_verification: Option<EmailVerificationSender>,
 }
 
 impl CreateUserUseCase {
@@ -48,11 +52,28 @@ impl CreateUserUseCase {
         Self {
             repo,
             password_hasher,
+            password_policy: Arc::default(),
+            email_verification: None,
         }
     }
 
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    /// Send a verification token to the new user's email address
+    pub fn with_email_verification(mut self, sender: EmailVerificationSender) -> Self {
+        self.email_verification = Some(sender);
+        self
+    }
+
     #[tracing::instrument(skip(self, req))]
     pub async fn execute(&self, req: CreateUserRequest) -> Result<User, AppError> {
+        self.password_policy
+            .validate("password", &req.password, &[&req.username, &req.email])?;
+
         // Validate unique email using custom validator
         req.validate_unique_email(&self.repo).await?;
 
@@ -68,6 +89,15 @@ impl CreateUserUseCase {
             password_hash,
         };
 
-        Ok(self.repo.create(new_user).await?)
+        let user = self.repo.create(new_user).await?;
+
+        // The account exists either way; a failed delivery can be retried via resend
+        if let Some(sender) = &self.email_verification
+            && let Err(e) = sender.send(&user).await
+        {
+            tracing::warn!(user_id = %user.id, error = %e, "Failed to send verification email");
+        }
+
+        Ok(user)
     }
 }
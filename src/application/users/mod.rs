@@ -2,4 +2,6 @@ pub mod create;
 pub mod delete;
 pub mod get;
 pub mod list;
+pub mod roles;
+pub mod unlock;
 pub mod update;
@@ -0,0 +1,89 @@
+use crate::domain::access_scope::AccessScope;
+use crate::domain::groups::GroupRepository;
+use crate::domain::roles::RoleRepository;
+use crate::domain::users::UserRepository;
+use crate::shared::error::{AppError, FieldError};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// User role assignments don't expire, unlike administrator ones, so an
+/// `expires_at` is rejected rather than ignored
+#[derive(Debug, serde::Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AttachUserRolesRequest {
+    #[validate(length(min = 1, message = "At least one role must be provided"))]
+    pub role_ids: Vec<Uuid>,
+}
+
+/// Assign user-scoped roles to a user, group roles only to members of the group
+#[derive(Clone)]
+pub struct AttachUserRoles {
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    group_repo: Arc<dyn GroupRepository>,
+}
+
+impl AttachUserRoles {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        group_repo: Arc<dyn GroupRepository>,
+    ) -> Self {
+        Self {
+            user_repo,
+            role_repo,
+            group_repo,
+        }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), AppError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        for role_id in &role_ids {
+            let role = self
+                .role_repo
+                .find_by_id(*role_id)
+                .await?
+                .filter(|role| role.scope == AccessScope::User)
+                .ok_or_else(|| {
+                    AppError::ValidationError(vec![FieldError::new(
+                        "role_ids",
+                        format!("Role {} is not a user role", role_id),
+                    )])
+                })?;
+
+            if let Some(group_id) = role.group_id
+                && !self.group_repo.is_member(group_id, user_id).await?
+            {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "role_ids",
+                    format!("User is not a member of the group of role {}", role_id),
+                )]));
+            }
+        }
+
+        self.user_repo.attach_roles(user_id, role_ids).await?;
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct DetachUserRoles {
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl DetachUserRoles {
+    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), AppError> {
+        self.user_repo.detach_roles(user_id, role_ids).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::domain::login_attempts::AttemptScope;
+use crate::domain::users::UserRepository;
+use crate::shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Lift the login and password change lockouts of a user
+pub struct UnlockUserUseCase {
+    repo: Arc<dyn UserRepository>,
+    login_throttle: Arc<LoginThrottle>,
+}
+
+impl UnlockUserUseCase {
+    pub fn new(repo: Arc<dyn UserRepository>, login_throttle: Arc<LoginThrottle>) -> Self {
+        Self {
+            repo,
+            login_throttle,
+        }
+    }
+
+    /// Returns whether the user had failed attempts to clear
+    pub async fn execute(&self, id: Uuid) -> Result<bool, AppError> {
+        let user = self
+            .repo
+            .find_by_id(id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        let login = self
+            .login_throttle
+            .unlock(AttemptScope::User, &user.email)
+            .await?;
+        let password_change = self
+            .login_throttle
+            .unlock(AttemptScope::UserPasswordChange, &user.email)
+            .await?;
+
+        Ok(login || password_change)
+    }
+}
@@ -1,6 +1,9 @@
+use crate::application::auth::email_verification::EmailVerificationSender;
+use crate::domain::auth::{AccessTokenDenylist, RefreshTokenRepository};
 use crate::domain::password::PasswordHashingService;
 use crate::domain::users::{UpdateUser, User, UserRepository};
 use crate::shared::error::{AppError, FieldError};
+use crate::shared::validation::PasswordPolicy;
 use serde::Deserialize;
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -15,8 +18,9 @@ pub struct UpdateUserRequest {
     #[validate(email(message = "Invalid email format"))]
     #[schema(example = "newemail@example.com")]
     pub email: Option<String>,
-    #[validate(length(min = 6, message = "Password must be at least 6 characters"))]
-    #[schema(example = "newpassword123", min_length = 6)]
+    /// Rejected unless the password policy allows changes without the current
+    /// password, use `POST /api/v1/me/password` instead
+    #[schema(example = "correct horse battery staple")]
     pub password: Option<String>,
 }
 
@@ -45,29 +49,67 @@ impl UpdateUserRequest {
 pub struct UpdateUserUseCase {
     repo: Arc<dyn UserRepository>,
     password_hasher: Arc<dyn PasswordHashingService>,
+    password_policy: Arc<PasswordPolicy>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    email_verification: Option<EmailVerificationSender>,
 }
 
 impl UpdateUserUseCase {
     pub fn new(
         repo: Arc<dyn UserRepository>,
         password_hasher: Arc<dyn PasswordHashingService>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+        access_token_denylist: Arc<dyn AccessTokenDenylist>,
     ) -> Self {
         Self {
             repo,
             password_hasher,
+            password_policy: Arc::default(),
+            refresh_token_repo,
+            access_token_denylist,
+            email_verification: None,
         }
     }
 
+    /// Reject passwords that break the policy, the default policy applies otherwise
+    pub fn with_password_policy(mut self, password_policy: Arc<PasswordPolicy>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
+    /// Send a verification token when the email address changes
+    pub fn with_email_verification(mut self, sender: EmailVerificationSender) -> Self {
+        self.email_verification = Some(sender);
+        self
+    }
+
     pub async fn execute(&self, id: Uuid, req: UpdateUserRequest) -> Result<User, AppError> {
         // Check if user exists
-        let existing = self.repo.find_by_id(id).await?;
-        if existing.is_none() {
-            return Err(AppError::NotFound(format!("User with id {} not found", id)));
-        }
+        let existing = self
+            .repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User with id {} not found", id)))?;
 
         // Validate unique email using custom validator (ignoring current user)
         req.validate_unique_email(&self.repo, id).await?;
 
+        if let Some(password) = &req.password {
+            // Without the current password a stolen access token could take over the account
+            if !self.password_policy.allow_change_without_current_password {
+                return Err(AppError::ValidationError(vec![FieldError::new(
+                    "password",
+                    "Password can only be changed together with the current password",
+                )]));
+            }
+
+            let username = req.username.as_deref().unwrap_or(&existing.username);
+            let email = req.email.as_deref().unwrap_or(&existing.email);
+            self.password_policy
+                .validate("password", password, &[username, email])?;
+        }
+
         // Hash the password if it's being updated
         let password_hash = if let Some(password) = req.password {
             Some(
@@ -79,12 +121,38 @@ impl UpdateUserUseCase {
             None
         };
 
+        let password_changed = password_hash.is_some();
+        let email_changed = req.email.as_ref().is_some_and(|e| *e != existing.email);
+
         let update = UpdateUser {
             username: req.username,
             email: req.email,
             password_hash,
         };
 
-        Ok(self.repo.update(id, update).await?)
+        let user = self.repo.update(id, update).await?;
+
+        // Sessions started with the old password must stop working
+        if password_changed {
+            self.access_token_denylist
+                .deny_subject(id, "user")
+                .await
+                .map_err(AppError::InternalServerError)?;
+
+            self.refresh_token_repo
+                .delete_by_user_id(id, "user")
+                .await
+                .map_err(AppError::InternalServerError)?;
+        }
+
+        // The new address starts out unverified; a failed delivery can be retried via resend
+        if email_changed
+            && let Some(sender) = &self.email_verification
+            && let Err(e) = sender.send(&user).await
+        {
+            tracing::warn!(user_id = %user.id, error = %e, "Failed to send verification email");
+        }
+
+        Ok(user)
     }
 }
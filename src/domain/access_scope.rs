@@ -7,11 +7,13 @@ use utoipa::ToSchema;
 pub enum AccessScope {
     #[serde(rename = "administrator")]
     Administrator,
+    #[serde(rename = "user")]
+    User,
 }
 
 impl AccessScope {
     pub fn all() -> Vec<AccessScope> {
-        vec![AccessScope::Administrator]
+        vec![AccessScope::Administrator, AccessScope::User]
     }
 }
 
@@ -19,6 +21,7 @@ impl fmt::Display for AccessScope {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
             AccessScope::Administrator => "administrator",
+            AccessScope::User => "user",
         };
         write!(f, "{}", s)
     }
@@ -30,6 +33,7 @@ impl FromStr for AccessScope {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "administrator" => Ok(AccessScope::Administrator),
+            "user" => Ok(AccessScope::User),
             _ => Err(format!("Unknown access scope: {}", s)),
         }
     }
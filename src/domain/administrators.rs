@@ -42,6 +42,26 @@ pub struct UpdateAdministrator {
     pub password_hash: Option<String>,
 }
 
+/// Terms of a role assignment
+#[derive(Debug, Clone, Default)]
+pub struct RoleAssignment {
+    /// The role stops granting permissions at this time, `None` never expires
+    pub expires_at: Option<OffsetDateTime>,
+    /// Administrator who made the assignment
+    pub assigned_by: Option<Uuid>,
+}
+
+/// A role held by an administrator and the terms it was granted on
+#[derive(Debug, Clone)]
+pub struct AssignedRole {
+    pub role_id: Uuid,
+    pub role_name: String,
+    pub assigned_at: OffsetDateTime,
+    /// Administrator who made the assignment, `None` if unknown or since deleted
+    pub assigned_by: Option<Uuid>,
+    pub expires_at: Option<OffsetDateTime>,
+}
+
 #[async_trait]
 pub trait AdministratorRepository: Send + Sync {
     async fn create(&self, new_admin: NewAdministrator) -> Result<Administrator, anyhow::Error>;
@@ -56,10 +76,30 @@ pub trait AdministratorRepository: Send + Sync {
     ) -> Result<Administrator, anyhow::Error>;
     async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error>;
 
-    async fn attach_roles(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error>;
-    async fn detach_roles(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error>;
+    /// Assign roles, replacing the terms of roles that are already assigned, and record the grants in the role history
+    async fn attach_roles(
+        &self,
+        admin_id: Uuid,
+        role_ids: Vec<Uuid>,
+        assignment: RoleAssignment,
+    ) -> Result<(), anyhow::Error>;
+    /// Remove roles, recording `revoked_by` in the role history
+    async fn detach_roles(
+        &self,
+        admin_id: Uuid,
+        role_ids: Vec<Uuid>,
+        revoked_by: Uuid,
+    ) -> Result<(), anyhow::Error>;
+    /// Roles assigned and not expired
+    async fn find_role_assignments(
+        &self,
+        admin_id: Uuid,
+    ) -> Result<Vec<AssignedRole>, anyhow::Error>;
+    /// Permissions of the roles assigned and not expired
     async fn get_permissions(
         &self,
         admin_id: Uuid,
     ) -> Result<Vec<crate::domain::permissions::Permission>, anyhow::Error>;
+    /// Delete expired role assignments, recording them in the role history, returning how many were removed
+    async fn delete_expired_role_assignments(&self) -> Result<u64, anyhow::Error>;
 }
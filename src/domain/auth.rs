@@ -13,39 +13,79 @@ pub struct Claims {
     pub user_type: String,
     /// Issued at timestamp
     pub iat: i64,
+    /// Issued at timestamp in milliseconds, tells tokens apart from a revocation in the same second
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat_ms: Option<i64>,
     /// Expiration timestamp
     pub exp: i64,
     /// Token type: "access" or "refresh"
     #[serde(rename = "type")]
     pub token_type: String,
+    /// Unique token identifier, used to revoke individual tokens
+    pub jti: String,
+    /// Issuer, the base URL of the issuing service
+    pub iss: String,
+    /// Audience the token is meant for, differs between admin and client tokens
+    pub aud: String,
+    /// Not before timestamp
+    pub nbf: i64,
 }
 
 impl Claims {
     pub fn new_access_token(user_id: Uuid, user_type: String, expiry_seconds: i64) -> Self {
-        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let issued_at = OffsetDateTime::now_utc();
+        let now = issued_at.unix_timestamp();
         Self {
             sub: user_id.to_string(),
             user_type,
             iat: now,
+            iat_ms: Some((issued_at.unix_timestamp_nanos() / 1_000_000) as i64),
             exp: now + expiry_seconds,
             token_type: "access".to_string(),
+            jti: Uuid::new_v4().to_string(),
+            iss: String::new(),
+            aud: String::new(),
+            nbf: now,
         }
     }
 
     pub fn new_refresh_token(user_id: Uuid, user_type: String, expiry_seconds: i64) -> Self {
-        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let issued_at = OffsetDateTime::now_utc();
+        let now = issued_at.unix_timestamp();
         Self {
             sub: user_id.to_string(),
             user_type,
             iat: now,
+            iat_ms: Some((issued_at.unix_timestamp_nanos() / 1_000_000) as i64),
             exp: now + expiry_seconds,
             token_type: "refresh".to_string(),
+            jti: Uuid::new_v4().to_string(),
+            iss: String::new(),
+            aud: String::new(),
+            nbf: now,
         }
     }
 
+    pub fn with_issuer(mut self, iss: impl Into<String>) -> Self {
+        self.iss = iss.into();
+        self
+    }
+
+    pub fn with_audience(mut self, aud: impl Into<String>) -> Self {
+        self.aud = aud.into();
+        self
+    }
+
     pub fn user_id(&self) -> Result<Uuid> {
         Uuid::parse_str(&self.sub).map_err(|e| anyhow::anyhow!("Invalid user ID in claims: {}", e))
     }
+
+    /// Issue time at millisecond precision, tokens without `iat_ms` count from the start of their second
+    pub fn issued_at(&self) -> Result<OffsetDateTime> {
+        let millis = self.iat_ms.unwrap_or(self.iat.saturating_mul(1000));
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000)
+            .map_err(|e| anyhow::anyhow!("Invalid issue time in claims: {}", e))
+    }
 }
 
 /// Refresh token entity
@@ -55,6 +95,16 @@ pub struct RefreshToken {
     pub user_id: Uuid,
     pub user_type: String,
     pub token_hash: String,
+    /// Shared by every token descending from the same login
+    pub family_id: Uuid,
+    /// Token this one was rotated from, if any
+    pub parent_id: Option<Uuid>,
+    /// Set once the token has been exchanged for a new pair
+    pub rotated_at: Option<OffsetDateTime>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    pub last_used_at: OffsetDateTime,
     pub expires_at: OffsetDateTime,
     pub created_at: OffsetDateTime,
 }
@@ -65,6 +115,34 @@ pub struct NewRefreshToken {
     pub user_id: Uuid,
     pub user_type: String,
     pub token_hash: String,
+    pub family_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    pub expires_at: OffsetDateTime,
+}
+
+/// Client details captured when tokens are issued
+#[derive(Debug, Clone, Default)]
+pub struct SessionMetadata {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+}
+
+/// An active login, i.e. a refresh token family that has not been revoked
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// The refresh token family id
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_type: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub last_used_at: OffsetDateTime,
     pub expires_at: OffsetDateTime,
 }
 
@@ -74,17 +152,43 @@ pub trait RefreshTokenRepository: Send + Sync {
     /// Create a new refresh token
     async fn create(&self, token: NewRefreshToken) -> Result<RefreshToken>;
 
-    /// Find a refresh token by its hash
+    /// Find a refresh token by its hash (including already rotated tokens)
     async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
 
-    /// Delete all refresh tokens for a user
-    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    /// Delete all refresh tokens of a user or administrator
+    async fn delete_by_user_id(&self, user_id: Uuid, user_type: &str) -> Result<u64>;
 
     /// Delete expired refresh tokens
     async fn delete_expired(&self) -> Result<u64>;
 
     /// Delete a specific refresh token by hash
     async fn delete_by_hash(&self, token_hash: &str) -> Result<bool>;
+
+    /// Mark a refresh token as rotated.
+    /// Returns false if the token was already rotated.
+    async fn mark_rotated(&self, id: Uuid) -> Result<bool>;
+
+    /// Delete every refresh token belonging to a token family
+    async fn delete_by_family_id(&self, family_id: Uuid) -> Result<u64>;
+
+    /// Find the active sessions (unrotated, unexpired tokens) of a user
+    async fn find_sessions(&self, user_id: Uuid, user_type: &str) -> Result<Vec<Session>>;
+}
+
+/// Denylist for access tokens that must stop working before they expire
+#[async_trait]
+pub trait AccessTokenDenylist: Send + Sync {
+    /// Revoke a single access token by its `jti` until it expires
+    async fn deny_token(&self, jti: &str, expires_at: OffsetDateTime) -> Result<()>;
+
+    /// Revoke every access token issued to a subject before the current second
+    async fn deny_subject(&self, user_id: Uuid, user_type: &str) -> Result<()>;
+
+    /// Check whether the token described by the claims has been revoked
+    async fn is_denied(&self, claims: &Claims) -> Result<bool>;
+
+    /// Delete entries whose tokens have expired anyway
+    async fn delete_expired(&self) -> Result<u64>;
 }
 
 /// Auth service trait for JWT operations
@@ -135,6 +239,16 @@ mod tests {
         assert_eq!(claims.exp, claims.iat + expiry_seconds);
     }
 
+    #[test]
+    fn test_tokens_have_unique_jti() {
+        let user_id = Uuid::new_v4();
+
+        let first = Claims::new_refresh_token(user_id, "user".to_string(), 60);
+        let second = Claims::new_refresh_token(user_id, "user".to_string(), 60);
+
+        assert_ne!(first.jti, second.jti);
+    }
+
     #[test]
     fn test_user_id_valid() {
         let user_id = Uuid::new_v4();
@@ -142,8 +256,13 @@ mod tests {
             sub: user_id.to_string(),
             user_type: "user".to_string(),
             iat: 0,
+            iat_ms: None,
             exp: 0,
             token_type: "access".to_string(),
+            jti: Uuid::new_v4().to_string(),
+            iss: String::new(),
+            aud: String::new(),
+            nbf: 0,
         };
 
         assert_eq!(claims.user_id().unwrap(), user_id);
@@ -155,8 +274,13 @@ mod tests {
             sub: "invalid-uuid".to_string(),
             user_type: "user".to_string(),
             iat: 0,
+            iat_ms: None,
             exp: 0,
             token_type: "access".to_string(),
+            jti: Uuid::new_v4().to_string(),
+            iss: String::new(),
+            aud: String::new(),
+            nbf: 0,
         };
 
         assert!(claims.user_id().is_err());
@@ -0,0 +1,53 @@
+use super::users::User;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Organization users belong to. Roles with its `group_id` only grant
+/// permissions to its members, within the group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(with = "time::serde::iso8601")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::iso8601")]
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewGroup {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateGroup {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[async_trait]
+pub trait GroupRepository: Send + Sync {
+    async fn create(&self, new_group: NewGroup) -> Result<Group, anyhow::Error>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Group>, anyhow::Error>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<Group>, anyhow::Error>;
+    async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Group>, anyhow::Error>;
+    async fn count(&self) -> Result<i64, anyhow::Error>;
+    async fn update(&self, id: Uuid, update: UpdateGroup) -> Result<Group, anyhow::Error>;
+    async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error>;
+
+    /// Groups `user_id` is a member of
+    async fn find_by_member(&self, user_id: Uuid) -> Result<Vec<Group>, anyhow::Error>;
+    async fn find_members(&self, group_id: Uuid) -> Result<Vec<User>, anyhow::Error>;
+    async fn is_member(&self, group_id: Uuid, user_id: Uuid) -> Result<bool, anyhow::Error>;
+    async fn add_members(&self, group_id: Uuid, user_ids: Vec<Uuid>) -> Result<(), anyhow::Error>;
+    /// Remove members along with the group roles assigned to them
+    async fn remove_members(
+        &self,
+        group_id: Uuid,
+        user_ids: Vec<Uuid>,
+    ) -> Result<(), anyhow::Error>;
+}
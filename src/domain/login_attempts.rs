@@ -0,0 +1,63 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use time::{Duration, OffsetDateTime};
+
+/// What failed login attempts are counted against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptScope {
+    /// A client user account, keyed by email
+    User,
+    /// An administrator account, keyed by email
+    Administrator,
+    /// A client IP address, shared by client and administrator logins
+    Ip,
+    /// Wrong current passwords of a client user changing their password, keyed by email
+    UserPasswordChange,
+    /// Wrong current passwords of an administrator changing their password, keyed by email
+    AdministratorPasswordChange,
+}
+
+impl AttemptScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttemptScope::User => "user",
+            AttemptScope::Administrator => "admin",
+            AttemptScope::Ip => "ip",
+            AttemptScope::UserPasswordChange => "user_password",
+            AttemptScope::AdministratorPasswordChange => "admin_password",
+        }
+    }
+}
+
+/// Failed login attempts recorded for one account or IP
+#[derive(Debug, Clone)]
+pub struct LoginAttempts {
+    pub failed_count: i32,
+    pub last_failed_at: OffsetDateTime,
+    pub locked_until: Option<OffsetDateTime>,
+}
+
+/// Repository trait for failed login counters
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    /// Find the current counter, ignoring counters that have expired
+    async fn find(&self, scope: AttemptScope, key: &str) -> Result<Option<LoginAttempts>>;
+
+    /// Count a failed attempt. The counter starts over if the previous one expired,
+    /// otherwise it is kept for `window` after this failure.
+    async fn record_failure(
+        &self,
+        scope: AttemptScope,
+        key: &str,
+        window: Duration,
+    ) -> Result<LoginAttempts>;
+
+    /// Reject further attempts until the given time
+    async fn lock(&self, scope: AttemptScope, key: &str, until: OffsetDateTime) -> Result<()>;
+
+    /// Forget all failed attempts, returns false if none were recorded
+    async fn reset(&self, scope: AttemptScope, key: &str) -> Result<bool>;
+
+    /// Delete expired counters
+    async fn delete_expired(&self) -> Result<u64>;
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// A periodic cleanup task run by the maintenance scheduler
+#[async_trait]
+pub trait MaintenanceJob: Send + Sync {
+    /// Short identifier used in logs
+    fn name(&self) -> &'static str;
+
+    /// Run the job once, returning the number of affected rows
+    async fn run(&self) -> Result<u64>;
+}
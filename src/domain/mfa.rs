@@ -0,0 +1,61 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// TOTP second factor of an administrator
+#[derive(Debug, Clone)]
+pub struct TotpEnrollment {
+    pub administrator_id: Uuid,
+    /// Base32 encoded shared secret, encrypted at rest by the repository
+    pub secret: String,
+    /// Set once the administrator proved their authenticator works
+    pub confirmed_at: Option<OffsetDateTime>,
+    pub last_used_step: Option<i64>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl TotpEnrollment {
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
+
+/// Repository trait for administrator multi-factor authentication
+#[async_trait]
+pub trait MfaRepository: Send + Sync {
+    /// Find the TOTP enrollment of an administrator, confirmed or not
+    async fn find_totp(&self, administrator_id: Uuid) -> Result<Option<TotpEnrollment>>;
+
+    /// Store a new unconfirmed secret, replacing a previous unconfirmed one
+    async fn save_pending_totp(
+        &self,
+        administrator_id: Uuid,
+        secret: &str,
+    ) -> Result<TotpEnrollment>;
+
+    /// Mark the enrollment as confirmed
+    async fn confirm_totp(&self, administrator_id: Uuid) -> Result<bool>;
+
+    /// Record the time step of an accepted code.
+    /// Returns false if this or a later step was already used (replay).
+    async fn record_totp_step(&self, administrator_id: Uuid, step: i64) -> Result<bool>;
+
+    /// Remove the TOTP enrollment together with all recovery codes
+    async fn delete_totp(&self, administrator_id: Uuid) -> Result<bool>;
+
+    /// Replace all recovery codes of an administrator
+    async fn replace_recovery_codes(
+        &self,
+        administrator_id: Uuid,
+        code_hashes: Vec<String>,
+    ) -> Result<()>;
+
+    /// Mark an unused recovery code as used.
+    /// Returns false if no unused code with this hash exists.
+    async fn use_recovery_code(&self, administrator_id: Uuid, code_hash: &str) -> Result<bool>;
+
+    /// Number of recovery codes that have not been used yet
+    async fn count_unused_recovery_codes(&self, administrator_id: Uuid) -> Result<i64>;
+}
@@ -1,6 +1,13 @@
 pub mod access_scope;
 pub mod administrators;
 pub mod auth;
+pub mod groups;
+pub mod login_attempts;
+pub mod maintenance;
+pub mod mfa;
+pub mod notifications;
+pub mod one_time_tokens;
+pub mod passkeys;
 pub mod password;
 pub mod permissions;
 pub mod roles;
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+/// A message sent to a user outside of the API, e.g. by email
+#[derive(Debug, Clone)]
+pub enum Notification {
+    /// Carries the plain reset token; it is never stored
+    PasswordReset {
+        email: String,
+        user_type: String,
+        token: String,
+        expires_at: OffsetDateTime,
+    },
+    /// Sent after signup and after an email change
+    EmailVerification {
+        email: String,
+        token: String,
+        expires_at: OffsetDateTime,
+    },
+}
+
+impl Notification {
+    /// Address the notification is delivered to
+    pub fn recipient(&self) -> &str {
+        match self {
+            Notification::PasswordReset { email, .. }
+            | Notification::EmailVerification { email, .. } => email,
+        }
+    }
+}
+
+/// Delivery channel for notifications (email, SMS, queue, ...)
+#[async_trait]
+pub trait NotificationService: Send + Sync {
+    async fn send(&self, notification: Notification) -> Result<()>;
+}
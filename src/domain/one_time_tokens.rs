@@ -0,0 +1,84 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// What a one-time token may be used for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenPurpose {
+    PasswordReset,
+    EmailVerification,
+    MfaChallenge,
+    MfaEnrollment,
+    WebAuthnRegistration,
+    WebAuthnAuthentication,
+}
+
+impl TokenPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenPurpose::PasswordReset => "password_reset",
+            TokenPurpose::EmailVerification => "email_verification",
+            TokenPurpose::MfaChallenge => "mfa_challenge",
+            TokenPurpose::MfaEnrollment => "mfa_enrollment",
+            TokenPurpose::WebAuthnRegistration => "webauthn_registration",
+            TokenPurpose::WebAuthnAuthentication => "webauthn_authentication",
+        }
+    }
+}
+
+/// A single-use token delivered out of band; only its hash is stored
+#[derive(Debug, Clone)]
+pub struct OneTimeToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_type: String,
+    pub purpose: String,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+/// New one-time token for creation
+#[derive(Debug, Clone)]
+pub struct NewOneTimeToken {
+    pub user_id: Uuid,
+    pub user_type: String,
+    pub purpose: TokenPurpose,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+}
+
+/// Repository trait for one-time tokens
+#[async_trait]
+pub trait OneTimeTokenRepository: Send + Sync {
+    /// Create a new one-time token
+    async fn create(&self, token: NewOneTimeToken) -> Result<OneTimeToken>;
+
+    /// Find an unused, unexpired token by its hash and purpose
+    async fn find_valid(
+        &self,
+        token_hash: &str,
+        purpose: TokenPurpose,
+    ) -> Result<Option<OneTimeToken>>;
+
+    /// Mark a token as used.
+    /// Returns false if the token was already used or has expired.
+    async fn consume(&self, id: Uuid) -> Result<bool>;
+
+    /// Count a wrong code entered for a token and mark the token used once
+    /// `max_attempts` is reached. Returns true if the token can no longer be used.
+    async fn record_failed_attempt(&self, id: Uuid, max_attempts: i32) -> Result<bool>;
+
+    /// Delete every token of a user issued for the given purpose
+    async fn delete_for_user(
+        &self,
+        user_id: Uuid,
+        user_type: &str,
+        purpose: TokenPurpose,
+    ) -> Result<u64>;
+
+    /// Delete expired tokens
+    async fn delete_expired(&self) -> Result<u64>;
+}
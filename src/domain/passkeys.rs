@@ -0,0 +1,49 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// WebAuthn credential registered by an administrator
+#[derive(Debug, Clone)]
+pub struct Passkey {
+    pub id: Uuid,
+    pub administrator_id: Uuid,
+    /// Base64url encoded credential ID
+    pub credential_id: String,
+    /// Base64url encoded SEC1 public key
+    pub public_key: String,
+    pub sign_count: i64,
+    pub name: String,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+/// New passkey for creation
+#[derive(Debug, Clone)]
+pub struct NewPasskey {
+    pub administrator_id: Uuid,
+    pub credential_id: String,
+    pub public_key: String,
+    pub sign_count: i64,
+    pub name: String,
+}
+
+/// Repository trait for administrator passkeys
+#[async_trait]
+pub trait PasskeyRepository: Send + Sync {
+    /// Store a newly registered passkey
+    async fn create(&self, passkey: NewPasskey) -> Result<Passkey>;
+
+    /// Find a passkey by its base64url credential ID
+    async fn find_by_credential_id(&self, credential_id: &str) -> Result<Option<Passkey>>;
+
+    /// List the passkeys of an administrator, newest first
+    async fn list_for_administrator(&self, administrator_id: Uuid) -> Result<Vec<Passkey>>;
+
+    /// Record a successful login with the new signature counter.
+    /// Returns false if the counter did not increase (possible cloned authenticator).
+    async fn record_use(&self, id: Uuid, sign_count: i64) -> Result<bool>;
+
+    /// Delete a passkey of an administrator
+    async fn delete(&self, administrator_id: Uuid, id: Uuid) -> Result<bool>;
+}
@@ -5,4 +5,9 @@ use anyhow::Result;
 pub trait PasswordHashingService: Send + Sync {
     fn hash_password(&self, password: &str) -> Result<String>;
     fn verify_password(&self, password: &str, hash: &str) -> Result<bool>;
+
+    /// Whether a verified hash should be replaced, e.g. after the hashing cost was raised
+    fn needs_rehash(&self, _hash: &str) -> bool {
+        false
+    }
 }
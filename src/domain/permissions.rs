@@ -1,72 +1,338 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::fmt;
 use std::str::FromStr;
+use utoipa::ToSchema;
 
 use super::access_scope::AccessScope;
 
-/// Pre-defined permissions for RBAC system
-/// These permissions control access to various resources and operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum Permission {
-    #[serde(rename = "*")]
-    Wildcard,
-    #[serde(rename = "administrator_management")]
-    AdministratorManagement,
-    #[serde(rename = "role_management")]
-    RoleManagement,
+/// A registered permission and the metadata shown when assigning it
+pub struct PermissionDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub scopes: &'static [AccessScope],
 }
 
+const ADMINISTRATOR_SCOPE: &[AccessScope] = &[AccessScope::Administrator];
+const USER_SCOPE: &[AccessScope] = &[AccessScope::User];
+/// Administrators hold these everywhere, users within the groups granting them
+const GROUP_SCOPES: &[AccessScope] = &[AccessScope::Administrator, AccessScope::User];
+
+/// Every permission checked by the application.
+/// Roles may hold these or wildcard patterns covering several of them.
+pub const REGISTRY: &[PermissionDefinition] = &[
+    PermissionDefinition {
+        name: "administrators.read",
+        description: "View administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.create",
+        description: "Create administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.update",
+        description: "Update administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.delete",
+        description: "Delete administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.roles.attach",
+        description: "Assign roles to administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.roles.detach",
+        description: "Remove roles from administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.sessions.read",
+        description: "View sessions of administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.sessions.revoke",
+        description: "Revoke sessions of administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "administrators.unlock",
+        description: "Lift login lockouts of administrators",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.read",
+        description: "View users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.sessions.read",
+        description: "View sessions of users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.sessions.revoke",
+        description: "Revoke sessions of users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.update",
+        description: "Update the accounts of other users",
+        scopes: USER_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.delete",
+        description: "Delete the accounts of other users",
+        scopes: USER_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.unlock",
+        description: "Lift login lockouts of users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.roles.attach",
+        description: "Assign roles to users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "users.roles.detach",
+        description: "Remove roles from users",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.read",
+        description: "View roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.create",
+        description: "Create roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.update",
+        description: "Update roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.delete",
+        description: "Delete roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.permissions.read",
+        description: "View the permissions of roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.permissions.attach",
+        description: "Grant permissions to roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "roles.permissions.detach",
+        description: "Revoke permissions from roles",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "groups.read",
+        description: "View groups",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "groups.create",
+        description: "Create groups",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "groups.update",
+        description: "Update groups",
+        scopes: GROUP_SCOPES,
+    },
+    PermissionDefinition {
+        name: "groups.delete",
+        description: "Delete groups",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "groups.members.read",
+        description: "View the members of groups",
+        scopes: GROUP_SCOPES,
+    },
+    PermissionDefinition {
+        name: "groups.members.add",
+        description: "Add members to groups",
+        scopes: GROUP_SCOPES,
+    },
+    PermissionDefinition {
+        name: "groups.members.remove",
+        description: "Remove members from groups",
+        scopes: GROUP_SCOPES,
+    },
+    PermissionDefinition {
+        name: "permissions.read",
+        description: "View the permission catalog",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+    PermissionDefinition {
+        name: "permissions.cache.read",
+        description: "View permission cache statistics",
+        scopes: ADMINISTRATOR_SCOPE,
+    },
+];
+
+/// Segment matching any single segment, or everything below when it comes last
+const WILDCARD_SEGMENT: &str = "*";
+
+/// Dot-namespaced permission such as `users.read`, or a pattern such as
+/// `users.*` or `*.read` granting every registered permission it matches.
+///
+/// A `*` segment matches exactly one segment, except at the end where it
+/// matches one or more, so `*` alone grants everything.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ToSchema)]
+#[schema(value_type = String, example = "users.read")]
+pub struct Permission(Cow<'static, str>);
+
 impl Permission {
-    /// Returns all available permissions
+    /// Grants every permission
+    pub const WILDCARD: Permission = Permission::from_static(WILDCARD_SEGMENT);
+
+    /// Permission from a literal, which must name a registered permission
+    pub const fn from_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        self.segments().any(|segment| segment == WILDCARD_SEGMENT)
+    }
+
+    fn segments(&self) -> std::str::Split<'_, char> {
+        self.0.split('.')
+    }
+
+    /// Whether holding `self` grants `permission`
+    pub fn grants(&self, permission: &Permission) -> bool {
+        let mut pattern = self.segments().peekable();
+        let mut required = permission.segments();
+
+        while let Some(segment) = pattern.next() {
+            let is_last = pattern.peek().is_none();
+            match required.next() {
+                None => return false,
+                Some(_) if segment == WILDCARD_SEGMENT && is_last => return true,
+                Some(other) if segment != WILDCARD_SEGMENT && segment != other => return false,
+                Some(_) => {}
+            }
+        }
+
+        required.next().is_none()
+    }
+
+    /// Registered permissions granted by `self`
+    pub fn expand(&self) -> impl Iterator<Item = &'static PermissionDefinition> + '_ {
+        REGISTRY
+            .iter()
+            .filter(|definition| self.grants(&Permission::from_static(definition.name)))
+    }
+
+    /// Returns every grantable permission: the registered ones, the wildcard
+    /// and a `namespace.*` pattern for each namespace
     pub fn all() -> Vec<Permission> {
-        vec![
-            Permission::Wildcard,
-            Permission::AdministratorManagement,
-            Permission::RoleManagement,
-        ]
+        let mut permissions = vec![Permission::WILDCARD];
+
+        for definition in REGISTRY {
+            let mut prefix_end = 0;
+            while let Some(dot) = definition.name[prefix_end..].find('.') {
+                prefix_end += dot;
+                let namespace = Permission(format!("{}.*", &definition.name[..prefix_end]).into());
+                if !permissions.contains(&namespace) {
+                    permissions.push(namespace);
+                }
+                prefix_end += 1;
+            }
+            permissions.push(Permission::from_static(definition.name));
+        }
+
+        permissions
     }
 
     /// Returns a human-readable description of the permission
-    pub fn description(&self) -> &str {
-        match self {
-            Permission::Wildcard => "Full access to all resources within the scope",
-            Permission::AdministratorManagement => "Manage administrators",
-            Permission::RoleManagement => "Manage roles and permissions",
+    pub fn description(&self) -> String {
+        if let Some(definition) = REGISTRY.iter().find(|d| d.name == self.as_str()) {
+            return definition.description.to_string();
+        }
+
+        if *self == Permission::WILDCARD {
+            return "Full access to all resources within the scope".to_string();
+        }
+
+        match self.as_str().strip_suffix(".*") {
+            Some(namespace) => format!("All permissions under {}", namespace),
+            None => format!("Permissions matching {}", self),
         }
     }
 
     /// Returns the scopes allowed for this permission
     pub fn scopes(&self) -> Vec<AccessScope> {
-        match self {
-            // Wildcard is allowed in all scopes by default, checking logic handles the rest
-            Permission::Wildcard => vec![AccessScope::Administrator],
-            Permission::AdministratorManagement => vec![AccessScope::Administrator],
-            Permission::RoleManagement => vec![AccessScope::Administrator],
+        let mut scopes = Vec::new();
+        for scope in self.expand().flat_map(|definition| definition.scopes) {
+            if !scopes.contains(scope) {
+                scopes.push(*scope);
+            }
         }
+        scopes
     }
 }
 
 impl fmt::Display for Permission {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let s = match self {
-            Permission::Wildcard => "*",
-            Permission::AdministratorManagement => "administrator_management",
-            Permission::RoleManagement => "role_management",
-        };
-        write!(f, "{}", s)
+        write!(f, "{}", self.0)
     }
 }
 
 impl FromStr for Permission {
     type Err = String;
 
+    /// Accepts registered permissions and patterns matching at least one of them
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "*" => Ok(Permission::Wildcard),
-            "administrator_management" => Ok(Permission::AdministratorManagement),
-            "role_management" => Ok(Permission::RoleManagement),
-            _ => Err(format!("Unknown permission: {}", s)),
+        let well_formed = s.split('.').all(|segment| {
+            segment == WILDCARD_SEGMENT
+                || (!segment.is_empty()
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+        });
+
+        let permission = Permission(s.to_string().into());
+        if !well_formed || permission.expand().next().is_none() {
+            return Err(format!("Unknown permission: {}", s));
         }
+
+        Ok(permission)
+    }
+}
+
+impl Serialize for Permission {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -74,75 +340,99 @@ impl FromStr for Permission {
 mod tests {
     use super::*;
 
+    fn permission(name: &str) -> Permission {
+        name.parse().unwrap()
+    }
+
     #[test]
     fn test_permission_display() {
-        assert_eq!(
-            Permission::AdministratorManagement.to_string(),
-            "administrator_management"
-        );
-        assert_eq!(Permission::RoleManagement.to_string(), "role_management");
+        assert_eq!(permission("users.read").to_string(), "users.read");
+        assert_eq!(Permission::WILDCARD.to_string(), "*");
     }
 
     #[test]
     fn test_permission_from_str() {
         assert_eq!(
-            "administrator_management".parse::<Permission>().unwrap(),
-            Permission::AdministratorManagement
-        );
-        assert_eq!(
-            "role_management".parse::<Permission>().unwrap(),
-            Permission::RoleManagement
+            "roles.permissions.attach".parse::<Permission>().unwrap(),
+            Permission::from_static("roles.permissions.attach")
         );
+        assert!("users.*".parse::<Permission>().is_ok());
+        assert!("*.read".parse::<Permission>().is_ok());
         assert!("invalid.permission".parse::<Permission>().is_err());
+        assert!("users..read".parse::<Permission>().is_err());
+        assert!("Users.Read".parse::<Permission>().is_err());
+        assert!("administrator_management".parse::<Permission>().is_err());
+    }
+
+    #[test]
+    fn test_segment_wildcards() {
+        let users_read = permission("users.read");
+        let user_sessions = permission("users.sessions.revoke");
+
+        assert!(Permission::WILDCARD.grants(&user_sessions));
+        assert!(permission("users.*").grants(&users_read));
+        assert!(permission("users.*").grants(&user_sessions));
+        assert!(permission("users.sessions.*").grants(&user_sessions));
+        assert!(!permission("users.sessions.*").grants(&users_read));
+        assert!(permission("*.read").grants(&users_read));
+        assert!(!permission("*.read").grants(&permission("users.sessions.read")));
+        assert!(permission("*.sessions.read").grants(&permission("users.sessions.read")));
+        assert!(users_read.grants(&users_read));
+        assert!(!users_read.grants(&permission("users.unlock")));
+        assert!(!permission("roles.permissions.read").grants(&permission("roles.read")));
     }
 
     #[test]
     fn test_permission_description() {
+        assert_eq!(permission("users.read").description(), "View users");
         assert_eq!(
-            Permission::AdministratorManagement.description(),
-            "Manage administrators"
+            permission("roles.permissions.*").description(),
+            "All permissions under roles.permissions"
         );
         assert_eq!(
-            Permission::RoleManagement.description(),
-            "Manage roles and permissions"
+            Permission::WILDCARD.description(),
+            "Full access to all resources within the scope"
         );
     }
 
     #[test]
     fn test_permission_all() {
         let all_permissions = Permission::all();
-        assert_eq!(all_permissions.len(), 3);
-        assert!(all_permissions.contains(&Permission::Wildcard));
-        assert!(all_permissions.contains(&Permission::AdministratorManagement));
-        assert!(all_permissions.contains(&Permission::RoleManagement));
+
+        assert_eq!(all_permissions[0], Permission::WILDCARD);
+        assert!(all_permissions.contains(&permission("administrators.read")));
+        assert!(all_permissions.contains(&permission("administrators.*")));
+        assert!(all_permissions.contains(&permission("roles.permissions.*")));
+        assert_eq!(
+            all_permissions.iter().filter(|p| !p.is_wildcard()).count(),
+            REGISTRY.len()
+        );
+        // Every grantable permission parses back
+        for p in &all_permissions {
+            assert_eq!(&p.to_string().parse::<Permission>().unwrap(), p);
+        }
     }
 
     #[test]
     fn test_permission_serialization() {
-        let permission = Permission::AdministratorManagement;
-        let json = serde_json::to_string(&permission).unwrap();
-        assert_eq!(json, "\"administrator_management\"");
+        let json = serde_json::to_string(&permission("users.unlock")).unwrap();
+        assert_eq!(json, "\"users.unlock\"");
 
         let deserialized: Permission = serde_json::from_str(&json).unwrap();
-        assert_eq!(deserialized, Permission::AdministratorManagement);
+        assert_eq!(deserialized, permission("users.unlock"));
+        assert!(serde_json::from_str::<Permission>("\"users.fly\"").is_err());
     }
 
     #[test]
     fn test_permission_scopes() {
-        // Test AdministratorManagement scopes
-        let admin_scopes = Permission::AdministratorManagement.scopes();
-        assert_eq!(admin_scopes.len(), 1);
-        assert_eq!(admin_scopes[0], AccessScope::Administrator);
-
-        // Test RoleManagement scopes
-        // Test RoleManagement scopes
-        let role_scopes = Permission::RoleManagement.scopes();
-        assert_eq!(role_scopes.len(), 1);
-        assert_eq!(role_scopes[0], AccessScope::Administrator); // Fixed from PermissionScope to AccessScope
-
-        // Test Wildcard scopes
-        let wildcard_scopes = Permission::Wildcard.scopes();
-        assert_eq!(wildcard_scopes.len(), 1);
-        assert_eq!(wildcard_scopes[0], AccessScope::Administrator); // Fixed from PermissionScope to AccessScope
+        assert_eq!(
+            permission("roles.read").scopes(),
+            vec![AccessScope::Administrator]
+        );
+        assert_eq!(permission("users.delete").scopes(), vec![AccessScope::User]);
+        assert_eq!(
+            Permission::WILDCARD.scopes(),
+            vec![AccessScope::Administrator, AccessScope::User]
+        );
     }
 }
@@ -13,6 +13,8 @@ pub struct Role {
     pub description: Option<String>,
     pub scope: AccessScope,
     pub group_id: Option<Uuid>,
+    /// Roles this role inherits permissions from
+    pub parent_ids: Vec<Uuid>,
     #[serde(with = "time::serde::iso8601")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::iso8601")]
@@ -54,7 +56,26 @@ pub trait RoleRepository: Send + Sync {
     async fn update(&self, id: Uuid, update: UpdateRole) -> Result<Role, anyhow::Error>;
     async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error>;
 
+    /// Permissions of the role, including those inherited from its ancestors
     async fn get_permissions(&self, role_id: Uuid) -> Result<Vec<Permission>, anyhow::Error>;
+    /// Permissions attached to the role itself
+    async fn get_direct_permissions(&self, role_id: Uuid)
+    -> Result<Vec<Permission>, anyhow::Error>;
+
+    /// Parents of the role, their parents and so on
+    async fn find_ancestor_ids(&self, role_id: Uuid) -> Result<Vec<Uuid>, anyhow::Error>;
+    /// Attach the parents unless one of them would make the role its own ancestor,
+    /// in which case nothing is attached and that parent is returned
+    async fn attach_parents(
+        &self,
+        role_id: Uuid,
+        parent_ids: Vec<Uuid>,
+    ) -> Result<Option<Uuid>, anyhow::Error>;
+    async fn detach_parents(
+        &self,
+        role_id: Uuid,
+        parent_ids: Vec<Uuid>,
+    ) -> Result<(), anyhow::Error>;
 
     // Bulk permission operations for better performance
     async fn attach_permissions(
@@ -10,6 +10,8 @@ pub struct User {
     pub email: String,
     #[serde(skip)]
     pub password_hash: String,
+    #[serde(with = "time::serde::iso8601::option")]
+    pub email_verified_at: Option<OffsetDateTime>,
 
     #[serde(with = "time::serde::iso8601")]
     pub created_at: OffsetDateTime,
@@ -40,4 +42,19 @@ pub trait UserRepository: Send + Sync {
     async fn count(&self) -> Result<i64, anyhow::Error>;
     async fn update(&self, id: Uuid, update: UpdateUser) -> Result<User, anyhow::Error>;
     async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error>;
+    async fn mark_email_verified(&self, id: Uuid) -> Result<bool, anyhow::Error>;
+
+    async fn attach_roles(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error>;
+    async fn detach_roles(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error>;
+    /// Permissions granted by roles outside any group
+    async fn get_permissions(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<crate::domain::permissions::Permission>, anyhow::Error>;
+    /// Permissions granted by the roles of `group_id`, None unless a member
+    async fn get_group_permissions(
+        &self,
+        user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Option<Vec<crate::domain::permissions::Permission>>, anyhow::Error>;
 }
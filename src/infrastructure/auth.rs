@@ -1,13 +1,118 @@
 use crate::domain::auth::{AuthService, Claims};
 use anyhow::Result;
-use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::DecodePublicKey;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::fs;
+use std::path::Path;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-/// JWT Authentication Service using ES256 algorithm
+/// Default issuer, used when `JWT_ISSUER` is not configured
+pub const DEFAULT_ISSUER: &str = "http://localhost:3000";
+
+/// Default audience of tokens issued to clients
+pub const DEFAULT_CLIENT_AUDIENCE: &str = "caxur-client";
+
+/// Default audience of tokens issued to administrators
+pub const DEFAULT_ADMIN_AUDIENCE: &str = "caxur-admin";
+
+/// Public key in JSON Web Key format (RFC 7517)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Jwk {
+    /// Key type, always "EC"
+    pub kty: String,
+    /// Curve, always "P-256"
+    pub crv: String,
+    /// Base64url encoded x coordinate
+    pub x: String,
+    /// Base64url encoded y coordinate
+    pub y: String,
+    /// Key ID, the RFC 7638 thumbprint of the key unless named explicitly
+    pub kid: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    pub alg: String,
+}
+
+impl Jwk {
+    /// Build the JWK of a PEM encoded P-256 public key
+    pub fn from_ec_pem(public_key_pem: &[u8]) -> Result<Self> {
+        let pem = std::str::from_utf8(public_key_pem)
+            .map_err(|e| anyhow::anyhow!("Failed to parse public key: {}", e))?;
+        let public_key = p256::PublicKey::from_public_key_pem(pem)
+            .map_err(|e| anyhow::anyhow!("Failed to parse public key: {}", e))?;
+
+        let point = public_key.to_encoded_point(false);
+        let (x, y) = match (point.x(), point.y()) {
+            (Some(x), Some(y)) => (URL_SAFE_NO_PAD.encode(x), URL_SAFE_NO_PAD.encode(y)),
+            _ => anyhow::bail!("Failed to parse public key: missing coordinates"),
+        };
+
+        // Thumbprint input: required members in lexicographic order, no whitespace
+        let thumbprint_input = format!(r#"{{"crv":"P-256","kty":"EC","x":"{}","y":"{}"}}"#, x, y);
+        let kid = URL_SAFE_NO_PAD.encode(Sha256::digest(thumbprint_input.as_bytes()));
+
+        Ok(Self {
+            kty: "EC".to_string(),
+            crv: "P-256".to_string(),
+            x,
+            y,
+            kid,
+            key_use: "sig".to_string(),
+            alg: "ES256".to_string(),
+        })
+    }
+}
+
+/// JWK Set document (RFC 7517)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+/// Public key accepted when validating tokens
+struct VerificationKey {
+    kid: String,
+    decoding_key: DecodingKey,
+    jwk: Jwk,
+}
+
+impl VerificationKey {
+    fn from_ec_pem(kid: String, public_key_pem: &[u8]) -> Result<Self> {
+        let decoding_key = DecodingKey::from_ec_pem(public_key_pem)
+            .map_err(|e| anyhow::anyhow!("Failed to parse public key {}: {}", kid, e))?;
+
+        let mut jwk = Jwk::from_ec_pem(public_key_pem)?;
+        jwk.kid = kid.clone();
+
+        Ok(Self {
+            kid,
+            decoding_key,
+            jwk,
+        })
+    }
+}
+
+/// JWT Authentication Service using ES256 algorithm.
+///
+/// Tokens are signed with the current key and carry its `kid` in the header.
+/// Previous public keys stay valid for verification until they are removed,
+/// so rotating the signing key does not invalidate issued tokens.
 pub struct JwtAuthService {
+    signing_kid: String,
     encoding_key: EncodingKey,
-    decoding_key: DecodingKey,
+    /// Current key first, followed by previous keys
+    verification_keys: Vec<VerificationKey>,
+    issuer: String,
+    client_audience: String,
+    admin_audience: String,
     access_token_expiry: i64,
     refresh_token_expiry: i64,
 }
@@ -36,53 +141,245 @@ impl JwtAuthService {
         )
     }
 
-    /// Create a new JWT service from key content
+    /// Create a new JWT service from key content.
+    /// The key ID is the RFC 7638 thumbprint of the public key.
     pub fn new_from_keys(
         private_key_pem: &[u8],
         public_key_pem: &[u8],
         access_token_expiry: i64,
         refresh_token_expiry: i64,
+    ) -> Result<Self> {
+        let kid = Jwk::from_ec_pem(public_key_pem)?.kid;
+
+        Self::new_with_kid(
+            kid,
+            private_key_pem,
+            public_key_pem,
+            access_token_expiry,
+            refresh_token_expiry,
+        )
+    }
+
+    /// Create a new JWT service from key content with an explicit key ID
+    pub fn new_with_kid(
+        kid: impl Into<String>,
+        private_key_pem: &[u8],
+        public_key_pem: &[u8],
+        access_token_expiry: i64,
+        refresh_token_expiry: i64,
     ) -> Result<Self> {
         let encoding_key = EncodingKey::from_ec_pem(private_key_pem)
             .map_err(|e| anyhow::anyhow!("Failed to parse private key: {}", e))?;
 
-        let decoding_key = DecodingKey::from_ec_pem(public_key_pem)
-            .map_err(|e| anyhow::anyhow!("Failed to parse public key: {}", e))?;
+        let current_key = VerificationKey::from_ec_pem(kid.into(), public_key_pem)?;
 
         Ok(Self {
+            signing_kid: current_key.kid.clone(),
             encoding_key,
-            decoding_key,
+            verification_keys: vec![current_key],
+            issuer: DEFAULT_ISSUER.to_string(),
+            client_audience: DEFAULT_CLIENT_AUDIENCE.to_string(),
+            admin_audience: DEFAULT_ADMIN_AUDIENCE.to_string(),
             access_token_expiry,
             refresh_token_expiry,
         })
     }
+
+    /// Load a key ring from a directory.
+    ///
+    /// Keys are stored as `<kid>.private.pem` / `<kid>.public.pem`. The key
+    /// named by `current_kid` signs new tokens; every other public key is only
+    /// used for verification. Without `current_kid` the directory must hold
+    /// exactly one private key.
+    pub fn from_keys_dir(
+        dir: impl AsRef<Path>,
+        current_kid: Option<&str>,
+        access_token_expiry: i64,
+        refresh_token_expiry: i64,
+    ) -> Result<Self> {
+        let dir = dir.as_ref();
+
+        let mut private_kids = Vec::new();
+        let mut public_kids = Vec::new();
+        for entry in fs::read_dir(dir)
+            .map_err(|e| anyhow::anyhow!("Failed to read keys directory: {}", e))?
+        {
+            let file_name = entry?.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            if let Some(kid) = file_name.strip_suffix(".private.pem") {
+                private_kids.push(kid.to_string());
+            } else if let Some(kid) = file_name.strip_suffix(".public.pem") {
+                public_kids.push(kid.to_string());
+            }
+        }
+        public_kids.sort();
+
+        let current_kid = match current_kid {
+            Some(kid) => kid.to_string(),
+            None if private_kids.len() == 1 => private_kids.remove(0),
+            None => anyhow::bail!(
+                "Expected exactly one private key in {}, set the current key ID explicitly",
+                dir.display()
+            ),
+        };
+
+        let read_key = |kid: &str, kind: &str| {
+            let path = dir.join(format!("{}.{}.pem", kid, kind));
+            fs::read(&path).map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))
+        };
+
+        let mut service = Self::new_with_kid(
+            current_kid.clone(),
+            &read_key(&current_kid, "private")?,
+            &read_key(&current_kid, "public")?,
+            access_token_expiry,
+            refresh_token_expiry,
+        )?;
+
+        for kid in public_kids.iter().filter(|kid| **kid != current_kid) {
+            service = service.with_verification_key(kid.clone(), &read_key(kid, "public")?)?;
+        }
+
+        Ok(service)
+    }
+
+    /// Accept tokens signed by a previous key
+    pub fn with_verification_key(
+        mut self,
+        kid: impl Into<String>,
+        public_key_pem: &[u8],
+    ) -> Result<Self> {
+        let key = VerificationKey::from_ec_pem(kid.into(), public_key_pem)?;
+
+        if self.verification_keys.iter().any(|k| k.kid == key.kid) {
+            anyhow::bail!("Duplicate key ID: {}", key.kid);
+        }
+
+        self.verification_keys.push(key);
+        Ok(self)
+    }
+
+    /// Set the audiences of client and admin tokens
+    pub fn with_audiences(
+        mut self,
+        client_audience: impl Into<String>,
+        admin_audience: impl Into<String>,
+    ) -> Self {
+        self.client_audience = client_audience.into();
+        self.admin_audience = admin_audience.into();
+        self
+    }
+
+    /// Audience of tokens issued to the given user type
+    pub fn audience_for(&self, user_type: &str) -> &str {
+        if user_type == "admin" {
+            &self.admin_audience
+        } else {
+            &self.client_audience
+        }
+    }
+
+    /// Set the issuer of tokens, also advertised by the discovery document
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = issuer.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// ID of the key signing new tokens
+    pub fn signing_kid(&self) -> &str {
+        &self.signing_kid
+    }
+
+    /// Public verification keys as a JWK Set
+    pub fn jwks(&self) -> JwkSet {
+        JwkSet {
+            keys: self
+                .verification_keys
+                .iter()
+                .map(|key| key.jwk.clone())
+                .collect(),
+        }
+    }
+
+    /// Access token lifetime in seconds
+    pub fn access_token_expiry(&self) -> i64 {
+        self.access_token_expiry
+    }
+
+    fn claims(&self, claims: Claims) -> Claims {
+        let audience = self.audience_for(&claims.user_type).to_string();
+        claims.with_issuer(&self.issuer).with_audience(audience)
+    }
+
+    fn header(&self) -> Header {
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some(self.signing_kid.clone());
+        header
+    }
 }
 
 impl AuthService for JwtAuthService {
     fn generate_access_token(&self, user_id: Uuid, user_type: String) -> Result<String> {
-        let claims = Claims::new_access_token(user_id, user_type, self.access_token_expiry);
-        let header = Header::new(Algorithm::ES256);
+        let claims = self.claims(Claims::new_access_token(
+            user_id,
+            user_type,
+            self.access_token_expiry,
+        ));
 
-        encode(&header, &claims, &self.encoding_key)
+        encode(&self.header(), &claims, &self.encoding_key)
             .map_err(|e| anyhow::anyhow!("Failed to generate access token: {}", e))
     }
 
     fn generate_refresh_token(&self, user_id: Uuid, user_type: String) -> Result<String> {
-        let claims = Claims::new_refresh_token(user_id, user_type, self.refresh_token_expiry);
-        let header = Header::new(Algorithm::ES256);
+        let claims = self.claims(Claims::new_refresh_token(
+            user_id,
+            user_type,
+            self.refresh_token_expiry,
+        ));
 
-        encode(&header, &claims, &self.encoding_key)
+        encode(&self.header(), &claims, &self.encoding_key)
             .map_err(|e| anyhow::anyhow!("Failed to generate refresh token: {}", e))
     }
 
     fn validate_token(&self, token: &str) -> Result<Claims> {
         let mut validation = Validation::new(Algorithm::ES256);
         validation.validate_exp = true;
+        validation.validate_nbf = true;
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.client_audience, &self.admin_audience]);
+        validation.set_required_spec_claims(&["exp", "nbf", "iss", "aud"]);
+
+        let header = decode_header(token).map_err(|e| anyhow::anyhow!("Invalid token: {}", e))?;
+
+        // Tokens issued before key IDs were introduced have none, try every key
+        let candidates = self
+            .verification_keys
+            .iter()
+            .filter(|key| header.kid.as_ref().is_none_or(|kid| *kid == key.kid));
+
+        let mut error = anyhow::anyhow!("Invalid token: unknown key ID");
+        for key in candidates {
+            match decode::<Claims>(token, &key.decoding_key, &validation) {
+                Ok(token_data) => {
+                    let claims = token_data.claims;
 
-        let token_data = decode::<Claims>(token, &self.decoding_key, &validation)
-            .map_err(|e| anyhow::anyhow!("Invalid token: {}", e))?;
+                    // An admin token must not pass as a client token and vice versa
+                    if claims.aud != self.audience_for(&claims.user_type) {
+                        anyhow::bail!("Invalid token: audience does not match user type");
+                    }
 
-        Ok(token_data.claims)
+                    return Ok(claims);
+                }
+                Err(e) => error = anyhow::anyhow!("Invalid token: {}", e),
+            }
+        }
+
+        Err(error)
     }
 }
 
@@ -110,6 +407,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_jwk_from_public_key() {
+        let public_key_pem = r#"-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAESUZJcBP06/6LCACxBIHoDsQK64E/
+izIFgemQyI98KFfNGlcD4zdkyQtbc0TXiICcWlT4y5XL3pe+OdPnLfHvKA==
+-----END PUBLIC KEY-----"#;
+
+        let jwk = Jwk::from_ec_pem(public_key_pem.as_bytes()).unwrap();
+        assert_eq!(jwk.kty, "EC");
+        assert_eq!(jwk.crv, "P-256");
+        // 32 byte coordinates and a SHA-256 thumbprint, base64url without padding
+        assert_eq!(jwk.x.len(), 43);
+        assert_eq!(jwk.y.len(), 43);
+        assert_eq!(jwk.kid.len(), 43);
+
+        // The thumbprint only depends on the key
+        let again = Jwk::from_ec_pem(public_key_pem.as_bytes()).unwrap();
+        assert_eq!(jwk.kid, again.kid);
+
+        assert!(Jwk::from_ec_pem(b"not a key").is_err());
+    }
+
     #[test]
     fn test_generate_and_validate_refresh_token() {
         let service =
@@ -127,4 +446,251 @@ mod tests {
             assert_eq!(claims.token_type, "refresh");
         }
     }
+
+    /// Generate a fresh P-256 key pair as (private PEM, public PEM)
+    fn generate_key_pair() -> (String, String) {
+        use argon2::password_hash::rand_core::OsRng;
+        use p256::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
+
+        let secret_key = p256::SecretKey::random(&mut OsRng);
+        let private_pem = secret_key.to_pkcs8_pem(LineEnding::LF).unwrap().to_string();
+        let public_pem = secret_key
+            .public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+
+        (private_pem, public_pem)
+    }
+
+    #[test]
+    fn test_tokens_carry_kid_header() {
+        let (private_pem, public_pem) = generate_key_pair();
+        let service = JwtAuthService::new_with_kid(
+            "2026-01",
+            private_pem.as_bytes(),
+            public_pem.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+
+        let token = service
+            .generate_access_token(Uuid::new_v4(), "user".to_string())
+            .unwrap();
+
+        assert_eq!(
+            decode_header(&token).unwrap().kid.as_deref(),
+            Some("2026-01")
+        );
+        assert_eq!(service.jwks().keys[0].kid, "2026-01");
+    }
+
+    #[test]
+    fn test_previous_keys_remain_valid_after_rotation() {
+        let (old_private, old_public) = generate_key_pair();
+        let (new_private, new_public) = generate_key_pair();
+
+        let old_service = JwtAuthService::new_with_kid(
+            "old",
+            old_private.as_bytes(),
+            old_public.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+        let old_token = old_service
+            .generate_access_token(Uuid::new_v4(), "user".to_string())
+            .unwrap();
+
+        let rotated = JwtAuthService::new_with_kid(
+            "new",
+            new_private.as_bytes(),
+            new_public.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap()
+        .with_verification_key("old", old_public.as_bytes())
+        .unwrap();
+
+        assert!(rotated.validate_token(&old_token).is_ok());
+        assert_eq!(rotated.jwks().keys.len(), 2);
+
+        // Once retired, the old key no longer validates its tokens
+        let retired = JwtAuthService::new_with_kid(
+            "new",
+            new_private.as_bytes(),
+            new_public.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+        assert!(retired.validate_token(&old_token).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_kid_rejected() {
+        let (private_pem, public_pem) = generate_key_pair();
+        let service = JwtAuthService::new_with_kid(
+            "dup",
+            private_pem.as_bytes(),
+            public_pem.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+
+        assert!(
+            service
+                .with_verification_key("dup", public_pem.as_bytes())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_from_keys_dir() {
+        let dir = std::env::temp_dir().join(format!("caxur-keys-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let (old_private, old_public) = generate_key_pair();
+        let (new_private, new_public) = generate_key_pair();
+        fs::write(dir.join("old.private.pem"), &old_private).unwrap();
+        fs::write(dir.join("old.public.pem"), &old_public).unwrap();
+        fs::write(dir.join("new.private.pem"), &new_private).unwrap();
+        fs::write(dir.join("new.public.pem"), &new_public).unwrap();
+
+        // Two private keys: the current one must be named
+        assert!(JwtAuthService::from_keys_dir(&dir, None, 900, 604800).is_err());
+        assert!(JwtAuthService::from_keys_dir(&dir, Some("missing"), 900, 604800).is_err());
+
+        let service = JwtAuthService::from_keys_dir(&dir, Some("new"), 900, 604800).unwrap();
+        assert_eq!(service.signing_kid(), "new");
+
+        let kids: Vec<String> = service.jwks().keys.into_iter().map(|k| k.kid).collect();
+        assert_eq!(kids, vec!["new".to_string(), "old".to_string()]);
+
+        // With the old private key removed the remaining one is picked automatically
+        fs::remove_file(dir.join("old.private.pem")).unwrap();
+        let service = JwtAuthService::from_keys_dir(&dir, None, 900, 604800).unwrap();
+        assert_eq!(service.signing_kid(), "new");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_kid_rejected() {
+        let (private_pem, public_pem) = generate_key_pair();
+        let (other_private, other_public) = generate_key_pair();
+
+        let service = JwtAuthService::new_with_kid(
+            "a",
+            private_pem.as_bytes(),
+            public_pem.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+        let other = JwtAuthService::new_with_kid(
+            "b",
+            other_private.as_bytes(),
+            other_public.as_bytes(),
+            900,
+            604800,
+        )
+        .unwrap();
+
+        let token = other
+            .generate_access_token(Uuid::new_v4(), "user".to_string())
+            .unwrap();
+        let err = service.validate_token(&token).unwrap_err();
+        assert!(err.to_string().contains("unknown key ID"));
+    }
+
+    fn test_service() -> JwtAuthService {
+        let (private_pem, public_pem) = generate_key_pair();
+        JwtAuthService::new_from_keys(private_pem.as_bytes(), public_pem.as_bytes(), 900, 604800)
+            .unwrap()
+            .with_issuer("https://auth.example.com")
+    }
+
+    #[test]
+    fn test_tokens_carry_issuer_and_audience() {
+        let service = test_service().with_audiences("shop", "backoffice");
+
+        let user_token = service
+            .generate_access_token(Uuid::new_v4(), "user".to_string())
+            .unwrap();
+        let admin_token = service
+            .generate_refresh_token(Uuid::new_v4(), "admin".to_string())
+            .unwrap();
+
+        let user_claims = service.validate_token(&user_token).unwrap();
+        assert_eq!(user_claims.iss, "https://auth.example.com");
+        assert_eq!(user_claims.aud, "shop");
+        assert_eq!(user_claims.nbf, user_claims.iat);
+
+        let admin_claims = service.validate_token(&admin_token).unwrap();
+        assert_eq!(admin_claims.aud, "backoffice");
+    }
+
+    #[test]
+    fn test_wrong_issuer_rejected() {
+        let service = test_service();
+        let token = service
+            .generate_access_token(Uuid::new_v4(), "user".to_string())
+            .unwrap();
+
+        let other_issuer = JwtAuthService {
+            issuer: "https://evil.example.com".to_string(),
+            ..service
+        };
+        assert!(other_issuer.validate_token(&token).is_err());
+    }
+
+    #[test]
+    fn test_audience_must_match_user_type() {
+        let service = test_service();
+
+        // Admin claims carrying the client audience
+        let claims = Claims::new_access_token(Uuid::new_v4(), "admin".to_string(), 900)
+            .with_issuer(service.issuer())
+            .with_audience(DEFAULT_CLIENT_AUDIENCE);
+        let token = encode(&service.header(), &claims, &service.encoding_key).unwrap();
+
+        let err = service.validate_token(&token).unwrap_err();
+        assert!(err.to_string().contains("audience"));
+
+        // Unknown audience
+        let claims = Claims::new_access_token(Uuid::new_v4(), "user".to_string(), 900)
+            .with_issuer(service.issuer())
+            .with_audience("another-service");
+        let token = encode(&service.header(), &claims, &service.encoding_key).unwrap();
+        assert!(service.validate_token(&token).is_err());
+    }
+
+    #[test]
+    fn test_not_before_enforced() {
+        let service = test_service();
+
+        let mut claims = service.claims(Claims::new_access_token(
+            Uuid::new_v4(),
+            "user".to_string(),
+            900,
+        ));
+        claims.nbf += 600;
+        let token = encode(&service.header(), &claims, &service.encoding_key).unwrap();
+
+        assert!(service.validate_token(&token).is_err());
+    }
+
+    #[test]
+    fn test_missing_issuer_and_audience_rejected() {
+        let service = test_service();
+
+        // Tokens minted before `iss` and `aud` were added
+        let claims = Claims::new_access_token(Uuid::new_v4(), "user".to_string(), 900);
+        let token = encode(&service.header(), &claims, &service.encoding_key).unwrap();
+
+        assert!(service.validate_token(&token).is_err());
+    }
 }
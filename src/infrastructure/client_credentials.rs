@@ -0,0 +1,75 @@
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Credentials of services allowed to call protected OAuth endpoints such as introspection.
+///
+/// Only SHA-256 digests of the secrets are kept in memory.
+#[derive(Debug, Clone, Default)]
+pub struct ClientCredentials {
+    clients: HashMap<String, [u8; 32]>,
+}
+
+impl ClientCredentials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a comma separated list of `client_id:client_secret` pairs.
+    /// Malformed entries are skipped.
+    pub fn parse(value: &str) -> Self {
+        value
+            .split(',')
+            .filter_map(|entry| entry.trim().split_once(':'))
+            .filter(|(id, secret)| !id.is_empty() && !secret.is_empty())
+            .fold(Self::new(), |clients, (id, secret)| {
+                clients.with_client(id, secret)
+            })
+    }
+
+    pub fn with_client(mut self, client_id: impl Into<String>, client_secret: &str) -> Self {
+        self.clients
+            .insert(client_id.into(), Sha256::digest(client_secret).into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Check a client's secret without leaking timing information about it
+    pub fn verify(&self, client_id: &str, client_secret: &str) -> bool {
+        let Some(expected) = self.clients.get(client_id) else {
+            return false;
+        };
+
+        let actual: [u8; 32] = Sha256::digest(client_secret).into();
+        expected
+            .iter()
+            .zip(actual.iter())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_verify() {
+        let clients = ClientCredentials::parse("orders:s3cret, billing:other ,broken,:nosecret");
+
+        assert!(clients.verify("orders", "s3cret"));
+        assert!(clients.verify("billing", "other"));
+        assert!(!clients.verify("orders", "wrong"));
+        assert!(!clients.verify("broken", ""));
+        assert!(!clients.verify("", "nosecret"));
+        assert!(!clients.verify("unknown", "s3cret"));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(ClientCredentials::parse("").is_empty());
+        assert!(!ClientCredentials::new().with_client("a", "b").is_empty());
+    }
+}
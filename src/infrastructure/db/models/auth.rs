@@ -1,4 +1,4 @@
-use crate::domain::auth::RefreshToken;
+use crate::domain::auth::{RefreshToken, Session};
 use sqlx::FromRow;
 use time::OffsetDateTime;
 use uuid::Uuid;
@@ -9,6 +9,13 @@ pub struct RefreshTokenDbModel {
     pub user_id: Uuid,
     pub user_type: String,
     pub token_hash: String,
+    pub family_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub rotated_at: Option<OffsetDateTime>,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    pub last_used_at: OffsetDateTime,
     pub expires_at: OffsetDateTime,
     pub created_at: OffsetDateTime,
 }
@@ -20,8 +27,44 @@ impl From<RefreshTokenDbModel> for RefreshToken {
             user_id: model.user_id,
             user_type: model.user_type,
             token_hash: model.token_hash,
+            family_id: model.family_id,
+            parent_id: model.parent_id,
+            rotated_at: model.rotated_at,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            device_name: model.device_name,
+            last_used_at: model.last_used_at,
             expires_at: model.expires_at,
             created_at: model.created_at,
         }
     }
 }
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SessionDbModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_type: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    pub created_at: OffsetDateTime,
+    pub last_used_at: OffsetDateTime,
+    pub expires_at: OffsetDateTime,
+}
+
+impl From<SessionDbModel> for Session {
+    fn from(model: SessionDbModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            user_type: model.user_type,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            device_name: model.device_name,
+            created_at: model.created_at,
+            last_used_at: model.last_used_at,
+            expires_at: model.expires_at,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::domain::login_attempts::LoginAttempts;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct LoginAttemptsDbModel {
+    pub failed_count: i32,
+    pub last_failed_at: OffsetDateTime,
+    pub locked_until: Option<OffsetDateTime>,
+}
+
+impl From<LoginAttemptsDbModel> for LoginAttempts {
+    fn from(model: LoginAttemptsDbModel) -> Self {
+        Self {
+            failed_count: model.failed_count,
+            last_failed_at: model.last_failed_at,
+            locked_until: model.locked_until,
+        }
+    }
+}
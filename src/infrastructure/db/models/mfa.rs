@@ -0,0 +1,27 @@
+use crate::domain::mfa::TotpEnrollment;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TotpEnrollmentDbModel {
+    pub administrator_id: Uuid,
+    pub secret: String,
+    pub confirmed_at: Option<OffsetDateTime>,
+    pub last_used_step: Option<i64>,
+    pub created_at: OffsetDateTime,
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<TotpEnrollmentDbModel> for TotpEnrollment {
+    fn from(model: TotpEnrollmentDbModel) -> Self {
+        Self {
+            administrator_id: model.administrator_id,
+            secret: model.secret,
+            confirmed_at: model.confirmed_at,
+            last_used_step: model.last_used_step,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
@@ -1,4 +1,9 @@
 pub mod administrators;
 pub mod auth;
+pub mod groups;
+pub mod login_attempts;
+pub mod mfa;
+pub mod one_time_tokens;
+pub mod passkeys;
 pub mod roles;
 pub mod users;
@@ -0,0 +1,31 @@
+use crate::domain::one_time_tokens::OneTimeToken;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct OneTimeTokenDbModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_type: String,
+    pub purpose: String,
+    pub token_hash: String,
+    pub expires_at: OffsetDateTime,
+    pub used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<OneTimeTokenDbModel> for OneTimeToken {
+    fn from(model: OneTimeTokenDbModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            user_type: model.user_type,
+            purpose: model.purpose,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            used_at: model.used_at,
+            created_at: model.created_at,
+        }
+    }
+}
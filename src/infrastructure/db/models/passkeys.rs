@@ -0,0 +1,31 @@
+use crate::domain::passkeys::Passkey;
+use sqlx::FromRow;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow)]
+pub struct PasskeyDbModel {
+    pub id: Uuid,
+    pub administrator_id: Uuid,
+    pub credential_id: String,
+    pub public_key: String,
+    pub sign_count: i64,
+    pub name: String,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub created_at: OffsetDateTime,
+}
+
+impl From<PasskeyDbModel> for Passkey {
+    fn from(model: PasskeyDbModel) -> Self {
+        Self {
+            id: model.id,
+            administrator_id: model.administrator_id,
+            credential_id: model.credential_id,
+            public_key: model.public_key,
+            sign_count: model.sign_count,
+            name: model.name,
+            last_used_at: model.last_used_at,
+            created_at: model.created_at,
+        }
+    }
+}
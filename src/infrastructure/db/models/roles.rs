@@ -10,6 +10,7 @@ pub struct RoleDbModel {
     pub description: Option<String>,
     pub scope: String,
     pub group_id: Option<Uuid>,
+    pub parent_ids: Vec<Uuid>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
@@ -24,6 +25,7 @@ impl From<RoleDbModel> for Role {
             description: model.description,
             scope: model.scope.parse().unwrap_or(AccessScope::Administrator),
             group_id: model.group_id,
+            parent_ids: model.parent_ids,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -9,6 +9,7 @@ pub struct UserDbModel {
     pub username: String,
     pub email: String,
     pub password_hash: String,
+    pub email_verified_at: Option<OffsetDateTime>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
 }
@@ -20,6 +21,7 @@ impl From<UserDbModel> for User {
             username: model.username,
             email: model.email,
             password_hash: model.password_hash,
+            email_verified_at: model.email_verified_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
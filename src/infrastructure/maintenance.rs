@@ -0,0 +1,180 @@
+use crate::domain::maintenance::MaintenanceJob;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Runs maintenance jobs periodically in a background task.
+///
+/// Each round waits `interval` plus a random delay of up to `jitter`, so several
+/// instances started together don't all hit the database at the same moment.
+pub struct MaintenanceScheduler {
+    interval: Duration,
+    jitter: Duration,
+    jobs: Vec<Arc<dyn MaintenanceJob>>,
+}
+
+impl MaintenanceScheduler {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            jitter: Duration::ZERO,
+            jobs: Vec::new(),
+        }
+    }
+
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_job(mut self, job: Arc<dyn MaintenanceJob>) -> Self {
+        self.jobs.push(job);
+        self
+    }
+
+    /// Run every job once, logging failures without stopping the remaining jobs
+    pub async fn run_once(&self) {
+        for job in &self.jobs {
+            let span = tracing::info_span!("maintenance_job", job = job.name());
+
+            async {
+                match job.run().await {
+                    Ok(affected) => tracing::info!(affected, "maintenance job completed"),
+                    Err(e) => tracing::error!(error = %e, "maintenance job failed"),
+                }
+            }
+            .instrument(span)
+            .await;
+        }
+    }
+
+    /// Spawn the scheduler loop
+    pub fn start(self) -> MaintenanceHandle {
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+        let task = tokio::spawn(async move {
+            loop {
+                let delay = self.interval + random_delay(self.jitter);
+
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => self.run_once().await,
+                    _ = shutdown_rx.changed() => break,
+                }
+            }
+
+            tracing::debug!("maintenance scheduler stopped");
+        });
+
+        MaintenanceHandle { shutdown_tx, task }
+    }
+}
+
+/// Handle to a running scheduler
+pub struct MaintenanceHandle {
+    shutdown_tx: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl MaintenanceHandle {
+    /// Stop the scheduler, letting a round that is already running finish first
+    pub async fn shutdown(self) {
+        let _ = self.shutdown_tx.send(true);
+        let _ = self.task.await;
+    }
+}
+
+/// Uniformly random delay in `[0, max]`
+fn random_delay(max: Duration) -> Duration {
+    let max_millis = max.as_millis();
+    if max_millis == 0 {
+        return Duration::ZERO;
+    }
+
+    // v4 UUIDs are backed by the OS random number generator
+    let millis = Uuid::new_v4().as_u128() % (max_millis + 1);
+    Duration::from_millis(millis as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingJob {
+        runs: AtomicUsize,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl MaintenanceJob for CountingJob {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn run(&self) -> Result<u64> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                anyhow::bail!("job failed");
+            }
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_random_delay_is_bounded() {
+        assert_eq!(random_delay(Duration::ZERO), Duration::ZERO);
+
+        let max = Duration::from_millis(50);
+        for _ in 0..100 {
+            assert!(random_delay(max) <= max);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_once_continues_after_failure() {
+        let failing = Arc::new(CountingJob {
+            runs: AtomicUsize::new(0),
+            fail: true,
+        });
+        let succeeding = Arc::new(CountingJob {
+            runs: AtomicUsize::new(0),
+            fail: false,
+        });
+
+        MaintenanceScheduler::new(Duration::from_secs(60))
+            .with_job(failing.clone())
+            .with_job(succeeding.clone())
+            .run_once()
+            .await;
+
+        assert_eq!(failing.runs.load(Ordering::SeqCst), 1);
+        assert_eq!(succeeding.runs.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_runs_periodically_until_shutdown() {
+        let job = Arc::new(CountingJob {
+            runs: AtomicUsize::new(0),
+            fail: false,
+        });
+
+        let handle = MaintenanceScheduler::new(Duration::from_millis(10))
+            .with_job(job.clone())
+            .start();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.shutdown().await;
+
+        let runs = job.runs.load(Ordering::SeqCst);
+        assert!(runs >= 1);
+
+        // No further rounds after shutdown
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(job.runs.load(Ordering::SeqCst), runs);
+    }
+}
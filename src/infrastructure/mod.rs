@@ -1,5 +1,11 @@
 pub mod auth;
+pub mod client_credentials;
 pub mod db;
+pub mod maintenance;
+pub mod notifications;
 pub mod password;
+pub mod permission_cache;
 pub mod repositories;
+pub mod secret_cipher;
 pub mod state;
+pub mod token_denylist;
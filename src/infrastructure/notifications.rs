@@ -0,0 +1,57 @@
+use crate::domain::notifications::{Notification, NotificationService};
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// Writes notifications to the application log instead of delivering them.
+///
+/// Used until a real delivery channel (SMTP, queue, ...) is configured. Only the
+/// recipient and expiry are logged; anyone reading the logs could otherwise take
+/// over the account, so tokens are only included after an explicit
+/// [`with_token_logging`](Self::with_token_logging) opt-in for local development.
+#[derive(Debug, Default)]
+pub struct LogNotificationService {
+    log_tokens: bool,
+}
+
+impl LogNotificationService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the tokens in the log, never enable this outside local development
+    pub fn with_token_logging(mut self, enabled: bool) -> Self {
+        self.log_tokens = enabled;
+        self
+    }
+}
+
+#[async_trait]
+impl NotificationService for LogNotificationService {
+    async fn send(&self, notification: Notification) -> Result<()> {
+        match &notification {
+            Notification::PasswordReset {
+                email,
+                user_type,
+                token,
+                expires_at,
+            } => {
+                tracing::info!(recipient = %email, user_type = %user_type, expires_at = %expires_at, "Password reset requested");
+                if self.log_tokens {
+                    tracing::info!(recipient = %email, token = %token, "Password reset token");
+                }
+            }
+            Notification::EmailVerification {
+                email,
+                token,
+                expires_at,
+            } => {
+                tracing::info!(recipient = %email, expires_at = %expires_at, "Email verification requested");
+                if self.log_tokens {
+                    tracing::info!(recipient = %email, token = %token, "Email verification token");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,23 +1,38 @@
 use crate::domain::password::PasswordHashingService;
 use anyhow::Result;
 use argon2::{
-    Argon2,
+    Algorithm, Argon2, Params, Version,
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString, rand_core::OsRng},
 };
 
 /// Infrastructure service for password hashing and verification
 #[derive(Clone)]
-pub struct PasswordService;
+pub struct PasswordService {
+    params: Params,
+}
 
 impl PasswordService {
+    /// Argon2id with the crate's default cost (m=19456, t=2, p=1)
     pub fn new() -> Self {
-        Self
+        Self {
+            params: Params::default(),
+        }
+    }
+
+    /// Hash with a different memory (KiB), iteration and parallelism cost
+    pub fn with_params(mut self, params: Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    fn argon2(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
     }
 }
 
 impl Default for PasswordService {
     fn default() -> Self {
-        Self
+        Self::new()
     }
 }
 
@@ -26,9 +41,9 @@ impl PasswordHashingService for PasswordService {
     /// Hash a plain text password using Argon2
     fn hash_password(&self, password: &str) -> Result<String> {
         let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
 
-        let password_hash = argon2
+        let password_hash = self
+            .argon2()
             .hash_password(password.as_bytes(), &salt)
             .map_err(|e| anyhow::anyhow!("Failed to hash password: {}", e))?
             .to_string();
@@ -41,12 +56,34 @@ impl PasswordHashingService for PasswordService {
         let parsed_hash =
             PasswordHash::new(hash).map_err(|e| anyhow::anyhow!("Invalid password hash: {}", e))?;
 
-        let argon2 = Argon2::default();
-
-        Ok(argon2
+        // Cost parameters are read from the hash itself, so older hashes still verify
+        Ok(self
+            .argon2()
             .verify_password(password.as_bytes(), &parsed_hash)
             .is_ok())
     }
+
+    /// Whether the hash was made with another algorithm, version or cost than configured
+    fn needs_rehash(&self, hash: &str) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(hash) else {
+            return false;
+        };
+
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident()
+            || parsed_hash.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+
+        match Params::try_from(&parsed_hash) {
+            Ok(params) => {
+                params.m_cost() != self.params.m_cost()
+                    || params.t_cost() != self.params.t_cost()
+                    || params.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,9 +136,37 @@ mod tests {
         assert!(service.verify_password(password, &hash2).unwrap());
     }
 
+    #[test]
+    fn test_needs_rehash_when_cost_changes() {
+        let weak = PasswordService::new();
+        let strong = PasswordService::new().with_params(Params::new(32768, 3, 1, None).unwrap());
+        let hash = weak.hash_password("testpassword123").unwrap();
+
+        assert!(!weak.needs_rehash(&hash));
+        assert!(strong.needs_rehash(&hash));
+        // Old hashes keep verifying under the new parameters
+        assert!(strong.verify_password("testpassword123", &hash).unwrap());
+
+        let upgraded = strong.hash_password("testpassword123").unwrap();
+        assert!(upgraded.contains("m=32768,t=3,p=1"));
+        assert!(!strong.needs_rehash(&upgraded));
+    }
+
+    #[test]
+    fn test_needs_rehash_for_other_argon2_variants() {
+        let service = PasswordService::new();
+        let argon2i = Argon2::new(Algorithm::Argon2i, Version::V0x13, Params::default())
+            .hash_password(b"testpassword123", &SaltString::generate(&mut OsRng))
+            .unwrap()
+            .to_string();
+
+        assert!(service.needs_rehash(&argon2i));
+        assert!(!service.needs_rehash("not a hash"));
+    }
+
     #[test]
     fn test_default_implementation() {
-        let service = PasswordService::default();
+        let service = <PasswordService as Default>::default();
         let password = "testpassword123";
         let hash = service.hash_password(password).unwrap();
         assert!(service.verify_password(password, &hash).unwrap());
@@ -0,0 +1,485 @@
+use crate::domain::access_scope::AccessScope;
+use crate::domain::administrators::AdministratorRepository;
+use crate::domain::permissions::Permission;
+use crate::domain::users::UserRepository;
+use crate::infrastructure::db::DbPool;
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::postgres::PgListener;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+/// Default lifetime of cached permissions
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Channel the database triggers publish permission changes on
+pub const PERMISSION_CHANGES_CHANNEL: &str = "permission_changes";
+
+/// Payload invalidating every cached entry
+const INVALIDATE_ALL: &str = "*";
+
+/// Delay before reconnecting a failed listener
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Account whose permissions are cached, within a group when one is given
+type Subject = (AccessScope, Uuid, Option<Uuid>);
+
+/// Cached permissions, None for group lookups of non-members
+type Entry = (Option<Arc<Vec<Permission>>>, Instant);
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<Subject, Entry>,
+    /// Bumped on every invalidation so lookups started before one aren't cached
+    generation: u64,
+}
+
+/// Lookup counters of a [`PermissionCache`]
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64,
+    pub entries: usize,
+}
+
+impl PermissionCacheStats {
+    /// Share of lookups answered from the cache, 0 before the first lookup
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// In-memory cache of the permissions of administrators and users.
+///
+/// Entries are dropped after `ttl` and whenever role assignments or role
+/// permissions change. Changes made through this instance invalidate the cache
+/// directly, changes made elsewhere arrive through [`PermissionCache::listen`].
+pub struct PermissionCache {
+    administrators: Arc<dyn AdministratorRepository>,
+    users: Arc<dyn UserRepository>,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+impl PermissionCache {
+    pub fn new(
+        administrators: Arc<dyn AdministratorRepository>,
+        users: Arc<dyn UserRepository>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            administrators,
+            users,
+            ttl,
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            invalidations: AtomicU64::new(0),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        // The cache holds no invariants worth failing over, recover from poisoning
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Permissions granted through their roles to the `scope` account
+    /// `subject_id`, ignoring roles of groups
+    pub async fn permissions(
+        &self,
+        scope: AccessScope,
+        subject_id: Uuid,
+    ) -> Result<Arc<Vec<Permission>>> {
+        Ok(self
+            .lookup((scope, subject_id, None))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Permissions granted to `user_id` by the roles of `group_id`, None
+    /// unless the user is a member of the group
+    pub async fn group_permissions(
+        &self,
+        user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Option<Arc<Vec<Permission>>>> {
+        self.lookup((AccessScope::User, user_id, Some(group_id)))
+            .await
+    }
+
+    async fn lookup(&self, key: Subject) -> Result<Option<Arc<Vec<Permission>>>> {
+        let generation = {
+            let state = self.state();
+            if let Some((permissions, cached_at)) = state.entries.get(&key)
+                && cached_at.elapsed() < self.ttl
+            {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(permissions.clone());
+            }
+            state.generation
+        };
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let permissions = match key {
+            (AccessScope::Administrator, id, _) => {
+                Some(self.administrators.get_permissions(id).await?)
+            }
+            (AccessScope::User, id, None) => Some(self.users.get_permissions(id).await?),
+            (AccessScope::User, id, Some(group_id)) => {
+                self.users.get_group_permissions(id, group_id).await?
+            }
+        }
+        .map(Arc::new);
+
+        let mut state = self.state();
+        if state.generation == generation {
+            state
+                .entries
+                .retain(|_, (_, cached_at)| cached_at.elapsed() < self.ttl);
+            state
+                .entries
+                .insert(key, (permissions.clone(), Instant::now()));
+        }
+
+        Ok(permissions)
+    }
+
+    /// Drop the cached permissions of one account, in every group
+    pub fn invalidate(&self, scope: AccessScope, subject_id: Uuid) {
+        let mut state = self.state();
+        state.generation += 1;
+        state
+            .entries
+            .retain(|(entry_scope, id, _), _| (*entry_scope, *id) != (scope, subject_id));
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Drop every cached entry, e.g. after a role's permissions changed
+    pub fn invalidate_all(&self) {
+        let mut state = self.state();
+        state.generation += 1;
+        state.entries.clear();
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> PermissionCacheStats {
+        PermissionCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            invalidations: self.invalidations.load(Ordering::Relaxed),
+            entries: self.state().entries.len(),
+        }
+    }
+
+    /// Apply a notification published on [`PERMISSION_CHANGES_CHANNEL`],
+    /// either `<scope>:<id>` or [`INVALIDATE_ALL`]
+    fn apply_notification(&self, payload: &str) {
+        let subject = payload.split_once(':').and_then(|(scope, id)| {
+            Some((scope.parse::<AccessScope>().ok()?, id.parse::<Uuid>().ok()?))
+        });
+
+        match subject {
+            Some((scope, subject_id)) => self.invalidate(scope, subject_id),
+            None => {
+                if payload != INVALIDATE_ALL {
+                    tracing::warn!(payload, "Unexpected permission change notification");
+                }
+                self.invalidate_all();
+            }
+        }
+    }
+
+    /// Spawn a task applying the permission changes made by other instances
+    pub fn listen(self: Arc<Self>, pool: DbPool) -> PermissionListenerHandle {
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    result = self.receive_notifications(&pool) => {
+                        if let Err(e) = result {
+                            tracing::error!(error = %e, "Permission change listener failed");
+                        }
+                    }
+                    _ = shutdown_rx.changed() => break,
+                }
+
+                // Changes made while disconnected are lost, start over
+                self.invalidate_all();
+
+                tokio::select! {
+                    _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+                    _ = shutdown_rx.changed() => break,
+                }
+            }
+
+            tracing::debug!("permission change listener stopped");
+        });
+
+        PermissionListenerHandle { shutdown_tx, task }
+    }
+
+    async fn receive_notifications(&self, pool: &DbPool) -> Result<()> {
+        let mut listener = PgListener::connect_with(pool).await?;
+        listener.listen(PERMISSION_CHANGES_CHANNEL).await?;
+
+        // Notifications sent before listening are missed
+        self.invalidate_all();
+
+        // `try_recv` yields None when the connection was lost
+        while let Some(notification) = listener.try_recv().await? {
+            self.apply_notification(notification.payload());
+        }
+
+        Ok(())
+    }
+}
+
+/// Handle to a running permission change listener
+pub struct PermissionListenerHandle {
+    shutdown_tx: watch::Sender<bool>,
+    task: JoinHandle<()>,
+}
+
+impl PermissionListenerHandle {
+    /// Stop listening and close the listener's connection
+    pub async fn shutdown(self) {
+        let _ = self.shutdown_tx.send(true);
+        let _ = self.task.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::administrators::{
+        Administrator, AssignedRole, NewAdministrator, RoleAssignment, UpdateAdministrator,
+    };
+    use crate::domain::users::{NewUser, UpdateUser, User};
+    use async_trait::async_trait;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Repository that lets administrators read roles, users nothing, puts
+    /// nobody in a group and counts lookups
+    #[derive(Default)]
+    struct CountingRepository {
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AdministratorRepository for CountingRepository {
+        async fn create(&self, _new_admin: NewAdministrator) -> Result<Administrator> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<Administrator>> {
+            Ok(None)
+        }
+        async fn find_by_email(&self, _email: &str) -> Result<Option<Administrator>> {
+            Ok(None)
+        }
+        async fn find_all(&self, _limit: i64, _offset: i64) -> Result<Vec<Administrator>> {
+            Ok(vec![])
+        }
+        async fn count(&self) -> Result<i64> {
+            Ok(0)
+        }
+        async fn update(&self, _id: Uuid, _update: UpdateAdministrator) -> Result<Administrator> {
+            unimplemented!()
+        }
+        async fn delete(&self, _id: Uuid) -> Result<bool> {
+            Ok(false)
+        }
+        async fn attach_roles(
+            &self,
+            _admin_id: Uuid,
+            _role_ids: Vec<Uuid>,
+            _assignment: RoleAssignment,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn detach_roles(
+            &self,
+            _admin_id: Uuid,
+            _role_ids: Vec<Uuid>,
+            _revoked_by: Uuid,
+        ) -> Result<()> {
+            Ok(())
+        }
+        async fn find_role_assignments(&self, _admin_id: Uuid) -> Result<Vec<AssignedRole>> {
+            Ok(vec![])
+        }
+        async fn get_permissions(&self, _admin_id: Uuid) -> Result<Vec<Permission>> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Permission::from_static("roles.read")])
+        }
+        async fn delete_expired_role_assignments(&self) -> Result<u64> {
+            Ok(0)
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository for CountingRepository {
+        async fn create(&self, _new_user: NewUser) -> Result<User> {
+            unimplemented!()
+        }
+        async fn find_by_id(&self, _id: Uuid) -> Result<Option<User>> {
+            Ok(None)
+        }
+        async fn find_by_email(&self, _email: &str) -> Result<Option<User>> {
+            Ok(None)
+        }
+        async fn find_all(&self, _limit: i64, _offset: i64) -> Result<Vec<User>> {
+            Ok(vec![])
+        }
+        async fn count(&self) -> Result<i64> {
+            Ok(0)
+        }
+        async fn update(&self, _id: Uuid, _update: UpdateUser) -> Result<User> {
+            unimplemented!()
+        }
+        async fn delete(&self, _id: Uuid) -> Result<bool> {
+            Ok(false)
+        }
+        async fn mark_email_verified(&self, _id: Uuid) -> Result<bool> {
+            Ok(false)
+        }
+        async fn attach_roles(&self, _user_id: Uuid, _role_ids: Vec<Uuid>) -> Result<()> {
+            Ok(())
+        }
+        async fn detach_roles(&self, _user_id: Uuid, _role_ids: Vec<Uuid>) -> Result<()> {
+            Ok(())
+        }
+        async fn get_permissions(&self, _user_id: Uuid) -> Result<Vec<Permission>> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![])
+        }
+        async fn get_group_permissions(
+            &self,
+            _user_id: Uuid,
+            _group_id: Uuid,
+        ) -> Result<Option<Vec<Permission>>> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(None)
+        }
+    }
+
+    fn cache(repo: &Arc<CountingRepository>) -> PermissionCache {
+        PermissionCache::new(repo.clone(), repo.clone(), Duration::from_secs(60))
+    }
+
+    #[tokio::test]
+    async fn test_caches_permissions_and_counts_hits() {
+        let repo = Arc::new(CountingRepository::default());
+        let cache = cache(&repo);
+        let admin_id = Uuid::new_v4();
+
+        for _ in 0..3 {
+            cache
+                .permissions(AccessScope::Administrator, admin_id)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(repo.lookups.load(Ordering::SeqCst), 1);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (2, 1));
+        assert!((stats.hit_ratio() - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    async fn test_scopes_are_cached_separately() {
+        let repo = Arc::new(CountingRepository::default());
+        let cache = cache(&repo);
+        let id = Uuid::new_v4();
+
+        let admin = cache
+            .permissions(AccessScope::Administrator, id)
+            .await
+            .unwrap();
+        let user = cache.permissions(AccessScope::User, id).await.unwrap();
+
+        assert_eq!(admin.len(), 1);
+        assert!(user.is_empty());
+        assert_eq!(cache.stats().entries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_user_invalidation_covers_groups() {
+        let repo = Arc::new(CountingRepository::default());
+        let cache = cache(&repo);
+        let user_id = Uuid::new_v4();
+        let group_id = Uuid::new_v4();
+
+        assert!(
+            cache
+                .group_permissions(user_id, group_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        // Non-membership is cached as well
+        cache.group_permissions(user_id, group_id).await.unwrap();
+        cache.permissions(AccessScope::User, user_id).await.unwrap();
+        assert_eq!(repo.lookups.load(Ordering::SeqCst), 2);
+
+        cache.invalidate(AccessScope::User, user_id);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_notifications_invalidate_entries() {
+        let repo = Arc::new(CountingRepository::default());
+        let cache = cache(&repo);
+        let admin_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+
+        cache
+            .permissions(AccessScope::Administrator, admin_id)
+            .await
+            .unwrap();
+        cache.permissions(AccessScope::User, user_id).await.unwrap();
+
+        // The id alone names no account
+        cache.apply_notification(&format!("user:{}", admin_id));
+        assert_eq!(cache.stats().entries, 2);
+
+        cache.apply_notification(&format!("administrator:{}", admin_id));
+        assert_eq!(cache.stats().entries, 1);
+
+        cache.apply_notification(INVALIDATE_ALL);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_listener_stops_while_reconnecting() {
+        let repo = Arc::new(CountingRepository::default());
+        let cache = Arc::new(cache(&repo));
+        // Nothing listens on port 1, so the listener keeps failing and waiting to reconnect
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(100))
+            .connect_lazy("postgres://caxur@127.0.0.1:1/caxur")
+            .unwrap();
+
+        let listener = cache.listen(pool);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        tokio::time::timeout(Duration::from_secs(1), listener.shutdown())
+            .await
+            .expect("listener did not stop");
+    }
+}
@@ -0,0 +1,114 @@
+use crate::domain::auth::{AccessTokenDenylist, Claims};
+use crate::infrastructure::db::DbPool;
+use anyhow::Result;
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+pub struct PostgresAccessTokenDenylist {
+    pool: DbPool,
+    /// Lifetime of access tokens, bounds how long subject entries are needed
+    access_token_expiry: i64,
+}
+
+impl PostgresAccessTokenDenylist {
+    pub fn new(pool: DbPool, access_token_expiry: i64) -> Self {
+        Self {
+            pool,
+            access_token_expiry,
+        }
+    }
+}
+
+#[async_trait]
+impl AccessTokenDenylist for PostgresAccessTokenDenylist {
+    async fn deny_token(&self, jti: &str, expires_at: OffsetDateTime) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO access_token_denylist (jti, expires_at)
+            VALUES ($1, $2)
+            ON CONFLICT (jti) DO NOTHING
+            "#,
+        )
+        .bind(jti)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn deny_subject(&self, user_id: Uuid, user_type: &str) -> Result<()> {
+        // Same clock and precision as the `iat_ms` claim of the tokens it is compared with
+        let now = OffsetDateTime::now_utc();
+        let revoked_before = now.replace_nanosecond(now.millisecond() as u32 * 1_000_000)?;
+        let expires_at = now + time::Duration::seconds(self.access_token_expiry);
+
+        sqlx::query(
+            r#"
+            INSERT INTO access_token_subject_denylist (user_id, user_type, revoked_before, expires_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, user_type)
+            DO UPDATE SET revoked_before = EXCLUDED.revoked_before, expires_at = EXCLUDED.expires_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_type)
+        .bind(revoked_before)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn is_denied(&self, claims: &Claims) -> Result<bool> {
+        let user_id = claims.user_id()?;
+
+        // Tokens issued in the millisecond of the revocation stay valid, otherwise signing in
+        // right after a revocation could hand out an already denied token
+        let denied: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM access_token_denylist
+                WHERE jti = $1 AND expires_at > NOW()
+            ) OR EXISTS (
+                SELECT 1 FROM access_token_subject_denylist
+                WHERE user_id = $2 AND user_type = $3
+                  AND revoked_before > $4
+                  AND expires_at > NOW()
+            )
+            "#,
+        )
+        .bind(&claims.jti)
+        .bind(user_id)
+        .bind(&claims.user_type)
+        .bind(claims.issued_at()?)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(denied)
+    }
+
+    async fn delete_expired(&self) -> Result<u64> {
+        let tokens = sqlx::query(
+            r#"
+            DELETE FROM access_token_denylist
+            WHERE expires_at <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        let subjects = sqlx::query(
+            r#"
+            DELETE FROM access_token_subject_denylist
+            WHERE expires_at <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(tokens.rows_affected() + subjects.rows_affected())
+    }
+}
@@ -1,5 +1,6 @@
 use crate::domain::administrators::{
-    Administrator, AdministratorRepository, NewAdministrator, UpdateAdministrator,
+    Administrator, AdministratorRepository, AssignedRole, NewAdministrator, RoleAssignment,
+    UpdateAdministrator,
 };
 use crate::infrastructure::db::DbPool;
 use crate::infrastructure::db::models::administrators::AdministratorDbModel;
@@ -202,20 +203,43 @@ impl AdministratorRepository for PostgresAdministratorRepository {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn attach_roles(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
+    async fn attach_roles(
+        &self,
+        admin_id: Uuid,
+        role_ids: Vec<Uuid>,
+        assignment: RoleAssignment,
+    ) -> Result<(), anyhow::Error> {
         if role_ids.is_empty() {
             return Ok(());
         }
 
-        let mut query_builder =
-            sqlx::QueryBuilder::new("INSERT INTO administrator_roles (administrator_id, role_id) ");
+        let mut query_builder = sqlx::QueryBuilder::new(
+            "WITH granted AS (\
+             INSERT INTO administrator_roles (administrator_id, role_id, expires_at, assigned_by) ",
+        );
 
         query_builder.push_values(role_ids, |mut b, role_id| {
             b.push_bind(admin_id);
             b.push_bind(role_id);
+            b.push_bind(assignment.expires_at);
+            b.push_bind(assignment.assigned_by);
         });
 
-        query_builder.push(" ON CONFLICT DO NOTHING");
+        // Re-assigning a role extends, shortens or makes permanent a temporary grant,
+        // a permanent grant never becomes temporary
+        query_builder.push(
+            " ON CONFLICT (administrator_id, role_id) DO UPDATE SET \
+             expires_at = CASE WHEN administrator_roles.expires_at IS NULL THEN NULL \
+             ELSE EXCLUDED.expires_at END, \
+             assigned_by = EXCLUDED.assigned_by, \
+             assigned_at = NOW() \
+             RETURNING administrator_id, role_id, expires_at, assigned_by) ",
+        );
+        // The grants as they took effect, i.e. after keeping permanent grants permanent
+        query_builder.push(
+            "INSERT INTO administrator_role_history (administrator_id, role_id, event, expires_at, actor_id) \
+             SELECT administrator_id, role_id, 'granted', expires_at, assigned_by FROM granted",
+        );
 
         let query = query_builder.build();
         query.execute(&self.pool).await?;
@@ -224,34 +248,90 @@ impl AdministratorRepository for PostgresAdministratorRepository {
     }
 
     #[tracing::instrument(skip(self))]
-    async fn detach_roles(&self, admin_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
+    async fn detach_roles(
+        &self,
+        admin_id: Uuid,
+        role_ids: Vec<Uuid>,
+        revoked_by: Uuid,
+    ) -> Result<(), anyhow::Error> {
         if role_ids.is_empty() {
             return Ok(());
         }
 
-        let query =
-            "DELETE FROM administrator_roles WHERE administrator_id = $1 AND role_id = ANY($2)";
-        sqlx::query(query)
-            .bind(admin_id)
-            .bind(role_ids)
-            .execute(&self.pool)
-            .await?;
+        sqlx::query(
+            r#"
+            WITH revoked AS (
+                DELETE FROM administrator_roles
+                WHERE administrator_id = $1 AND role_id = ANY($2)
+                RETURNING administrator_id, role_id
+            )
+            INSERT INTO administrator_role_history (administrator_id, role_id, event, actor_id)
+            SELECT administrator_id, role_id, 'revoked', $3 FROM revoked
+            "#,
+        )
+        .bind(admin_id)
+        .bind(role_ids)
+        .bind(revoked_by)
+        .execute(&self.pool)
+        .await?;
 
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn find_role_assignments(
+        &self,
+        admin_id: Uuid,
+    ) -> Result<Vec<AssignedRole>, anyhow::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT ar.role_id, r.name, ar.assigned_at, ar.assigned_by, ar.expires_at
+            FROM administrator_roles ar
+            JOIN roles r ON r.id = ar.role_id
+            WHERE ar.administrator_id = $1
+              AND (ar.expires_at IS NULL OR ar.expires_at > NOW())
+            ORDER BY ar.assigned_at, r.name
+            "#,
+            admin_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AssignedRole {
+                role_id: row.role_id,
+                role_name: row.name,
+                assigned_at: row.assigned_at,
+                assigned_by: row.assigned_by,
+                expires_at: row.expires_at,
+            })
+            .collect())
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_permissions(
         &self,
         admin_id: Uuid,
     ) -> Result<Vec<crate::domain::permissions::Permission>, anyhow::Error> {
-        // Query to get distinct permissions from all roles assigned to the administrator
+        // Distinct permissions of the assigned administrator roles and every role they
+        // inherit from, UNION stops the recursion on a cycle
         let rows = sqlx::query!(
             r#"
+            WITH RECURSIVE granted(role_id) AS (
+                SELECT ar.role_id
+                FROM administrator_roles ar
+                JOIN roles r ON r.id = ar.role_id
+                WHERE ar.administrator_id = $1
+                  AND (ar.expires_at IS NULL OR ar.expires_at > NOW())
+                  AND r.scope = 'administrator'
+                  AND r.group_id IS NULL
+                UNION
+                SELECT rp.parent_id FROM role_parents rp JOIN granted g ON rp.role_id = g.role_id
+            )
             SELECT DISTINCT rp.permission as "permission!: String"
-            FROM administrator_roles ar
-            JOIN role_permissions rp ON ar.role_id = rp.role_id
-            WHERE ar.administrator_id = $1
+            FROM granted g
+            JOIN role_permissions rp ON g.role_id = rp.role_id
             "#,
             admin_id
         )
@@ -265,4 +345,34 @@ impl AdministratorRepository for PostgresAdministratorRepository {
 
         Ok(permissions)
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_expired_role_assignments(&self) -> Result<u64, anyhow::Error> {
+        // The history is the only record of a temporary grant once it is purged
+        let expired = sqlx::query!(
+            r#"
+            WITH expired AS (
+                DELETE FROM administrator_roles
+                WHERE expires_at <= NOW()
+                RETURNING administrator_id, role_id, expires_at
+            )
+            INSERT INTO administrator_role_history (administrator_id, role_id, event, expires_at)
+            SELECT administrator_id, role_id, 'expired', expires_at FROM expired
+            RETURNING administrator_id, role_id, expires_at
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        for assignment in &expired {
+            tracing::info!(
+                administrator_id = %assignment.administrator_id,
+                role_id = %assignment.role_id,
+                expires_at = ?assignment.expires_at,
+                "Temporary role assignment expired"
+            );
+        }
+
+        Ok(expired.len() as u64)
+    }
 }
@@ -0,0 +1,226 @@
+use crate::domain::groups::{Group, GroupRepository, NewGroup, UpdateGroup};
+use crate::domain::users::User;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::db::models::groups::GroupDbModel;
+use crate::infrastructure::db::models::users::UserDbModel;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresGroupRepository {
+    pool: DbPool,
+}
+
+impl PostgresGroupRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GroupRepository for PostgresGroupRepository {
+    #[tracing::instrument(skip(self, new_group))]
+    async fn create(&self, new_group: NewGroup) -> Result<Group, anyhow::Error> {
+        let group_db = sqlx::query_as::<_, GroupDbModel>(
+            r#"
+            INSERT INTO groups (name, description)
+            VALUES ($1, $2)
+            RETURNING id, name, description, created_at, updated_at
+            "#,
+        )
+        .bind(new_group.name)
+        .bind(new_group.description)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(group_db.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Group>, anyhow::Error> {
+        let group_db = sqlx::query_as::<_, GroupDbModel>(
+            "SELECT id, name, description, created_at, updated_at FROM groups WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(group_db.map(|g| g.into()))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_by_name(&self, name: &str) -> Result<Option<Group>, anyhow::Error> {
+        let group_db = sqlx::query_as::<_, GroupDbModel>(
+            "SELECT id, name, description, created_at, updated_at FROM groups WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(group_db.map(|g| g.into()))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<Group>, anyhow::Error> {
+        let groups_db = sqlx::query_as::<_, GroupDbModel>(
+            r#"
+            SELECT id, name, description, created_at, updated_at
+            FROM groups
+            ORDER BY created_at DESC
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(groups_db.into_iter().map(|g| g.into()).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn count(&self) -> Result<i64, anyhow::Error> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM groups")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip(self, update))]
+    async fn update(&self, id: Uuid, update: UpdateGroup) -> Result<Group, anyhow::Error> {
+        let group_db = sqlx::query_as::<_, GroupDbModel>(
+            r#"
+            UPDATE groups
+            SET name = COALESCE($1, name),
+                description = COALESCE($2, description),
+                updated_at = NOW()
+            WHERE id = $3
+            RETURNING id, name, description, created_at, updated_at
+            "#,
+        )
+        .bind(update.name)
+        .bind(update.description)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(group_db.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query("DELETE FROM groups WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_by_member(&self, user_id: Uuid) -> Result<Vec<Group>, anyhow::Error> {
+        let groups_db = sqlx::query_as::<_, GroupDbModel>(
+            r#"
+            SELECT g.id, g.name, g.description, g.created_at, g.updated_at
+            FROM groups g
+            JOIN group_members gm ON gm.group_id = g.id
+            WHERE gm.user_id = $1
+            ORDER BY g.name
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(groups_db.into_iter().map(|g| g.into()).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_members(&self, group_id: Uuid) -> Result<Vec<User>, anyhow::Error> {
+        let users_db = sqlx::query_as::<_, UserDbModel>(
+            r#"
+            SELECT u.id, u.username, u.email, u.password_hash, u.email_verified_at, u.created_at, u.updated_at
+            FROM users u
+            JOIN group_members gm ON gm.user_id = u.id
+            WHERE gm.group_id = $1
+            ORDER BY gm.joined_at
+            "#,
+        )
+        .bind(group_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(users_db.into_iter().map(|u| u.into()).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn is_member(&self, group_id: Uuid, user_id: Uuid) -> Result<bool, anyhow::Error> {
+        let is_member = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM group_members WHERE group_id = $1 AND user_id = $2)",
+        )
+        .bind(group_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(is_member)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add_members(&self, group_id: Uuid, user_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_builder =
+            sqlx::QueryBuilder::new("INSERT INTO group_members (group_id, user_id) ");
+
+        query_builder.push_values(user_ids, |mut b, user_id| {
+            b.push_bind(group_id);
+            b.push_bind(user_id);
+        });
+
+        query_builder.push(" ON CONFLICT DO NOTHING");
+
+        query_builder.build().execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn remove_members(
+        &self,
+        group_id: Uuid,
+        user_ids: Vec<Uuid>,
+    ) -> Result<(), anyhow::Error> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        // Group roles must not apply again if the user rejoins
+        sqlx::query(
+            r#"
+            DELETE FROM user_roles ur
+            USING roles r
+            WHERE ur.role_id = r.id AND r.group_id = $1 AND ur.user_id = ANY($2)
+            "#,
+        )
+        .bind(group_id)
+        .bind(&user_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM group_members WHERE group_id = $1 AND user_id = ANY($2)")
+            .bind(group_id)
+            .bind(&user_ids)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::domain::login_attempts::{AttemptScope, LoginAttemptRepository, LoginAttempts};
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::db::models::login_attempts::LoginAttemptsDbModel;
+use anyhow::Result;
+use async_trait::async_trait;
+use time::{Duration, OffsetDateTime};
+
+pub struct PostgresLoginAttemptRepository {
+    pool: DbPool,
+}
+
+impl PostgresLoginAttemptRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepository for PostgresLoginAttemptRepository {
+    async fn find(&self, scope: AttemptScope, key: &str) -> Result<Option<LoginAttempts>> {
+        let attempts = sqlx::query_as::<_, LoginAttemptsDbModel>(
+            r#"
+            SELECT failed_count, last_failed_at, locked_until
+            FROM login_attempts
+            WHERE scope = $1 AND key = $2 AND expires_at > NOW()
+            "#,
+        )
+        .bind(scope.as_str())
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attempts.map(|a| a.into()))
+    }
+
+    async fn record_failure(
+        &self,
+        scope: AttemptScope,
+        key: &str,
+        window: Duration,
+    ) -> Result<LoginAttempts> {
+        let expires_at = OffsetDateTime::now_utc() + window;
+
+        let attempts = sqlx::query_as::<_, LoginAttemptsDbModel>(
+            r#"
+            INSERT INTO login_attempts (scope, key, failed_count, last_failed_at, expires_at)
+            VALUES ($1, $2, 1, NOW(), $3)
+            ON CONFLICT (scope, key) DO UPDATE
+            SET failed_count = CASE WHEN login_attempts.expires_at <= NOW() THEN 1
+                                    ELSE login_attempts.failed_count + 1 END,
+                locked_until = CASE WHEN login_attempts.expires_at <= NOW() THEN NULL
+                                    ELSE login_attempts.locked_until END,
+                last_failed_at = NOW(),
+                expires_at = GREATEST(EXCLUDED.expires_at, login_attempts.locked_until)
+            RETURNING failed_count, last_failed_at, locked_until
+            "#,
+        )
+        .bind(scope.as_str())
+        .bind(key)
+        .bind(expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(attempts.into())
+    }
+
+    async fn lock(&self, scope: AttemptScope, key: &str, until: OffsetDateTime) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE login_attempts
+            SET locked_until = $3, expires_at = GREATEST(expires_at, $3)
+            WHERE scope = $1 AND key = $2
+            "#,
+        )
+        .bind(scope.as_str())
+        .bind(key)
+        .bind(until)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reset(&self, scope: AttemptScope, key: &str) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM login_attempts
+            WHERE scope = $1 AND key = $2
+            "#,
+        )
+        .bind(scope.as_str())
+        .bind(key)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_expired(&self) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM login_attempts
+            WHERE expires_at <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,194 @@
+use crate::domain::mfa::{MfaRepository, TotpEnrollment};
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::db::models::mfa::TotpEnrollmentDbModel;
+use crate::infrastructure::secret_cipher::SecretCipher;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PostgresMfaRepository {
+    pool: DbPool,
+    /// TOTP secrets never reach the database in plaintext
+    cipher: Arc<SecretCipher>,
+}
+
+impl PostgresMfaRepository {
+    pub fn new(pool: DbPool, cipher: Arc<SecretCipher>) -> Self {
+        Self { pool, cipher }
+    }
+
+    /// Decrypt the stored secret, encrypting secrets saved before encryption was introduced
+    async fn decrypt(&self, model: TotpEnrollmentDbModel) -> Result<TotpEnrollment> {
+        let mut enrollment: TotpEnrollment = model.into();
+
+        if SecretCipher::is_encrypted(&enrollment.secret) {
+            enrollment.secret = self.cipher.decrypt(&enrollment.secret)?;
+        } else {
+            sqlx::query(
+                "UPDATE administrator_totp SET secret = $2 WHERE administrator_id = $1 AND secret = $3",
+            )
+            .bind(enrollment.administrator_id)
+            .bind(self.cipher.encrypt(&enrollment.secret)?)
+            .bind(&enrollment.secret)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(enrollment)
+    }
+}
+
+#[async_trait]
+impl MfaRepository for PostgresMfaRepository {
+    async fn find_totp(&self, administrator_id: Uuid) -> Result<Option<TotpEnrollment>> {
+        let enrollment = sqlx::query_as::<_, TotpEnrollmentDbModel>(
+            r#"
+            SELECT administrator_id, secret, confirmed_at, last_used_step, created_at, updated_at
+            FROM administrator_totp
+            WHERE administrator_id = $1
+            "#,
+        )
+        .bind(administrator_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match enrollment {
+            Some(enrollment) => Ok(Some(self.decrypt(enrollment).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn save_pending_totp(
+        &self,
+        administrator_id: Uuid,
+        secret: &str,
+    ) -> Result<TotpEnrollment> {
+        // A confirmed enrollment is never overwritten, the upsert then returns no row
+        let enrollment = sqlx::query_as::<_, TotpEnrollmentDbModel>(
+            r#"
+            INSERT INTO administrator_totp (administrator_id, secret)
+            VALUES ($1, $2)
+            ON CONFLICT (administrator_id) DO UPDATE
+            SET secret = EXCLUDED.secret, last_used_step = NULL, created_at = NOW(), updated_at = NOW()
+            WHERE administrator_totp.confirmed_at IS NULL
+            RETURNING administrator_id, secret, confirmed_at, last_used_step, created_at, updated_at
+            "#,
+        )
+        .bind(administrator_id)
+        .bind(self.cipher.encrypt(secret)?)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("TOTP is already enabled"))?;
+
+        self.decrypt(enrollment).await
+    }
+
+    async fn confirm_totp(&self, administrator_id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE administrator_totp
+            SET confirmed_at = NOW(), updated_at = NOW()
+            WHERE administrator_id = $1 AND confirmed_at IS NULL
+            "#,
+        )
+        .bind(administrator_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_totp_step(&self, administrator_id: Uuid, step: i64) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE administrator_totp
+            SET last_used_step = $2, updated_at = NOW()
+            WHERE administrator_id = $1 AND (last_used_step IS NULL OR last_used_step < $2)
+            "#,
+        )
+        .bind(administrator_id)
+        .bind(step)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_totp(&self, administrator_id: Uuid) -> Result<bool> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM administrator_recovery_codes WHERE administrator_id = $1")
+            .bind(administrator_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM administrator_totp WHERE administrator_id = $1")
+            .bind(administrator_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn replace_recovery_codes(
+        &self,
+        administrator_id: Uuid,
+        code_hashes: Vec<String>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM administrator_recovery_codes WHERE administrator_id = $1")
+            .bind(administrator_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO administrator_recovery_codes (administrator_id, code_hash)
+            SELECT $1, UNNEST($2::VARCHAR[])
+            "#,
+        )
+        .bind(administrator_id)
+        .bind(&code_hashes)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn use_recovery_code(&self, administrator_id: Uuid, code_hash: &str) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE administrator_recovery_codes
+            SET used_at = NOW()
+            WHERE administrator_id = $1 AND code_hash = $2 AND used_at IS NULL
+            "#,
+        )
+        .bind(administrator_id)
+        .bind(code_hash)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn count_unused_recovery_codes(&self, administrator_id: Uuid) -> Result<i64> {
+        let count: (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM administrator_recovery_codes
+            WHERE administrator_id = $1 AND used_at IS NULL
+            "#,
+        )
+        .bind(administrator_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.0)
+    }
+}
@@ -1,5 +1,10 @@
+pub mod access_token_denylist;
 pub mod administrators;
-
+pub mod groups;
+pub mod login_attempts;
+pub mod mfa;
+pub mod one_time_tokens;
+pub mod passkeys;
 pub mod refresh_tokens;
 pub mod roles;
 pub mod users;
@@ -0,0 +1,128 @@
+use crate::domain::one_time_tokens::{
+    NewOneTimeToken, OneTimeToken, OneTimeTokenRepository, TokenPurpose,
+};
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::db::models::one_time_tokens::OneTimeTokenDbModel;
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+pub struct PostgresOneTimeTokenRepository {
+    pool: DbPool,
+}
+
+impl PostgresOneTimeTokenRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OneTimeTokenRepository for PostgresOneTimeTokenRepository {
+    async fn create(&self, token: NewOneTimeToken) -> Result<OneTimeToken> {
+        let token_db = sqlx::query_as::<_, OneTimeTokenDbModel>(
+            r#"
+            INSERT INTO one_time_tokens (user_id, user_type, purpose, token_hash, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, user_id, user_type, purpose, token_hash, expires_at, used_at, created_at
+            "#,
+        )
+        .bind(token.user_id)
+        .bind(&token.user_type)
+        .bind(token.purpose.as_str())
+        .bind(&token.token_hash)
+        .bind(token.expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(token_db.into())
+    }
+
+    async fn find_valid(
+        &self,
+        token_hash: &str,
+        purpose: TokenPurpose,
+    ) -> Result<Option<OneTimeToken>> {
+        let token_db = sqlx::query_as::<_, OneTimeTokenDbModel>(
+            r#"
+            SELECT id, user_id, user_type, purpose, token_hash, expires_at, used_at, created_at
+            FROM one_time_tokens
+            WHERE token_hash = $1 AND purpose = $2 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+        )
+        .bind(token_hash)
+        .bind(purpose.as_str())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(token_db.map(|t| t.into()))
+    }
+
+    async fn consume(&self, id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE one_time_tokens
+            SET used_at = NOW()
+            WHERE id = $1 AND used_at IS NULL AND expires_at > NOW()
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_failed_attempt(&self, id: Uuid, max_attempts: i32) -> Result<bool> {
+        let burned: Option<bool> = sqlx::query_scalar(
+            r#"
+            UPDATE one_time_tokens
+            SET failed_attempts = failed_attempts + 1,
+                used_at = CASE WHEN failed_attempts + 1 >= $2 THEN NOW() ELSE used_at END
+            WHERE id = $1 AND used_at IS NULL
+            RETURNING used_at IS NOT NULL
+            "#,
+        )
+        .bind(id)
+        .bind(max_attempts)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        // A token that was used in the meantime is gone as well
+        Ok(burned.unwrap_or(true))
+    }
+
+    async fn delete_for_user(
+        &self,
+        user_id: Uuid,
+        user_type: &str,
+        purpose: TokenPurpose,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM one_time_tokens
+            WHERE user_id = $1 AND user_type = $2 AND purpose = $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_type)
+        .bind(purpose.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_expired(&self) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM one_time_tokens
+            WHERE expires_at <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
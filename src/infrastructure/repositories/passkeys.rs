@@ -0,0 +1,101 @@
+use crate::domain::passkeys::{NewPasskey, Passkey, PasskeyRepository};
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::db::models::passkeys::PasskeyDbModel;
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+pub struct PostgresPasskeyRepository {
+    pool: DbPool,
+}
+
+impl PostgresPasskeyRepository {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasskeyRepository for PostgresPasskeyRepository {
+    async fn create(&self, passkey: NewPasskey) -> Result<Passkey> {
+        let passkey_db = sqlx::query_as::<_, PasskeyDbModel>(
+            r#"
+            INSERT INTO administrator_passkeys (administrator_id, credential_id, public_key, sign_count, name)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, administrator_id, credential_id, public_key, sign_count, name, last_used_at, created_at
+            "#,
+        )
+        .bind(passkey.administrator_id)
+        .bind(&passkey.credential_id)
+        .bind(&passkey.public_key)
+        .bind(passkey.sign_count)
+        .bind(&passkey.name)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(passkey_db.into())
+    }
+
+    async fn find_by_credential_id(&self, credential_id: &str) -> Result<Option<Passkey>> {
+        let passkey_db = sqlx::query_as::<_, PasskeyDbModel>(
+            r#"
+            SELECT id, administrator_id, credential_id, public_key, sign_count, name, last_used_at, created_at
+            FROM administrator_passkeys
+            WHERE credential_id = $1
+            "#,
+        )
+        .bind(credential_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(passkey_db.map(|p| p.into()))
+    }
+
+    async fn list_for_administrator(&self, administrator_id: Uuid) -> Result<Vec<Passkey>> {
+        let passkeys = sqlx::query_as::<_, PasskeyDbModel>(
+            r#"
+            SELECT id, administrator_id, credential_id, public_key, sign_count, name, last_used_at, created_at
+            FROM administrator_passkeys
+            WHERE administrator_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(administrator_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(passkeys.into_iter().map(|p| p.into()).collect())
+    }
+
+    async fn record_use(&self, id: Uuid, sign_count: i64) -> Result<bool> {
+        // Authenticators without a counter always report 0
+        let result = sqlx::query(
+            r#"
+            UPDATE administrator_passkeys
+            SET sign_count = $2, last_used_at = NOW()
+            WHERE id = $1 AND (sign_count < $2 OR (sign_count = 0 AND $2 = 0))
+            "#,
+        )
+        .bind(id)
+        .bind(sign_count)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete(&self, administrator_id: Uuid, id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM administrator_passkeys
+            WHERE id = $1 AND administrator_id = $2
+            "#,
+        )
+        .bind(id)
+        .bind(administrator_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -1,6 +1,6 @@
-use crate::domain::auth::{NewRefreshToken, RefreshToken, RefreshTokenRepository};
+use crate::domain::auth::{NewRefreshToken, RefreshToken, RefreshTokenRepository, Session};
 use crate::infrastructure::db::DbPool;
-use crate::infrastructure::db::models::auth::RefreshTokenDbModel;
+use crate::infrastructure::db::models::auth::{RefreshTokenDbModel, SessionDbModel};
 use anyhow::Result;
 use async_trait::async_trait;
 use sqlx;
@@ -21,14 +21,19 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
     async fn create(&self, token: NewRefreshToken) -> Result<RefreshToken> {
         let token_db = sqlx::query_as::<_, RefreshTokenDbModel>(
             r#"
-            INSERT INTO refresh_tokens (user_id, user_type, token_hash, expires_at)
-            VALUES ($1, $2, $3, $4)
-            RETURNING id, user_id, user_type, token_hash, expires_at, created_at
+            INSERT INTO refresh_tokens (user_id, user_type, token_hash, family_id, parent_id, user_agent, ip_address, device_name, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, user_id, user_type, token_hash, family_id, parent_id, rotated_at, user_agent, ip_address, device_name, last_used_at, expires_at, created_at
             "#,
         )
         .bind(token.user_id)
         .bind(&token.user_type)
         .bind(&token.token_hash)
+        .bind(token.family_id)
+        .bind(token.parent_id)
+        .bind(&token.user_agent)
+        .bind(&token.ip_address)
+        .bind(&token.device_name)
         .bind(token.expires_at)
         .fetch_one(&self.pool)
         .await?;
@@ -39,7 +44,7 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
     async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
         let token_db = sqlx::query_as::<_, RefreshTokenDbModel>(
             r#"
-            SELECT id, user_id, user_type, token_hash, expires_at, created_at
+            SELECT id, user_id, user_type, token_hash, family_id, parent_id, rotated_at, user_agent, ip_address, device_name, last_used_at, expires_at, created_at
             FROM refresh_tokens
             WHERE token_hash = $1 AND expires_at > NOW()
             "#,
@@ -51,14 +56,15 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         Ok(token_db.map(|t| t.into()))
     }
 
-    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64> {
+    async fn delete_by_user_id(&self, user_id: Uuid, user_type: &str) -> Result<u64> {
         let result = sqlx::query(
             r#"
             DELETE FROM refresh_tokens
-            WHERE user_id = $1
+            WHERE user_id = $1 AND user_type = $2
             "#,
         )
         .bind(user_id)
+        .bind(user_type)
         .execute(&self.pool)
         .await?;
 
@@ -91,4 +97,53 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
 
         Ok(result.rows_affected() > 0)
     }
+
+    async fn mark_rotated(&self, id: Uuid) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE refresh_tokens
+            SET rotated_at = NOW()
+            WHERE id = $1 AND rotated_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_by_family_id(&self, family_id: Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM refresh_tokens
+            WHERE family_id = $1
+            "#,
+        )
+        .bind(family_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_sessions(&self, user_id: Uuid, user_type: &str) -> Result<Vec<Session>> {
+        let sessions = sqlx::query_as::<_, SessionDbModel>(
+            r#"
+            SELECT t.family_id AS id, t.user_id, t.user_type, t.user_agent, t.ip_address, t.device_name,
+                   (SELECT MIN(f.created_at) FROM refresh_tokens f WHERE f.family_id = t.family_id) AS created_at,
+                   t.last_used_at, t.expires_at
+            FROM refresh_tokens t
+            WHERE t.user_id = $1 AND t.user_type = $2
+              AND t.rotated_at IS NULL AND t.expires_at > NOW()
+            ORDER BY t.last_used_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_type)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sessions.into_iter().map(|s| s.into()).collect())
+    }
 }
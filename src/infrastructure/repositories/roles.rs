@@ -6,6 +6,15 @@ use crate::infrastructure::db::models::roles::RoleDbModel;
 use async_trait::async_trait;
 use uuid::Uuid;
 
+const ANCESTOR_IDS_QUERY: &str = r#"
+    WITH RECURSIVE ancestors(role_id) AS (
+        SELECT parent_id FROM role_parents WHERE role_id = $1
+        UNION
+        SELECT rp.parent_id FROM role_parents rp JOIN ancestors a ON rp.role_id = a.role_id
+    )
+    SELECT role_id FROM ancestors
+"#;
+
 #[derive(Clone)]
 pub struct PostgresRoleRepository {
     pool: DbPool,
@@ -25,7 +34,7 @@ impl RoleRepository for PostgresRoleRepository {
             r#"
             INSERT INTO roles (name, description, scope, group_id)
             VALUES ($1, $2, $3, $4)
-            RETURNING id, name, description, scope, group_id, created_at, updated_at
+            RETURNING id, name, description, scope, group_id, ARRAY[]::uuid[] AS parent_ids, created_at, updated_at
             "#,
         )
         .bind(new_role.name)
@@ -42,7 +51,7 @@ impl RoleRepository for PostgresRoleRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Role>, anyhow::Error> {
         let role_db = sqlx::query_as::<_, RoleDbModel>(
             r#"
-            SELECT id, name, description, scope, group_id, created_at, updated_at
+            SELECT id, name, description, scope, group_id, ARRAY(SELECT parent_id FROM role_parents WHERE role_id = roles.id) AS parent_ids, created_at, updated_at
             FROM roles
             WHERE id = $1
             "#,
@@ -62,7 +71,7 @@ impl RoleRepository for PostgresRoleRepository {
         group_id: Option<Uuid>,
     ) -> Result<Option<Role>, anyhow::Error> {
         let mut query = String::from(
-            "SELECT id, name, description, scope, group_id, created_at, updated_at FROM roles WHERE name = $1 AND scope = $2",
+            "SELECT id, name, description, scope, group_id, ARRAY(SELECT parent_id FROM role_parents WHERE role_id = roles.id) AS parent_ids, created_at, updated_at FROM roles WHERE name = $1 AND scope = $2",
         );
         match group_id {
             Some(_) => query.push_str(" AND group_id = $3"),
@@ -91,7 +100,7 @@ impl RoleRepository for PostgresRoleRepository {
         offset: i64,
     ) -> Result<Vec<Role>, anyhow::Error> {
         let mut query = String::from(
-            "SELECT id, name, description, scope, group_id, created_at, updated_at FROM roles WHERE scope = $1",
+            "SELECT id, name, description, scope, group_id, ARRAY(SELECT parent_id FROM role_parents WHERE role_id = roles.id) AS parent_ids, created_at, updated_at FROM roles WHERE scope = $1",
         );
         let mut param_index = 2; // Start after scope
 
@@ -162,7 +171,7 @@ impl RoleRepository for PostgresRoleRepository {
         updates.push("updated_at = NOW()".to_string());
         query.push_str(&updates.join(", "));
         query.push_str(&format!(
-            " WHERE id = ${} RETURNING id, name, description, scope, group_id, created_at, updated_at",
+            " WHERE id = ${} RETURNING id, name, description, scope, group_id, ARRAY(SELECT parent_id FROM role_parents WHERE role_id = roles.id) AS parent_ids, created_at, updated_at",
             param_count
         ));
 
@@ -193,6 +202,34 @@ impl RoleRepository for PostgresRoleRepository {
 
     #[tracing::instrument(skip(self))]
     async fn get_permissions(&self, role_id: Uuid) -> Result<Vec<Permission>, anyhow::Error> {
+        // UNION rather than UNION ALL so the recursion stops on a cycle
+        let permissions = sqlx::query_scalar::<_, String>(
+            r#"
+            WITH RECURSIVE lineage(role_id) AS (
+                SELECT $1::uuid
+                UNION
+                SELECT rp.parent_id FROM role_parents rp JOIN lineage l ON rp.role_id = l.role_id
+            )
+            SELECT DISTINCT permission
+            FROM role_permissions
+            WHERE role_id IN (SELECT role_id FROM lineage)
+            "#,
+        )
+        .bind(role_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        permissions
+            .into_iter()
+            .map(|p| p.parse().map_err(|e: String| anyhow::anyhow!(e)))
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_direct_permissions(
+        &self,
+        role_id: Uuid,
+    ) -> Result<Vec<Permission>, anyhow::Error> {
         let permissions = sqlx::query_scalar::<_, String>(
             "SELECT permission FROM role_permissions WHERE role_id = $1",
         )
@@ -206,6 +243,80 @@ impl RoleRepository for PostgresRoleRepository {
             .collect()
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn find_ancestor_ids(&self, role_id: Uuid) -> Result<Vec<Uuid>, anyhow::Error> {
+        let ancestor_ids = sqlx::query_scalar::<_, Uuid>(ANCESTOR_IDS_QUERY)
+            .bind(role_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(ancestor_ids)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn attach_parents(
+        &self,
+        role_id: Uuid,
+        parent_ids: Vec<Uuid>,
+    ) -> Result<Option<Uuid>, anyhow::Error> {
+        if parent_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        // Serialise hierarchy changes, two concurrent attachments could otherwise
+        // each pass the cycle check and close a loop between them
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext('role_parents'))")
+            .execute(&mut *tx)
+            .await?;
+
+        for parent_id in &parent_ids {
+            let ancestor_ids = sqlx::query_scalar::<_, Uuid>(ANCESTOR_IDS_QUERY)
+                .bind(parent_id)
+                .fetch_all(&mut *tx)
+                .await?;
+
+            if *parent_id == role_id || ancestor_ids.contains(&role_id) {
+                return Ok(Some(*parent_id));
+            }
+        }
+
+        let mut query_builder =
+            sqlx::QueryBuilder::new("INSERT INTO role_parents (role_id, parent_id) ");
+
+        query_builder.push_values(parent_ids, |mut b, parent_id| {
+            b.push_bind(role_id).push_bind(parent_id);
+        });
+
+        query_builder.push(" ON CONFLICT (role_id, parent_id) DO NOTHING");
+
+        query_builder.build().execute(&mut *tx).await?;
+
+        tx.commit().await?;
+
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn detach_parents(
+        &self,
+        role_id: Uuid,
+        parent_ids: Vec<Uuid>,
+    ) -> Result<(), anyhow::Error> {
+        if parent_ids.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query("DELETE FROM role_parents WHERE role_id = $1 AND parent_id = ANY($2)")
+            .bind(role_id)
+            .bind(&parent_ids)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     async fn attach_permissions(
         &self,
         role_id: Uuid,
@@ -372,8 +483,8 @@ mod tests {
         repo.attach_permissions(
             role.id,
             vec![
-                Permission::AdministratorManagement,
-                Permission::RoleManagement,
+                Permission::from_static("administrators.*"),
+                Permission::from_static("roles.read"),
             ],
         )
         .await
@@ -382,13 +493,102 @@ mod tests {
         // Get permissions
         let permissions = repo.get_permissions(role.id).await.unwrap();
         assert_eq!(permissions.len(), 2);
-        assert!(permissions.contains(&Permission::AdministratorManagement));
-        assert!(permissions.contains(&Permission::RoleManagement));
+        assert!(permissions.contains(&Permission::from_static("administrators.*")));
+        assert!(permissions.contains(&Permission::from_static("roles.read")));
 
         // Cleanup
         repo.delete(role.id).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_get_permissions_includes_ancestors() {
+        let pool = setup_test_db().await;
+        let repo = PostgresRoleRepository::new(pool.clone());
+
+        let mut roles = Vec::new();
+        for _ in 0..3 {
+            let new_role = NewRole {
+                name: format!("test_role_{}", uuid::Uuid::new_v4()),
+                description: None,
+                scope: AccessScope::Administrator,
+                group_id: None,
+            };
+            roles.push(repo.create(new_role).await.unwrap());
+        }
+        let (grandparent, parent, child) = (&roles[0], &roles[1], &roles[2]);
+
+        repo.attach_permissions(grandparent.id, vec![Permission::from_static("users.read")])
+            .await
+            .unwrap();
+        repo.attach_permissions(child.id, vec![Permission::from_static("roles.read")])
+            .await
+            .unwrap();
+        repo.attach_parents(parent.id, vec![grandparent.id])
+            .await
+            .unwrap();
+        repo.attach_parents(child.id, vec![parent.id])
+            .await
+            .unwrap();
+
+        let permissions = repo.get_permissions(child.id).await.unwrap();
+        assert_eq!(permissions.len(), 2);
+        assert!(permissions.contains(&Permission::from_static("users.read")));
+        assert_eq!(
+            repo.get_direct_permissions(child.id).await.unwrap(),
+            vec![Permission::from_static("roles.read")]
+        );
+
+        let ancestors = repo.find_ancestor_ids(child.id).await.unwrap();
+        assert_eq!(ancestors.len(), 2);
+        assert!(ancestors.contains(&grandparent.id));
+        assert_eq!(
+            repo.find_by_id(child.id).await.unwrap().unwrap().parent_ids,
+            vec![parent.id]
+        );
+
+        for role in &roles {
+            repo.delete(role.id).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_parent_attachments_cannot_form_cycle() {
+        let pool = setup_test_db().await;
+        let repo = PostgresRoleRepository::new(pool.clone());
+
+        let mut roles = Vec::new();
+        for _ in 0..2 {
+            let new_role = NewRole {
+                name: format!("test_role_{}", uuid::Uuid::new_v4()),
+                description: None,
+                scope: AccessScope::Administrator,
+                group_id: None,
+            };
+            roles.push(repo.create(new_role).await.unwrap());
+        }
+        let (first, second) = (&roles[0], &roles[1]);
+
+        let (a, b) = tokio::join!(
+            repo.attach_parents(first.id, vec![second.id]),
+            repo.attach_parents(second.id, vec![first.id])
+        );
+        let rejected = [a.unwrap(), b.unwrap()];
+
+        // Exactly one of them sees the other's edge and is refused
+        assert_eq!(rejected.iter().filter(|r| r.is_some()).count(), 1);
+        assert!(
+            !repo
+                .find_ancestor_ids(first.id)
+                .await
+                .unwrap()
+                .contains(&first.id)
+        );
+
+        for role in &roles {
+            repo.delete(role.id).await.unwrap();
+        }
+    }
+
     #[tokio::test]
     async fn test_detach_permission() {
         let pool = setup_test_db().await;
@@ -404,10 +604,10 @@ mod tests {
         let role = repo.create(new_role).await.unwrap();
 
         // Attach and detach
-        repo.attach_permissions(role.id, vec![Permission::AdministratorManagement])
+        repo.attach_permissions(role.id, vec![Permission::from_static("users.read")])
             .await
             .unwrap();
-        repo.detach_permissions(role.id, vec![Permission::AdministratorManagement])
+        repo.detach_permissions(role.id, vec![Permission::from_static("users.read")])
             .await
             .unwrap();
 
@@ -27,7 +27,7 @@ impl PostgresUserRepository {
                 r#"
                 INSERT INTO users (username, email, password_hash)
                 VALUES ($1, $2, $3)
-                RETURNING id, username, email, password_hash, created_at, updated_at
+                RETURNING id, username, email, password_hash, email_verified_at, created_at, updated_at
                 "#,
             )
             .bind(new_user.username)
@@ -52,7 +52,7 @@ impl PostgresUserRepository {
     ) -> impl Stream<Item = Result<User, sqlx::Error>> + '_ {
         sqlx::query_as::<_, UserDbModel>(
             r#"
-            SELECT id, username, email, password_hash, created_at, updated_at
+            SELECT id, username, email, password_hash, email_verified_at, created_at, updated_at
             FROM users
             ORDER BY created_at DESC
             LIMIT $1 OFFSET $2
@@ -74,7 +74,7 @@ impl UserRepository for PostgresUserRepository {
             r#"
             INSERT INTO users (username, email, password_hash)
             VALUES ($1, $2, $3)
-            RETURNING id, username, email, password_hash, created_at, updated_at
+            RETURNING id, username, email, password_hash, email_verified_at, created_at, updated_at
             "#,
         )
         .bind(new_user.username)
@@ -90,7 +90,7 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, anyhow::Error> {
         let user_db = sqlx::query_as::<_, UserDbModel>(
             r#"
-            SELECT id, username, email, password_hash, created_at, updated_at
+            SELECT id, username, email, password_hash, email_verified_at, created_at, updated_at
             FROM users
             WHERE id = $1
             "#,
@@ -106,7 +106,7 @@ impl UserRepository for PostgresUserRepository {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, anyhow::Error> {
         let user_db = sqlx::query_as::<_, UserDbModel>(
             r#"
-            SELECT id, username, email, password_hash, created_at, updated_at
+            SELECT id, username, email, password_hash, email_verified_at, created_at, updated_at
             FROM users
             WHERE email = $1
             "#,
@@ -122,7 +122,7 @@ impl UserRepository for PostgresUserRepository {
     async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<User>, anyhow::Error> {
         let users_db = sqlx::query_as::<_, UserDbModel>(
             r#"
-            SELECT id, username, email, password_hash, created_at, updated_at
+            SELECT id, username, email, password_hash, email_verified_at, created_at, updated_at
             FROM users
             ORDER BY created_at DESC
             LIMIT $1 OFFSET $2
@@ -163,6 +163,11 @@ impl UserRepository for PostgresUserRepository {
         }
         if update.email.is_some() {
             updates.push(format!("email = ${}", param_count));
+            // A new address has to be verified again; SET sees the old `email` value
+            updates.push(format!(
+                "email_verified_at = CASE WHEN email = ${} THEN email_verified_at END",
+                param_count
+            ));
             param_count += 1;
         }
         if update.password_hash.is_some() {
@@ -177,7 +182,7 @@ impl UserRepository for PostgresUserRepository {
         updates.push("updated_at = NOW()".to_string());
         query.push_str(&updates.join(", "));
         query.push_str(&format!(
-            " WHERE id = ${} RETURNING id, username, email, password_hash, created_at, updated_at",
+            " WHERE id = ${} RETURNING id, username, email, password_hash, email_verified_at, created_at, updated_at",
             param_count
         ));
 
@@ -199,6 +204,18 @@ impl UserRepository for PostgresUserRepository {
         Ok(user_db.into())
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn mark_email_verified(&self, id: Uuid) -> Result<bool, anyhow::Error> {
+        let result = sqlx::query(
+            "UPDATE users SET email_verified_at = NOW(), updated_at = NOW() WHERE id = $1",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn delete(&self, id: Uuid) -> Result<bool, anyhow::Error> {
         let result = sqlx::query("DELETE FROM users WHERE id = $1")
@@ -208,4 +225,116 @@ impl UserRepository for PostgresUserRepository {
 
         Ok(result.rows_affected() > 0)
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn attach_roles(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
+        if role_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_builder =
+            sqlx::QueryBuilder::new("INSERT INTO user_roles (user_id, role_id) ");
+
+        query_builder.push_values(role_ids, |mut b, role_id| {
+            b.push_bind(user_id);
+            b.push_bind(role_id);
+        });
+
+        query_builder.push(" ON CONFLICT DO NOTHING");
+
+        query_builder.build().execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn detach_roles(&self, user_id: Uuid, role_ids: Vec<Uuid>) -> Result<(), anyhow::Error> {
+        if role_ids.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query("DELETE FROM user_roles WHERE user_id = $1 AND role_id = ANY($2)")
+            .bind(user_id)
+            .bind(role_ids)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_permissions(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<crate::domain::permissions::Permission>, anyhow::Error> {
+        let permissions = sqlx::query_scalar::<_, String>(
+            r#"
+            WITH RECURSIVE granted(role_id) AS (
+                SELECT ur.role_id
+                FROM user_roles ur
+                JOIN roles r ON r.id = ur.role_id
+                WHERE ur.user_id = $1 AND r.group_id IS NULL
+                UNION
+                SELECT rp.parent_id FROM role_parents rp JOIN granted g ON rp.role_id = g.role_id
+            )
+            SELECT DISTINCT rp.permission
+            FROM granted g
+            JOIN role_permissions rp ON g.role_id = rp.role_id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(permissions
+            .into_iter()
+            .filter_map(|permission| permission.parse().ok())
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_group_permissions(
+        &self,
+        user_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Option<Vec<crate::domain::permissions::Permission>>, anyhow::Error> {
+        let is_member = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM group_members WHERE group_id = $1 AND user_id = $2)",
+        )
+        .bind(group_id)
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if !is_member {
+            return Ok(None);
+        }
+
+        let permissions = sqlx::query_scalar::<_, String>(
+            r#"
+            WITH RECURSIVE granted(role_id) AS (
+                SELECT ur.role_id
+                FROM user_roles ur
+                JOIN roles r ON r.id = ur.role_id
+                WHERE ur.user_id = $1 AND r.group_id = $2
+                UNION
+                SELECT rp.parent_id FROM role_parents rp JOIN granted g ON rp.role_id = g.role_id
+            )
+            SELECT DISTINCT rp.permission
+            FROM granted g
+            JOIN role_permissions rp ON g.role_id = rp.role_id
+            "#,
+        )
+        .bind(user_id)
+        .bind(group_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(
+            permissions
+                .into_iter()
+                .filter_map(|permission| permission.parse().ok())
+                .collect(),
+        ))
+    }
 }
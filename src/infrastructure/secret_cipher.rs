@@ -0,0 +1,135 @@
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{Context, Result, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Prefix of values encrypted by [`SecretCipher`], anything else is a legacy plaintext value
+const VERSION_PREFIX: &str = "v1:";
+
+/// Length of the AES-GCM nonce stored in front of the ciphertext
+const NONCE_LENGTH: usize = 12;
+
+/// Encrypts secrets that have to be read back, such as TOTP shared secrets, with AES-256-GCM.
+///
+/// Values are stored as `v1:` followed by base64 of the random nonce and the ciphertext.
+#[derive(Clone)]
+pub struct SecretCipher {
+    cipher: Aes256Gcm,
+}
+
+impl SecretCipher {
+    pub fn new(key: [u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+        }
+    }
+
+    /// Parse a base64 encoded 256-bit key, e.g. from `openssl rand -base64 32`
+    pub fn from_base64(key: &str) -> Result<Self> {
+        let key: [u8; 32] = STANDARD
+            .decode(key.trim())
+            .context("Encryption key is not valid base64")?
+            .try_into()
+            .map_err(|_| anyhow!("Encryption key must be 32 bytes"))?;
+
+        Ok(Self::new(key))
+    }
+
+    /// Cipher with a random key, values it encrypts can't be read by any other instance
+    pub fn ephemeral() -> Self {
+        Self {
+            cipher: Aes256Gcm::new(&Aes256Gcm::generate_key(OsRng)),
+        }
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| anyhow!("Failed to encrypt secret"))?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+
+        Ok(format!("{}{}", VERSION_PREFIX, STANDARD.encode(payload)))
+    }
+
+    /// Decrypt a value produced by [`encrypt`](Self::encrypt), failing on any other key or tampering
+    pub fn decrypt(&self, value: &str) -> Result<String> {
+        let encoded = value
+            .strip_prefix(VERSION_PREFIX)
+            .ok_or_else(|| anyhow!("Secret is not encrypted"))?;
+        let payload = STANDARD
+            .decode(encoded)
+            .context("Encrypted secret is not valid base64")?;
+        if payload.len() < NONCE_LENGTH {
+            return Err(anyhow!("Encrypted secret is too short"));
+        }
+
+        let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Failed to decrypt secret"))?;
+
+        String::from_utf8(plaintext).context("Decrypted secret is not valid UTF-8")
+    }
+
+    /// Whether the stored value was written by [`encrypt`](Self::encrypt)
+    pub fn is_encrypted(value: &str) -> bool {
+        value.starts_with(VERSION_PREFIX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_uses_fresh_nonces() {
+        let cipher = SecretCipher::new([7; 32]);
+
+        let first = cipher.encrypt("JBSWY3DPEHPK3PXP").unwrap();
+        let second = cipher.encrypt("JBSWY3DPEHPK3PXP").unwrap();
+
+        assert!(SecretCipher::is_encrypted(&first));
+        assert!(!first.contains("JBSWY3DPEHPK3PXP"));
+        assert_ne!(first, second);
+        assert_eq!(cipher.decrypt(&first).unwrap(), "JBSWY3DPEHPK3PXP");
+        assert_eq!(cipher.decrypt(&second).unwrap(), "JBSWY3DPEHPK3PXP");
+    }
+
+    #[test]
+    fn test_decrypt_rejects_other_keys_and_tampering() {
+        let cipher = SecretCipher::new([7; 32]);
+        let encrypted = cipher.encrypt("JBSWY3DPEHPK3PXP").unwrap();
+
+        assert!(SecretCipher::new([8; 32]).decrypt(&encrypted).is_err());
+        assert!(SecretCipher::ephemeral().decrypt(&encrypted).is_err());
+
+        let mut tampered = encrypted.into_bytes();
+        let middle = tampered.len() / 2;
+        tampered[middle] = if tampered[middle] == b'A' { b'B' } else { b'A' };
+        assert!(
+            cipher
+                .decrypt(&String::from_utf8(tampered).unwrap())
+                .is_err()
+        );
+
+        assert!(cipher.decrypt("JBSWY3DPEHPK3PXP").is_err());
+        assert!(cipher.decrypt("v1:AAAA").is_err());
+    }
+
+    #[test]
+    fn test_from_base64() {
+        let key = STANDARD.encode([7u8; 32]);
+        let cipher = SecretCipher::from_base64(&key).unwrap();
+        let encrypted = SecretCipher::new([7; 32]).encrypt("secret").unwrap();
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "secret");
+
+        assert!(SecretCipher::from_base64(&STANDARD.encode([7u8; 16])).is_err());
+        assert!(SecretCipher::from_base64("not base64!").is_err());
+    }
+}
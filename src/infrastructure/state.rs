@@ -1,17 +1,130 @@
+use crate::application::auth::login_throttle::LoginThrottle;
+use crate::domain::auth::AccessTokenDenylist;
+use crate::domain::notifications::NotificationService;
 use crate::infrastructure::auth::JwtAuthService;
+use crate::infrastructure::client_credentials::ClientCredentials;
 use crate::infrastructure::db::DbPool;
+use crate::infrastructure::notifications::LogNotificationService;
+use crate::infrastructure::password::PasswordService;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::infrastructure::repositories::access_token_denylist::PostgresAccessTokenDenylist;
+use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
+use crate::infrastructure::repositories::login_attempts::PostgresLoginAttemptRepository;
+use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::infrastructure::secret_cipher::SecretCipher;
+use crate::infrastructure::{permission_cache, token_denylist};
+use crate::shared::validation::PasswordPolicies;
 use std::sync::Arc;
+use token_denylist::CachedAccessTokenDenylist;
 
 /// Application state shared across handlers
 #[derive(Clone)]
 pub struct AppState {
     pub pool: DbPool,
     pub auth_service: Arc<JwtAuthService>,
+    pub access_token_denylist: Arc<dyn AccessTokenDenylist>,
+    /// Services allowed to introspect tokens
+    pub introspection_clients: Arc<ClientCredentials>,
+    /// Delivers out-of-band messages such as password reset tokens
+    pub notification_service: Arc<dyn NotificationService>,
+    /// Back-off and lockout after failed logins
+    pub login_throttle: Arc<LoginThrottle>,
+    /// Hashes new passwords with the configured Argon2 cost
+    pub password_service: Arc<PasswordService>,
+    /// Rules for new passwords of users and administrators
+    pub password_policies: PasswordPolicies,
+    /// Administrator and user permissions, invalidated when roles or their permissions change
+    pub permission_cache: Arc<PermissionCache>,
+    /// Take the client IP from `X-Forwarded-For`, only enable behind a reverse proxy
+    pub trust_proxy_headers: bool,
+    /// Encrypts TOTP secrets at rest; random per instance unless a key is configured
+    pub totp_cipher: Arc<SecretCipher>,
 }
 
 impl AppState {
     pub fn new(pool: DbPool, auth_service: Arc<JwtAuthService>) -> Self {
-        Self { pool, auth_service }
+        let access_token_denylist = Arc::new(CachedAccessTokenDenylist::new(
+            Arc::new(PostgresAccessTokenDenylist::new(
+                pool.clone(),
+                auth_service.access_token_expiry(),
+            )),
+            token_denylist::DEFAULT_CACHE_TTL,
+        ));
+
+        let login_throttle = Arc::new(LoginThrottle::new(Arc::new(
+            PostgresLoginAttemptRepository::new(pool.clone()),
+        )));
+
+        let permission_cache = Arc::new(PermissionCache::new(
+            Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+            Arc::new(PostgresUserRepository::new(pool.clone())),
+            permission_cache::DEFAULT_CACHE_TTL,
+        ));
+
+        Self {
+            pool,
+            auth_service,
+            access_token_denylist,
+            introspection_clients: Arc::new(ClientCredentials::new()),
+            notification_service: Arc::new(LogNotificationService::new()),
+            login_throttle,
+            password_service: Arc::new(PasswordService::new()),
+            password_policies: PasswordPolicies::default(),
+            permission_cache,
+            trust_proxy_headers: false,
+            totp_cipher: Arc::new(SecretCipher::ephemeral()),
+        }
+    }
+
+    /// Replace the access token denylist, e.g. to use a different cache TTL
+    pub fn with_access_token_denylist(mut self, denylist: Arc<dyn AccessTokenDenylist>) -> Self {
+        self.access_token_denylist = denylist;
+        self
+    }
+
+    pub fn with_introspection_clients(mut self, clients: ClientCredentials) -> Self {
+        self.introspection_clients = Arc::new(clients);
+        self
+    }
+
+    /// Replace the notification channel, e.g. with an email sender
+    pub fn with_notification_service(mut self, service: Arc<dyn NotificationService>) -> Self {
+        self.notification_service = service;
+        self
+    }
+
+    /// Replace the login throttle, e.g. to use a different policy
+    pub fn with_login_throttle(mut self, login_throttle: LoginThrottle) -> Self {
+        self.login_throttle = Arc::new(login_throttle);
+        self
+    }
+
+    /// Replace the password hasher, e.g. to raise the Argon2 cost
+    pub fn with_password_service(mut self, password_service: PasswordService) -> Self {
+        self.password_service = Arc::new(password_service);
+        self
+    }
+
+    pub fn with_password_policies(mut self, password_policies: PasswordPolicies) -> Self {
+        self.password_policies = password_policies;
+        self
+    }
+
+    /// Replace the permission cache, e.g. to use a different TTL
+    pub fn with_permission_cache(mut self, permission_cache: PermissionCache) -> Self {
+        self.permission_cache = Arc::new(permission_cache);
+        self
+    }
+
+    pub fn with_trust_proxy_headers(mut self, trust_proxy_headers: bool) -> Self {
+        self.trust_proxy_headers = trust_proxy_headers;
+        self
+    }
+
+    /// Encrypt TOTP secrets with the configured key, so they survive restarts
+    pub fn with_totp_cipher(mut self, totp_cipher: SecretCipher) -> Self {
+        self.totp_cipher = Arc::new(totp_cipher);
+        self
     }
 }
 
@@ -26,3 +139,39 @@ impl axum::extract::FromRef<AppState> for Arc<JwtAuthService> {
         app_state.auth_service.clone()
     }
 }
+
+impl axum::extract::FromRef<AppState> for Arc<dyn AccessTokenDenylist> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.access_token_denylist.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for Arc<dyn NotificationService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.notification_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for Arc<PasswordService> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.password_service.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for PasswordPolicies {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.password_policies.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for Arc<SecretCipher> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.totp_cipher.clone()
+    }
+}
+
+impl axum::extract::FromRef<AppState> for Arc<PermissionCache> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.permission_cache.clone()
+    }
+}
@@ -0,0 +1,203 @@
+use crate::domain::auth::{AccessTokenDenylist, Claims};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Default lifetime of cached "not denied" lookups
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Number of cached lookups after which stale entries are pruned
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Default)]
+struct CacheState {
+    /// Revoked token ids and the unix timestamp they expire at
+    denied_tokens: HashMap<String, i64>,
+    /// Token ids known not to be revoked, with the time of the lookup
+    allowed_tokens: HashMap<String, Instant>,
+}
+
+/// In-memory cache in front of another denylist.
+///
+/// Denied tokens are remembered until they expire. Lookups that
+/// found a token valid are cached for `ttl`, so revocations made by another
+/// instance take at most `ttl` to be picked up.
+pub struct CachedAccessTokenDenylist {
+    inner: Arc<dyn AccessTokenDenylist>,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+}
+
+impl CachedAccessTokenDenylist {
+    pub fn new(inner: Arc<dyn AccessTokenDenylist>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        // The cache holds no invariants worth failing over, recover from poisoning
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Answer from the cache if possible
+    fn cached(&self, claims: &Claims) -> Option<bool> {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let state = self.state();
+
+        if state
+            .denied_tokens
+            .get(&claims.jti)
+            .is_some_and(|expires_at| *expires_at > now)
+        {
+            return Some(true);
+        }
+
+        state
+            .allowed_tokens
+            .get(&claims.jti)
+            .filter(|checked_at| checked_at.elapsed() < self.ttl)
+            .map(|_| false)
+    }
+
+    fn prune(&self, state: &mut CacheState) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        state
+            .denied_tokens
+            .retain(|_, expires_at| *expires_at > now);
+        state
+            .allowed_tokens
+            .retain(|_, checked_at| checked_at.elapsed() < self.ttl);
+    }
+}
+
+#[async_trait]
+impl AccessTokenDenylist for CachedAccessTokenDenylist {
+    async fn deny_token(&self, jti: &str, expires_at: OffsetDateTime) -> Result<()> {
+        self.inner.deny_token(jti, expires_at).await?;
+
+        let mut state = self.state();
+        state.allowed_tokens.remove(jti);
+        state
+            .denied_tokens
+            .insert(jti.to_string(), expires_at.unix_timestamp());
+
+        Ok(())
+    }
+
+    async fn deny_subject(&self, user_id: Uuid, user_type: &str) -> Result<()> {
+        self.inner.deny_subject(user_id, user_type).await?;
+
+        // Cached lookups don't record their subject, so drop them all and let
+        // the next lookup of each token hit the inner denylist again
+        self.state().allowed_tokens.clear();
+
+        Ok(())
+    }
+
+    async fn is_denied(&self, claims: &Claims) -> Result<bool> {
+        if let Some(denied) = self.cached(claims) {
+            return Ok(denied);
+        }
+
+        let denied = self.inner.is_denied(claims).await?;
+
+        let mut state = self.state();
+        if state.denied_tokens.len() + state.allowed_tokens.len() > PRUNE_THRESHOLD {
+            self.prune(&mut state);
+        }
+        if denied {
+            state.denied_tokens.insert(claims.jti.clone(), claims.exp);
+        } else {
+            state
+                .allowed_tokens
+                .insert(claims.jti.clone(), Instant::now());
+        }
+
+        Ok(denied)
+    }
+
+    async fn delete_expired(&self) -> Result<u64> {
+        let deleted = self.inner.delete_expired().await?;
+        self.prune(&mut self.state());
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Inner denylist that denies nothing and counts lookups
+    #[derive(Default)]
+    struct CountingDenylist {
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AccessTokenDenylist for CountingDenylist {
+        async fn deny_token(&self, _jti: &str, _expires_at: OffsetDateTime) -> Result<()> {
+            Ok(())
+        }
+
+        async fn deny_subject(&self, _user_id: Uuid, _user_type: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn is_denied(&self, _claims: &Claims) -> Result<bool> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            Ok(false)
+        }
+
+        async fn delete_expired(&self) -> Result<u64> {
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_caches_allowed_lookups() {
+        let inner = Arc::new(CountingDenylist::default());
+        let cache = CachedAccessTokenDenylist::new(inner.clone(), Duration::from_secs(60));
+        let claims = Claims::new_access_token(Uuid::new_v4(), "user".to_string(), 900);
+
+        assert!(!cache.is_denied(&claims).await.unwrap());
+        assert!(!cache.is_denied(&claims).await.unwrap());
+        assert_eq!(inner.lookups.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_denied_token_overrides_cached_lookup() {
+        let inner = Arc::new(CountingDenylist::default());
+        let cache = CachedAccessTokenDenylist::new(inner.clone(), Duration::from_secs(60));
+        let claims = Claims::new_access_token(Uuid::new_v4(), "user".to_string(), 900);
+
+        assert!(!cache.is_denied(&claims).await.unwrap());
+
+        let expires_at = OffsetDateTime::from_unix_timestamp(claims.exp).unwrap();
+        cache.deny_token(&claims.jti, expires_at).await.unwrap();
+
+        assert!(cache.is_denied(&claims).await.unwrap());
+        assert_eq!(inner.lookups.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deny_subject_clears_cached_lookups() {
+        let inner = Arc::new(CountingDenylist::default());
+        let cache = CachedAccessTokenDenylist::new(inner.clone(), Duration::from_secs(60));
+        let user_id = Uuid::new_v4();
+        let claims = Claims::new_access_token(user_id, "user".to_string(), 900);
+
+        assert!(!cache.is_denied(&claims).await.unwrap());
+        cache.deny_subject(user_id, "user").await.unwrap();
+        assert!(!cache.is_denied(&claims).await.unwrap());
+
+        assert_eq!(inner.lookups.load(Ordering::SeqCst), 2);
+    }
+}
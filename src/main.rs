@@ -1,5 +1,7 @@
+use caxur::application;
 use caxur::infrastructure;
 use caxur::presentation;
+use caxur::shared;
 
 use dotenvy::dotenv;
 use std::env;
@@ -39,11 +41,21 @@ where
 
     let database_url = env::var("DATABASE_URL").context("DATABASE_URL must be set")?;
 
-    let (listener, app) = bootstrap(&database_url, port).await?;
+    let (listener, app, maintenance, permission_listener) = bootstrap(&database_url, port).await?;
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal)
-        .await?;
+    // Connection info gives handlers and the rate limiter the peer address
+    let served = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal)
+    .await;
+
+    // Stop background tasks once the server stopped taking requests
+    maintenance.shutdown().await;
+    permission_listener.shutdown().await;
+
+    served?;
 
     Ok(())
 }
@@ -51,7 +63,12 @@ where
 async fn bootstrap(
     database_url: &str,
     port: u16,
-) -> anyhow::Result<(tokio::net::TcpListener, axum::Router)> {
+) -> anyhow::Result<(
+    tokio::net::TcpListener,
+    axum::Router,
+    infrastructure::maintenance::MaintenanceHandle,
+    infrastructure::permission_cache::PermissionListenerHandle,
+)> {
     let pool = infrastructure::db::create_pool(database_url).await?;
 
     // Run migrations
@@ -71,25 +88,283 @@ async fn bootstrap(
         .parse::<i64>()
         .unwrap_or(604800);
 
-    // Initialize auth service
-    let auth_service = std::sync::Arc::new(
-        infrastructure::auth::JwtAuthService::new(
+    // Public base URL of this service, used as `iss` and advertised in the discovery document
+    let issuer = std::env::var("JWT_ISSUER")
+        .unwrap_or_else(|_| infrastructure::auth::DEFAULT_ISSUER.to_string());
+
+    // Initialize auth service, from a key ring when a keys directory is configured
+    let auth_service = match std::env::var("JWT_KEYS_DIR") {
+        Ok(keys_dir) => infrastructure::auth::JwtAuthService::from_keys_dir(
+            keys_dir,
+            std::env::var("JWT_CURRENT_KID").ok().as_deref(),
+            access_token_expiry,
+            refresh_token_expiry,
+        ),
+        Err(_) => infrastructure::auth::JwtAuthService::new(
             &private_key_path,
             &public_key_path,
             access_token_expiry,
             refresh_token_expiry,
-        )
-        .map_err(|e| anyhow::anyhow!("Failed to initialize auth service: {}", e))?,
+        ),
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to initialize auth service: {}", e))?;
+    // Distinct audiences keep admin tokens from being accepted as client tokens elsewhere
+    let client_audience = std::env::var("JWT_CLIENT_AUDIENCE")
+        .unwrap_or_else(|_| infrastructure::auth::DEFAULT_CLIENT_AUDIENCE.to_string());
+    let admin_audience = std::env::var("JWT_ADMIN_AUDIENCE")
+        .unwrap_or_else(|_| infrastructure::auth::DEFAULT_ADMIN_AUDIENCE.to_string());
+
+    let auth_service = std::sync::Arc::new(
+        auth_service
+            .with_issuer(issuer)
+            .with_audiences(client_audience, admin_audience),
     );
 
-    let state = infrastructure::state::AppState::new(pool, auth_service);
+    // Cached "not revoked" lookups may lag revocations made by other instances by this long
+    let denylist_cache_ttl = std::env::var("ACCESS_TOKEN_DENYLIST_CACHE_TTL_SECS")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse::<u64>()
+        .unwrap_or(30);
+
+    let access_token_denylist = std::sync::Arc::new(
+        infrastructure::token_denylist::CachedAccessTokenDenylist::new(
+            std::sync::Arc::new(
+                infrastructure::repositories::access_token_denylist::PostgresAccessTokenDenylist::new(
+                    pool.clone(),
+                    access_token_expiry,
+                ),
+            ),
+            std::time::Duration::from_secs(denylist_cache_ttl),
+        ),
+    );
+
+    let permission_cache_ttl = std::env::var("PERMISSION_CACHE_TTL_SECS")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse::<u64>()
+        .unwrap_or(60);
+    let permission_cache = infrastructure::permission_cache::PermissionCache::new(
+        std::sync::Arc::new(
+            infrastructure::repositories::administrators::PostgresAdministratorRepository::new(
+                pool.clone(),
+            ),
+        ),
+        std::sync::Arc::new(
+            infrastructure::repositories::users::PostgresUserRepository::new(pool.clone()),
+        ),
+        std::time::Duration::from_secs(permission_cache_ttl),
+    );
+
+    let login_attempt_repo = std::sync::Arc::new(
+        infrastructure::repositories::login_attempts::PostgresLoginAttemptRepository::new(
+            pool.clone(),
+        ),
+    );
+
+    // Periodic cleanup of expired tokens
+    let maintenance_interval = std::env::var("MAINTENANCE_INTERVAL_SECS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<u64>()
+        .unwrap_or(3600);
+    // A zero interval would rerun the jobs back to back
+    anyhow::ensure!(
+        maintenance_interval > 0,
+        "MAINTENANCE_INTERVAL_SECS must be greater than zero"
+    );
+    let maintenance_jitter = std::env::var("MAINTENANCE_JITTER_SECS")
+        .unwrap_or_else(|_| "60".to_string())
+        .parse::<u64>()
+        .unwrap_or(60);
+
+    let maintenance = infrastructure::maintenance::MaintenanceScheduler::new(
+        std::time::Duration::from_secs(maintenance_interval),
+    )
+    .with_jitter(std::time::Duration::from_secs(maintenance_jitter))
+    .with_job(std::sync::Arc::new(
+        application::maintenance::purge_expired_tokens::PurgeExpiredRefreshTokensJob::new(
+            std::sync::Arc::new(
+                infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository::new(
+                    pool.clone(),
+                ),
+            ),
+        ),
+    ))
+    .with_job(std::sync::Arc::new(
+        application::maintenance::purge_expired_tokens::PurgeExpiredDenylistEntriesJob::new(
+            access_token_denylist.clone(),
+        ),
+    ))
+    .with_job(std::sync::Arc::new(
+        application::maintenance::purge_expired_tokens::PurgeExpiredOneTimeTokensJob::new(
+            std::sync::Arc::new(
+                infrastructure::repositories::one_time_tokens::PostgresOneTimeTokenRepository::new(
+                    pool.clone(),
+                ),
+            ),
+        ),
+    ))
+    .with_job(std::sync::Arc::new(
+        application::maintenance::purge_expired_tokens::PurgeExpiredLoginAttemptsJob::new(
+            login_attempt_repo.clone(),
+        ),
+    ))
+    .with_job(std::sync::Arc::new(
+        application::maintenance::purge_expired_tokens::PurgeExpiredRoleAssignmentsJob::new(
+            std::sync::Arc::new(
+                infrastructure::repositories::administrators::PostgresAdministratorRepository::new(
+                    pool.clone(),
+                ),
+            ),
+        ),
+    ));
+
+    // Failed login limits, per account and per client IP
+    let env_limit = |name: &str, default: i32| {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse::<i32>().ok())
+            .unwrap_or(default)
+    };
+    let default_policy = application::auth::login_throttle::LoginThrottlePolicy::default();
+    let login_throttle_policy = application::auth::login_throttle::LoginThrottlePolicy {
+        account: application::auth::login_throttle::ThrottleLimits {
+            backoff_after: env_limit("LOGIN_BACKOFF_AFTER", default_policy.account.backoff_after),
+            lockout_after: env_limit("LOGIN_LOCKOUT_AFTER", default_policy.account.lockout_after),
+        },
+        ip: application::auth::login_throttle::ThrottleLimits {
+            backoff_after: env_limit("LOGIN_IP_BACKOFF_AFTER", default_policy.ip.backoff_after),
+            lockout_after: env_limit("LOGIN_IP_LOCKOUT_AFTER", default_policy.ip.lockout_after),
+        },
+        lockout_duration: time::Duration::seconds(
+            env_limit(
+                "LOGIN_LOCKOUT_SECS",
+                default_policy.lockout_duration.whole_seconds() as i32,
+            )
+            .into(),
+        ),
+        ..default_policy
+    };
+    let login_throttle = application::auth::login_throttle::LoginThrottle::new(login_attempt_repo)
+        .with_policy(login_throttle_policy);
+
+    // Argon2 cost for new hashes, older hashes are upgraded as their owners sign in
+    let env_cost = |name: &str, default: u32| {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(default)
+    };
+    let argon2_params = argon2::Params::new(
+        env_cost("ARGON2_MEMORY_KIB", argon2::Params::DEFAULT_M_COST),
+        env_cost("ARGON2_ITERATIONS", argon2::Params::DEFAULT_T_COST),
+        env_cost("ARGON2_PARALLELISM", argon2::Params::DEFAULT_P_COST),
+        None,
+    )
+    .map_err(|e| anyhow::anyhow!("Invalid Argon2 parameters: {}", e))?;
+    let password_service =
+        infrastructure::password::PasswordService::new().with_params(argon2_params);
+
+    // Rules for new passwords, administrators can be held to a stricter policy
+    let breached_passwords = match std::env::var("BREACHED_PASSWORDS_FILE") {
+        Ok(path) if !path.is_empty() => {
+            shared::validation::PasswordPolicy::load_breached_passwords(&path)
+                .with_context(|| format!("Failed to read breached password list {}", path))?
+        }
+        _ => Default::default(),
+    };
+    let breached_passwords = std::sync::Arc::new(breached_passwords);
+    let password_policies = shared::validation::PasswordPolicies {
+        users: std::sync::Arc::new(password_policy_from_env(
+            "PASSWORD",
+            breached_passwords.clone(),
+        )),
+        administrators: std::sync::Arc::new(password_policy_from_env(
+            "ADMIN_PASSWORD",
+            breached_passwords,
+        )),
+    };
+
+    // Services allowed to introspect tokens, as `client_id:client_secret` pairs
+    let introspection_clients = infrastructure::client_credentials::ClientCredentials::parse(
+        &std::env::var("INTROSPECTION_CLIENTS").unwrap_or_default(),
+    );
+
+    // Client IPs are taken from X-Forwarded-For only behind a reverse proxy
+    let trust_proxy_headers = std::env::var("TRUST_PROXY_HEADERS")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+
+    // TOTP secrets are encrypted at rest, a lost key disables every enrolled authenticator
+    let totp_cipher = infrastructure::secret_cipher::SecretCipher::from_base64(
+        &env::var("TOTP_ENCRYPTION_KEY").context("TOTP_ENCRYPTION_KEY must be set")?,
+    )
+    .context("Invalid TOTP_ENCRYPTION_KEY")?;
+
+    // Password reset and verification tokens are only logged on explicit opt-in
+    let log_notification_tokens = std::env::var("LOG_NOTIFICATION_TOKENS")
+        .map(|v| v == "true")
+        .unwrap_or(false);
+    if log_notification_tokens {
+        tracing::warn!(
+            "LOG_NOTIFICATION_TOKENS is enabled, never use it outside local development"
+        );
+    }
+    let notification_service = infrastructure::notifications::LogNotificationService::new()
+        .with_token_logging(log_notification_tokens);
+
+    let state = infrastructure::state::AppState::new(pool, auth_service)
+        .with_access_token_denylist(access_token_denylist)
+        .with_introspection_clients(introspection_clients)
+        .with_notification_service(std::sync::Arc::new(notification_service))
+        .with_login_throttle(login_throttle)
+        .with_password_service(password_service)
+        .with_password_policies(password_policies)
+        .with_permission_cache(permission_cache)
+        .with_trust_proxy_headers(trust_proxy_headers)
+        .with_totp_cipher(totp_cipher);
+    let permission_cache = state.permission_cache.clone();
+    let listener_pool = state.pool.clone();
     let app = presentation::router::app(state)?;
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     tracing::debug!("listening on {}", addr);
     let listener = tokio::net::TcpListener::bind(addr).await?;
 
-    Ok((listener, app))
+    // Started last so a failed bootstrap leaves no background task behind
+    let permission_listener = permission_cache.listen(listener_pool);
+    Ok((listener, app, maintenance.start(), permission_listener))
+}
+
+/// Password policy from `{prefix}_MIN_LENGTH`, `{prefix}_REQUIRE_DIGIT` and so on
+fn password_policy_from_env(
+    prefix: &str,
+    breached_passwords: std::sync::Arc<std::collections::HashSet<String>>,
+) -> shared::validation::PasswordPolicy {
+    let length = |name: &str, default: usize| {
+        env::var(format!("{}_{}", prefix, name))
+            .ok()
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(default)
+    };
+    let flag = |name: &str, default: bool| {
+        env::var(format!("{}_{}", prefix, name))
+            .map(|value| value == "true")
+            .unwrap_or(default)
+    };
+
+    let mut policy =
+        shared::validation::PasswordPolicy::default().with_breached_passwords(breached_passwords);
+    policy.min_length = length("MIN_LENGTH", policy.min_length);
+    policy.max_length = length("MAX_LENGTH", policy.max_length);
+    policy.require_lowercase = flag("REQUIRE_LOWERCASE", policy.require_lowercase);
+    policy.require_uppercase = flag("REQUIRE_UPPERCASE", policy.require_uppercase);
+    policy.require_digit = flag("REQUIRE_DIGIT", policy.require_digit);
+    policy.require_symbol = flag("REQUIRE_SYMBOL", policy.require_symbol);
+    policy.disallow_identifiers = flag("DISALLOW_IDENTIFIERS", policy.disallow_identifiers);
+    policy.allow_change_without_current_password = flag(
+        "ALLOW_CHANGE_WITHOUT_CURRENT",
+        policy.allow_change_without_current_password,
+    );
+    policy
 }
 
 #[cfg(test)]
@@ -140,6 +415,10 @@ mod tests {
         // SAFETY: This is a test and we are setting the env var before running the app
         unsafe {
             std::env::set_var("DATABASE_URL", database_url);
+            std::env::set_var(
+                "TOTP_ENCRYPTION_KEY",
+                "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+            );
         }
 
         // Run with an immediate shutdown signal and port 0
@@ -172,6 +451,10 @@ mod tests {
         // SAFETY: This is a test and we are setting the env var before running the app
         unsafe {
             std::env::set_var("DATABASE_URL", database_url);
+            std::env::set_var(
+                "TOTP_ENCRYPTION_KEY",
+                "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+            );
         }
 
         // Test run_with_signal by mocking the signal with immediate completion
@@ -7,20 +7,28 @@ use crate::application::administrators::list::{
     ListAdministratorsRequest, ListAdministratorsUseCase,
 };
 use crate::application::administrators::roles::{
-    AttachRoles, AttachRolesRequest, DetachRoles, DetachRolesRequest,
+    AttachRoles, AttachRolesRequest, DetachRoles, DetachRolesRequest, ListRoleAssignments,
 };
 use crate::application::administrators::update::{
     UpdateAdministratorRequest, UpdateAdministratorUseCase,
 };
-use crate::domain::administrators::Administrator;
+use crate::domain::access_scope::AccessScope;
+use crate::domain::administrators::{Administrator, AssignedRole};
+use crate::domain::auth::AccessTokenDenylist;
 use crate::infrastructure::db::DbPool;
 use crate::infrastructure::password::PasswordService;
+use crate::infrastructure::permission_cache::PermissionCache;
 use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
-use crate::presentation::extractors::AuthUser;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::repositories::roles::PostgresRoleRepository;
+use crate::presentation::extractors::{
+    AdministratorsCreate, AdministratorsDelete, AdministratorsRead, AdministratorsRolesAttach,
+    AdministratorsRolesDetach, AdministratorsUpdate, RequirePermission,
+};
 use crate::shared::error::{AppError, ErrorResponse};
 use crate::shared::query::Qs;
 use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
-use crate::shared::validation::ValidatedJson;
+use crate::shared::validation::{PasswordPolicies, ValidatedJson};
 use axum::{
     Json,
     extract::{Path, State},
@@ -67,6 +75,30 @@ impl From<Administrator> for AdministratorResource {
     }
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleAssignmentResource {
+    pub name: String,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub assigned_at: time::OffsetDateTime,
+    pub assigned_by: Option<String>,
+    #[serde(with = "time::serde::iso8601::option")]
+    #[schema(value_type = Option<String>)]
+    pub expires_at: Option<time::OffsetDateTime>,
+}
+
+impl From<AssignedRole> for RoleAssignmentResource {
+    fn from(assignment: AssignedRole) -> Self {
+        Self {
+            name: assignment.role_name,
+            assigned_at: assignment.assigned_at,
+            assigned_by: assignment.assigned_by.map(|id| id.to_string()),
+            expires_at: assignment.expires_at,
+        }
+    }
+}
+
 /// Create a new administrator
 #[utoipa::path(
     post,
@@ -84,12 +116,14 @@ impl From<Administrator> for AdministratorResource {
 )]
 pub async fn create_admin(
     State(pool): State<DbPool>,
-    _auth: AuthUser,
+    State(hasher): State<Arc<PasswordService>>,
+    State(password_policies): State<PasswordPolicies>,
+    _admin: RequirePermission<AdministratorsCreate>,
     ValidatedJson(req): ValidatedJson<CreateAdministratorRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresAdministratorRepository::new(pool));
-    let hasher = Arc::new(PasswordService::new());
-    let use_case = CreateAdministratorUseCase::new(repo, hasher);
+    let use_case = CreateAdministratorUseCase::new(repo, hasher)
+        .with_password_policy(password_policies.administrators);
 
     let admin = use_case.execute(req).await?;
     let resource = JsonApiResource::new(
@@ -121,7 +155,7 @@ pub async fn create_admin(
 pub async fn get_admin(
     State(pool): State<DbPool>,
     Path(id): Path<Uuid>,
-    _auth: AuthUser,
+    _admin: RequirePermission<AdministratorsRead>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresAdministratorRepository::new(pool));
     let use_case = GetAdministratorUseCase::new(repo);
@@ -159,7 +193,7 @@ pub async fn list_admins(
     State(pool): State<DbPool>,
     uri: Uri,
     Qs(req): Qs<ListAdministratorsRequest>,
-    _auth: AuthUser,
+    _admin: RequirePermission<AdministratorsRead>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresAdministratorRepository::new(pool));
     let use_case = ListAdministratorsUseCase::new(repo.clone());
@@ -232,13 +266,18 @@ pub async fn list_admins(
 )]
 pub async fn update_admin(
     State(pool): State<DbPool>,
+    State(hasher): State<Arc<PasswordService>>,
+    State(password_policies): State<PasswordPolicies>,
+    State(access_token_denylist): State<Arc<dyn AccessTokenDenylist>>,
     Path(id): Path<Uuid>,
-    _auth: AuthUser,
+    _admin: RequirePermission<AdministratorsUpdate>,
     ValidatedJson(req): ValidatedJson<UpdateAdministratorRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let repo = Arc::new(PostgresAdministratorRepository::new(pool));
-    let hasher = Arc::new(PasswordService::new());
-    let use_case = UpdateAdministratorUseCase::new(repo, hasher);
+    let repo = Arc::new(PostgresAdministratorRepository::new(pool.clone()));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
+    let use_case =
+        UpdateAdministratorUseCase::new(repo, hasher, refresh_token_repo, access_token_denylist)
+            .with_password_policy(password_policies.administrators);
 
     let admin = use_case.execute(id, req).await?;
     let resource = JsonApiResource::new(
@@ -269,11 +308,13 @@ pub async fn update_admin(
 )]
 pub async fn delete_admin(
     State(pool): State<DbPool>,
+    State(access_token_denylist): State<Arc<dyn AccessTokenDenylist>>,
     Path(id): Path<Uuid>,
-    _auth: AuthUser,
+    _admin: RequirePermission<AdministratorsDelete>,
 ) -> Result<impl IntoResponse, AppError> {
-    let repo = Arc::new(PostgresAdministratorRepository::new(pool));
-    let use_case = DeleteAdministratorUseCase::new(repo);
+    let repo = Arc::new(PostgresAdministratorRepository::new(pool.clone()));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
+    let use_case = DeleteAdministratorUseCase::new(repo, refresh_token_repo, access_token_denylist);
 
     let deleted = use_case.execute(id).await?;
 
@@ -288,6 +329,53 @@ pub async fn delete_admin(
     }
 }
 
+/// List the roles of an administrator with who assigned them and until when
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/administrators/{id}/roles",
+    params(
+        ("id" = Uuid, Path, description = "Administrator ID")
+    ),
+    responses(
+        (status = 200, description = "Roles assigned and not expired", body = JsonApiResponse<Vec<JsonApiResource<RoleAssignmentResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Administrator not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Administrator Management"
+)]
+pub async fn list_admin_roles(
+    State(pool): State<DbPool>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<AdministratorsRead>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresAdministratorRepository::new(pool));
+    let use_case = ListRoleAssignments::new(repo);
+
+    let assignments = use_case.execute(id).await?;
+    let total = assignments.len() as i64;
+
+    let resources: Vec<JsonApiResource<RoleAssignmentResource>> = assignments
+        .into_iter()
+        .map(|assignment| {
+            JsonApiResource::new(
+                "role-assignments",
+                assignment.role_id.to_string(),
+                RoleAssignmentResource::from(assignment),
+            )
+        })
+        .collect();
+
+    let meta = JsonApiMeta::new().with_total(total);
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(resources).with_meta(meta)),
+    ))
+}
+
 /// Attach roles to an administrator
 #[utoipa::path(
     post,
@@ -299,7 +387,8 @@ pub async fn delete_admin(
     responses(
         (status = 200, description = "Roles attached successfully", body = JsonApiResponse<serde_json::Value>),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 404, description = "Administrator not found", body = ErrorResponse)
+        (status = 404, description = "Administrator not found", body = ErrorResponse),
+        (status = 422, description = "Expiry is not in the future or a role is not an administrator role", body = ErrorResponse)
     ),
     security(
         ("bearer_auth" = [])
@@ -308,14 +397,18 @@ pub async fn delete_admin(
 )]
 pub async fn attach_admin_roles(
     State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
     Path(id): Path<Uuid>,
-    _auth: AuthUser,
+    admin: RequirePermission<AdministratorsRolesAttach>,
     ValidatedJson(req): ValidatedJson<AttachRolesRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let repo = Arc::new(PostgresAdministratorRepository::new(pool));
-    let use_case = AttachRoles::new(repo);
+    let use_case = AttachRoles::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresRoleRepository::new(pool)),
+    );
 
-    use_case.execute(id, req.role_ids).await?;
+    use_case.execute(id, req, admin.administrator_id).await?;
+    permission_cache.invalidate(AccessScope::Administrator, id);
 
     Ok((
         StatusCode::OK,
@@ -343,14 +436,18 @@ pub async fn attach_admin_roles(
 )]
 pub async fn detach_admin_roles(
     State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
     Path(id): Path<Uuid>,
-    _auth: AuthUser,
+    admin: RequirePermission<AdministratorsRolesDetach>,
     ValidatedJson(req): ValidatedJson<DetachRolesRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresAdministratorRepository::new(pool));
     let use_case = DetachRoles::new(repo);
 
-    use_case.execute(id, req.role_ids).await?;
+    use_case
+        .execute(id, req.role_ids, admin.administrator_id)
+        .await?;
+    permission_cache.invalidate(AccessScope::Administrator, id);
 
     Ok((
         StatusCode::OK,
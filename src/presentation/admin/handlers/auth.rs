@@ -1,12 +1,34 @@
-use crate::application::auth::admin_login::{AdminLoginRequest, AdminLoginUseCase};
+use crate::application::auth::admin_login::{
+    AdminLoginRequest, AdminLoginResponse, AdminLoginUseCase,
+};
+use crate::application::auth::logout::{LogoutAllUseCase, LogoutRequest, LogoutUseCase};
+use crate::application::auth::mfa::{
+    ConfirmRequiredTotpEnrollmentUseCase, MfaChallengeIssuer, MfaEnrollmentRequest,
+    MfaVerifyRequest, StartRequiredTotpEnrollmentUseCase, VerifyMfaChallengeUseCase,
+};
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::application::auth::password_reset::{
+    ForgotPasswordRequest, RequestPasswordResetUseCase, ResetPasswordRequest, ResetPasswordUseCase,
+};
+use crate::infrastructure::db::DbPool;
 use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
+use crate::infrastructure::repositories::mfa::PostgresMfaRepository;
+use crate::infrastructure::repositories::one_time_tokens::PostgresOneTimeTokenRepository;
 use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::secret_cipher::SecretCipher;
 use crate::infrastructure::state::AppState;
-use crate::presentation::dtos::AuthTokenResource;
+use crate::presentation::dtos::{AuthTokenResource, MfaChallengeResource, TotpEnrollmentResource};
+use crate::presentation::extractors::{AnyAdministrator, ClientInfo, RequirePermission};
 use crate::shared::error::{AppError, ErrorResponse};
-use crate::shared::response::{JsonApiResource, JsonApiResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
 use crate::shared::validation::ValidatedJson;
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
 use std::sync::Arc;
 
 /// Admin Login handler
@@ -15,22 +37,31 @@ use std::sync::Arc;
     path = "/api/v1/admin/auth/login",
     request_body = AdminLoginRequest,
     responses(
-        (status = 200, description = "Admin Login successful", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
+        (status = 200, description = "Admin Login successful. Administrators with a second factor receive an `mfa-challenges` resource (MfaChallengeResource) instead of tokens, holders of `*` without one an `mfa-enrollment-challenges` resource", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
         (status = 401, description = "Invalid credentials", body = ErrorResponse),
+        (status = 429, description = "Too many failed attempts (`login_throttled` or `account_locked`)", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     ),
     tag = "Admin / Auth"
 )]
 pub async fn admin_login(
     State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
     ValidatedJson(req): ValidatedJson<AdminLoginRequest>,
-) -> Result<impl IntoResponse, AppError> {
+) -> Result<Response, AppError> {
     let auth_service = state.auth_service;
     let pool = state.pool;
 
     let admin_repo = Arc::new(PostgresAdministratorRepository::new(pool.clone()));
-    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
-    let password_service = Arc::new(crate::infrastructure::password::PasswordService::new());
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let mfa = MfaChallengeIssuer::new(
+        Arc::new(PostgresMfaRepository::new(
+            pool.clone(),
+            state.totp_cipher.clone(),
+        )),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+    );
+    let password_service = state.password_service;
 
     let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
         .unwrap_or_else(|_| "900".to_string())
@@ -48,7 +79,80 @@ pub async fn admin_login(
         password_service,
         access_token_expiry,
         refresh_token_expiry,
-    );
+    )
+    .with_session_metadata(session_metadata)
+    .with_mfa(mfa)
+    .with_login_throttle(state.login_throttle);
+
+    let response = match use_case.execute(req).await? {
+        AdminLoginResponse::Authenticated(tokens) => {
+            let resource =
+                JsonApiResource::new("auth-tokens", "session", AuthTokenResource::from(tokens));
+            (StatusCode::OK, Json(JsonApiResponse::new(resource))).into_response()
+        }
+        AdminLoginResponse::MfaRequired(challenge) => {
+            let resource = JsonApiResource::new(
+                "mfa-challenges",
+                "challenge",
+                MfaChallengeResource::from(challenge),
+            );
+            (StatusCode::OK, Json(JsonApiResponse::new(resource))).into_response()
+        }
+        AdminLoginResponse::MfaEnrollmentRequired(challenge) => {
+            let resource = JsonApiResource::new(
+                "mfa-enrollment-challenges",
+                "challenge",
+                MfaChallengeResource::from(challenge),
+            );
+            (StatusCode::OK, Json(JsonApiResponse::new(resource))).into_response()
+        }
+    };
+
+    Ok(response)
+}
+
+/// Admin MFA verify handler - exchanges an MFA challenge and a code for tokens
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/mfa/verify",
+    request_body = MfaVerifyRequest,
+    responses(
+        (status = 200, description = "Second factor accepted", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
+        (status = 401, description = "Invalid challenge or code", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse),
+        (status = 429, description = "Too many failed attempts (`login_throttled` or `account_locked`)", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_verify_mfa(
+    State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
+    ValidatedJson(req): ValidatedJson<MfaVerifyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "900".to_string())
+        .parse::<i64>()
+        .unwrap_or(900);
+    let refresh_token_expiry = std::env::var("JWT_REFRESH_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "604800".to_string())
+        .parse::<i64>()
+        .unwrap_or(604800);
+
+    let pool = state.pool;
+    let use_case = VerifyMfaChallengeUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresMfaRepository::new(
+            pool.clone(),
+            state.totp_cipher.clone(),
+        )),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool.clone())),
+        Arc::new(PostgresRefreshTokenRepository::new(pool)),
+        state.auth_service,
+        access_token_expiry,
+        refresh_token_expiry,
+    )
+    .with_session_metadata(session_metadata)
+    .with_login_throttle(state.login_throttle);
 
     let response = use_case.execute(req).await?;
     let resource =
@@ -56,3 +160,237 @@ pub async fn admin_login(
 
     Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
 }
+
+/// Start the TOTP enrollment required to finish signing in
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/mfa/enroll",
+    request_body = MfaEnrollmentRequest,
+    responses(
+        (status = 200, description = "TOTP secret generated", body = JsonApiResponse<JsonApiResource<TotpEnrollmentResource>>),
+        (status = 401, description = "Invalid or expired challenge", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_start_required_mfa_enrollment(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    ValidatedJson(req): ValidatedJson<MfaEnrollmentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let issuer = std::env::var("TOTP_ISSUER").unwrap_or_else(|_| "Caxur".to_string());
+
+    let use_case = StartRequiredTotpEnrollmentUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresMfaRepository::new(pool.clone(), totp_cipher)),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+    )
+    .with_issuer(issuer);
+    let enrollment = use_case.execute(req).await?;
+
+    let resource = JsonApiResource::new(
+        "totp-enrollments",
+        "enrollment",
+        TotpEnrollmentResource::from(enrollment),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Confirm the required TOTP enrollment - returns tokens, recovery codes are in `meta`
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/mfa/enroll/confirm",
+    request_body = MfaVerifyRequest,
+    responses(
+        (status = 200, description = "TOTP enabled and signed in, `meta.recoveryCodes` are shown once", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
+        (status = 400, description = "Enrollment not started", body = ErrorResponse),
+        (status = 401, description = "Invalid challenge or code", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse),
+        (status = 429, description = "Too many failed attempts (`login_throttled` or `account_locked`)", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_confirm_required_mfa_enrollment(
+    State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
+    ValidatedJson(req): ValidatedJson<MfaVerifyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "900".to_string())
+        .parse::<i64>()
+        .unwrap_or(900);
+    let refresh_token_expiry = std::env::var("JWT_REFRESH_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "604800".to_string())
+        .parse::<i64>()
+        .unwrap_or(604800);
+
+    let pool = state.pool;
+    let use_case = ConfirmRequiredTotpEnrollmentUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresMfaRepository::new(
+            pool.clone(),
+            state.totp_cipher.clone(),
+        )),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool.clone())),
+        Arc::new(PostgresRefreshTokenRepository::new(pool)),
+        state.auth_service,
+        access_token_expiry,
+        refresh_token_expiry,
+    )
+    .with_session_metadata(session_metadata)
+    .with_login_throttle(state.login_throttle);
+
+    let completed = use_case.execute(req).await?;
+    let resource = JsonApiResource::new(
+        "auth-tokens",
+        "session",
+        AuthTokenResource::from(completed.tokens),
+    );
+    let meta = JsonApiMeta::new()
+        .with_extra(json!({ "recoveryCodes": completed.recovery_codes.recovery_codes }));
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(resource).with_meta(meta)),
+    ))
+}
+
+/// Admin Logout handler - revokes the presented refresh token and the current access token
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/logout",
+    request_body = LogoutRequest,
+    responses(
+        (status = 200, description = "Logged out successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized or invalid refresh token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_logout(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<LogoutRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = LogoutUseCase::new(refresh_token_repo, state.access_token_denylist);
+
+    use_case.execute(&admin.claims, req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "loggedOut": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Admin Logout-all handler - revokes every token of the authenticated administrator
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/logout-all",
+    responses(
+        (status = 200, description = "All sessions revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_logout_all(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = LogoutAllUseCase::new(refresh_token_repo, state.access_token_denylist);
+
+    let revoked = use_case.execute(&admin.claims).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "loggedOut": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Admin Forgot-password handler - sends a password reset token to the account's email
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/forgot-password",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset requested; the response is the same for unknown emails", body = JsonApiResponse<serde_json::Value>),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_forgot_password(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let reset_token_expiry = std::env::var("PASSWORD_RESET_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<i64>()
+        .unwrap_or(3600);
+
+    let accounts = PasswordAccounts::Administrators(Arc::new(
+        PostgresAdministratorRepository::new(state.pool.clone()),
+    ));
+    let token_repo = Arc::new(PostgresOneTimeTokenRepository::new(state.pool));
+    let use_case =
+        RequestPasswordResetUseCase::new(accounts, token_repo, state.notification_service)
+            .with_token_ttl(time::Duration::seconds(reset_token_expiry));
+
+    use_case.execute(req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "resetRequested": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Admin Reset-password handler - sets a new password using a reset token
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/reset-password",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset, all sessions revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 400, description = "Invalid or expired reset token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn admin_reset_password(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<ResetPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let accounts = PasswordAccounts::Administrators(Arc::new(
+        PostgresAdministratorRepository::new(state.pool.clone()),
+    ));
+    let token_repo = Arc::new(PostgresOneTimeTokenRepository::new(state.pool.clone()));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let password_service = state.password_service;
+    let use_case = ResetPasswordUseCase::new(
+        accounts,
+        token_repo,
+        password_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    )
+    .with_password_policy(state.password_policies.administrators);
+
+    let revoked = use_case.execute(req).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "passwordReset": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
@@ -0,0 +1,335 @@
+use crate::application::groups::create::{CreateGroupRequest, CreateGroupUseCase};
+use crate::application::groups::delete::DeleteGroupUseCase;
+use crate::application::groups::get::GetGroupUseCase;
+use crate::application::groups::list::ListGroupsUseCase;
+use crate::application::groups::members::{
+    AddGroupMembers, GroupMembersRequest, ListGroupMembers, RemoveGroupMembers,
+};
+use crate::application::groups::update::{UpdateGroupRequest, UpdateGroupUseCase};
+use crate::domain::access_scope::AccessScope;
+use crate::domain::groups::GroupRepository;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::infrastructure::repositories::groups::PostgresGroupRepository;
+use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::presentation::dtos::{GroupResource, UserResource};
+use crate::presentation::extractors::{
+    GroupsCreate, GroupsDelete, GroupsMembersAdd, GroupsMembersRead, GroupsMembersRemove,
+    GroupsRead, GroupsUpdate, RequirePermission,
+};
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::pagination::{default_page_number, default_page_size};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{StatusCode, Uri},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, IntoParams, ToSchema)]
+pub struct ListGroupsQuery {
+    #[serde(default = "default_page_number")]
+    #[param(example = 1, minimum = 1)]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    #[param(example = 20, minimum = 1, maximum = 100)]
+    pub per_page: i64,
+}
+
+/// Create a new group
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/groups",
+    request_body = CreateGroupRequest,
+    responses(
+        (status = 201, description = "Group created successfully", body = JsonApiResponse<JsonApiResource<GroupResource>>),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_group(
+    State(pool): State<DbPool>,
+    _admin: RequirePermission<GroupsCreate>,
+    ValidatedJson(req): ValidatedJson<CreateGroupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresGroupRepository::new(pool));
+    let use_case = CreateGroupUseCase::new(repo);
+
+    let group = use_case.execute(req).await?;
+    let resource = JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group));
+
+    Ok((StatusCode::CREATED, Json(JsonApiResponse::new(resource))))
+}
+
+/// List all groups with pagination
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/groups",
+    params(ListGroupsQuery),
+    responses(
+        (status = 200, description = "List of groups", body = JsonApiResponse<Vec<JsonApiResource<GroupResource>>>),
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_groups(
+    State(pool): State<DbPool>,
+    _admin: RequirePermission<GroupsRead>,
+    uri: Uri,
+    Query(query): Query<ListGroupsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresGroupRepository::new(pool));
+    let use_case = ListGroupsUseCase::new(repo.clone());
+
+    let groups = use_case.execute(query.per_page, query.page).await?;
+    let total = repo.count().await.map_err(AppError::InternalServerError)?;
+
+    let resources: Vec<JsonApiResource<GroupResource>> = groups
+        .into_iter()
+        .map(|group| {
+            JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group))
+        })
+        .collect();
+
+    let meta = JsonApiMeta::new()
+        .with_page(query.page)
+        .with_per_page(query.per_page)
+        .with_total(total);
+
+    let links = crate::shared::pagination::PaginationLinkBuilder::from_uri(
+        &uri,
+        query.page,
+        query.per_page,
+        total,
+    )
+    .build();
+
+    Ok((
+        StatusCode::OK,
+        Json(
+            JsonApiResponse::new(resources)
+                .with_meta(meta)
+                .with_links(links),
+        ),
+    ))
+}
+
+/// Get a group by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/groups/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    responses(
+        (status = 200, description = "Group found", body = JsonApiResponse<JsonApiResource<GroupResource>>),
+        (status = 404, description = "Group not found", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_group(
+    State(pool): State<DbPool>,
+    _admin: RequirePermission<GroupsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresGroupRepository::new(pool));
+    let use_case = GetGroupUseCase::new(repo);
+
+    let group = use_case.execute(id).await?;
+    let resource = JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group));
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Update a group
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/groups/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = UpdateGroupRequest,
+    responses(
+        (status = 200, description = "Group updated successfully", body = JsonApiResponse<JsonApiResource<GroupResource>>),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_group(
+    State(pool): State<DbPool>,
+    _admin: RequirePermission<GroupsUpdate>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(req): ValidatedJson<UpdateGroupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresGroupRepository::new(pool));
+    let use_case = UpdateGroupUseCase::new(repo);
+
+    let group = use_case.execute(id, req).await?;
+    let resource = JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group));
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Delete a group along with its roles
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/groups/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    responses(
+        (status = 200, description = "Group deleted successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 404, description = "Group not found", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_group(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<GroupsDelete>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresGroupRepository::new(pool));
+    let use_case = DeleteGroupUseCase::new(repo);
+
+    use_case.execute(id).await?;
+    permission_cache.invalidate_all();
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "deleted": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// List the members of a group
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/groups/{id}/members",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    responses(
+        (status = 200, description = "List of members", body = JsonApiResponse<Vec<JsonApiResource<UserResource>>>),
+        (status = 404, description = "Group not found", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_group_members(
+    State(pool): State<DbPool>,
+    _admin: RequirePermission<GroupsMembersRead>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = ListGroupMembers::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    let members = use_case.execute(id).await?;
+    let resources: Vec<JsonApiResource<UserResource>> = members
+        .into_iter()
+        .map(|user| JsonApiResource::new("users", user.id.to_string(), UserResource::from(user)))
+        .collect();
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resources))))
+}
+
+/// Add users to a group
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/groups/{id}/members",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = GroupMembersRequest,
+    responses(
+        (status = 200, description = "Members added successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 404, description = "Group not found", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_group_members(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<GroupsMembersAdd>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(req): ValidatedJson<GroupMembersRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = AddGroupMembers::new(
+        Arc::new(PostgresGroupRepository::new(pool.clone())),
+        Arc::new(PostgresUserRepository::new(pool)),
+    );
+
+    use_case.execute(id, req.user_ids.clone()).await?;
+    for user_id in req.user_ids {
+        permission_cache.invalidate(AccessScope::User, user_id);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
+
+/// Remove users from a group, along with their roles in the group
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/groups/{id}/members",
+    params(
+        ("id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = GroupMembersRequest,
+    responses(
+        (status = 200, description = "Members removed successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 404, description = "Group not found", body = ErrorResponse)
+    ),
+    tag = "Admin / Group Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_group_members(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<GroupsMembersRemove>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(req): ValidatedJson<GroupMembersRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = RemoveGroupMembers::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    use_case.execute(id, req.user_ids.clone()).await?;
+    for user_id in req.user_ids {
+        permission_cache.invalidate(AccessScope::User, user_id);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
@@ -0,0 +1,83 @@
+use crate::application::administrators::unlock::UnlockAdministratorUseCase;
+use crate::application::users::unlock::UnlockUserUseCase;
+use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
+use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::infrastructure::state::AppState;
+use crate::presentation::extractors::{AdministratorsUnlock, RequirePermission, UsersUnlock};
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResponse};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn unlocked_response(unlocked: bool) -> impl IntoResponse {
+    let meta = JsonApiMeta::new().with_extra(json!({ "unlocked": unlocked }));
+    (
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    )
+}
+
+/// Lift the login lockout of a user
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/users/{id}/unlock",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Failed login attempts cleared", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / User Management"
+)]
+pub async fn unlock_user(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<UsersUnlock>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresUserRepository::new(state.pool));
+    let use_case = UnlockUserUseCase::new(repo, state.login_throttle);
+
+    let unlocked = use_case.execute(id).await?;
+    Ok(unlocked_response(unlocked))
+}
+
+/// Lift the login lockout of an administrator
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/administrators/{id}/unlock",
+    params(
+        ("id" = Uuid, Path, description = "Administrator ID")
+    ),
+    responses(
+        (status = 200, description = "Failed login attempts cleared", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Administrator not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Administrator Management"
+)]
+pub async fn unlock_admin(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<AdministratorsUnlock>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresAdministratorRepository::new(state.pool));
+    let use_case = UnlockAdministratorUseCase::new(repo, state.login_throttle);
+
+    let unlocked = use_case.execute(id).await?;
+    Ok(unlocked_response(unlocked))
+}
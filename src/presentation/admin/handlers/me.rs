@@ -0,0 +1,299 @@
+use crate::application::auth::change_password::{ChangePasswordRequest, ChangePasswordUseCase};
+use crate::application::auth::mfa::{
+    ConfirmTotpEnrollmentUseCase, DisableTotpUseCase, GetMfaStatusUseCase,
+    RegenerateRecoveryCodesUseCase, StartTotpEnrollmentUseCase, TotpCodeRequest,
+};
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
+use crate::infrastructure::repositories::mfa::PostgresMfaRepository;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::secret_cipher::SecretCipher;
+use crate::infrastructure::state::AppState;
+use crate::presentation::dtos::{
+    MfaStatusResource, RecoveryCodesResource, SessionResource, TotpEnrollmentResource,
+};
+use crate::presentation::extractors::{AnyAdministrator, RequirePermission};
+use crate::presentation::sessions;
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// List the active sessions of the authenticated administrator
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/me/sessions",
+    responses(
+        (status = 200, description = "Active sessions", body = JsonApiResponse<Vec<JsonApiResource<SessionResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn list_my_sessions(
+    State(pool): State<DbPool>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::list_sessions(pool, admin.administrator_id, &admin.claims.user_type).await
+}
+
+/// Revoke one of the authenticated administrator's sessions
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/me/sessions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn revoke_my_session(
+    State(pool): State<DbPool>,
+    Path(id): Path<Uuid>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::revoke_session(pool, admin.administrator_id, &admin.claims.user_type, id).await
+}
+
+/// Show the second factor status of the authenticated administrator
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/me/mfa",
+    responses(
+        (status = 200, description = "MFA status", body = JsonApiResponse<JsonApiResource<MfaStatusResource>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn get_my_mfa_status(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case =
+        GetMfaStatusUseCase::new(Arc::new(PostgresMfaRepository::new(pool, totp_cipher)));
+    let status = use_case.execute(admin_id).await?;
+
+    let resource = JsonApiResource::new(
+        "mfa-status",
+        admin_id.to_string(),
+        MfaStatusResource::from(status),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Start TOTP enrollment - returns a new secret and its otpauth URI
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/me/mfa/totp",
+    responses(
+        (status = 200, description = "TOTP secret generated, confirm it with a code", body = JsonApiResponse<JsonApiResource<TotpEnrollmentResource>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "TOTP is already enabled", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn start_totp_enrollment(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+    let issuer = std::env::var("TOTP_ISSUER").unwrap_or_else(|_| "Caxur".to_string());
+
+    let use_case = StartTotpEnrollmentUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresMfaRepository::new(pool, totp_cipher)),
+    )
+    .with_issuer(issuer);
+    let enrollment = use_case.execute(admin_id).await?;
+
+    let resource = JsonApiResource::new(
+        "totp-enrollments",
+        admin_id.to_string(),
+        TotpEnrollmentResource::from(enrollment),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Confirm TOTP enrollment with a code from the authenticator - returns recovery codes
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/me/mfa/totp/confirm",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "TOTP enabled", body = JsonApiResponse<JsonApiResource<RecoveryCodesResource>>),
+        (status = 400, description = "Invalid code or enrollment not started", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "TOTP is already enabled", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn confirm_totp_enrollment(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case =
+        ConfirmTotpEnrollmentUseCase::new(Arc::new(PostgresMfaRepository::new(pool, totp_cipher)));
+    let codes = use_case.execute(admin_id, req).await?;
+
+    let resource = JsonApiResource::new(
+        "recovery-codes",
+        admin_id.to_string(),
+        RecoveryCodesResource::from(codes),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Disable TOTP using a current TOTP or recovery code
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/me/mfa/totp/disable",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "TOTP disabled", body = JsonApiResponse<serde_json::Value>),
+        (status = 400, description = "Invalid code or TOTP not enabled", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn disable_totp(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case = DisableTotpUseCase::new(Arc::new(PostgresMfaRepository::new(pool, totp_cipher)));
+    use_case.execute(admin_id, req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "totpDisabled": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Replace the recovery codes using a current TOTP code
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/me/mfa/recovery-codes",
+    request_body = TotpCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes, the previous ones stop working", body = JsonApiResponse<JsonApiResource<RecoveryCodesResource>>),
+        (status = 400, description = "Invalid code or TOTP not enabled", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn regenerate_recovery_codes(
+    State(pool): State<DbPool>,
+    State(totp_cipher): State<Arc<SecretCipher>>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<TotpCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case = RegenerateRecoveryCodesUseCase::new(Arc::new(PostgresMfaRepository::new(
+        pool,
+        totp_cipher,
+    )));
+    let codes = use_case.execute(admin_id, req).await?;
+
+    let resource = JsonApiResource::new(
+        "recovery-codes",
+        admin_id.to_string(),
+        RecoveryCodesResource::from(codes),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Change the authenticated administrator's password
+///
+/// Requires the current password. All sessions are signed out afterwards,
+/// including the one used for this request.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/me/password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Not an administrator token", body = ErrorResponse),
+        (status = 422, description = "Wrong current password or new password rejected by the policy", body = ErrorResponse),
+        (status = 429, description = "Too many wrong current passwords", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Me"
+)]
+pub async fn change_my_password(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let accounts = PasswordAccounts::Administrators(Arc::new(
+        PostgresAdministratorRepository::new(state.pool.clone()),
+    ));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = ChangePasswordUseCase::new(
+        accounts,
+        state.password_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    )
+    .with_password_policy(state.password_policies.administrators)
+    .with_login_throttle(state.login_throttle);
+
+    let revoked = use_case.execute(admin_id, req).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "passwordChanged": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
@@ -1,5 +1,10 @@
 pub mod administrators;
 pub mod auth;
+pub mod groups;
+pub mod lockouts;
+pub mod me;
 pub mod permissions;
 pub mod roles;
+pub mod sessions;
 pub mod users;
+pub mod webauthn;
@@ -1,14 +1,18 @@
 use crate::application::permissions::list::{ListPermissionsRequest, ListPermissionsUseCase};
 use crate::domain::access_scope::AccessScope;
-use crate::shared::error::AppError;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::presentation::extractors::{PermissionsCacheRead, PermissionsRead, RequirePermission};
+use crate::shared::error::{AppError, ErrorResponse};
 use crate::shared::query::Qs;
 use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
 use axum::{
     Json,
+    extract::State,
     http::{StatusCode, Uri},
     response::IntoResponse,
 };
 use serde::Serialize;
+use std::sync::Arc;
 use utoipa::ToSchema;
 
 #[derive(Serialize, ToSchema)]
@@ -18,6 +22,17 @@ pub struct PermissionResource {
     pub description: String,
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionCacheStatsResource {
+    pub hits: u64,
+    pub misses: u64,
+    /// Share of permission lookups answered without a database query
+    pub hit_ratio: f64,
+    pub invalidations: u64,
+    pub entries: usize,
+}
+
 /// List all available permissions
 #[utoipa::path(
     get,
@@ -25,14 +40,21 @@ pub struct PermissionResource {
     params(ListPermissionsRequest),
     responses(
         (status = 200, description = "List of permissions", body = JsonApiResponse<Vec<JsonApiResource<PermissionResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse)
     ),
-    tag = "Admin / Permission Management"
+    tag = "Admin / Permission Management",
+    security(
+        ("bearer_auth" = [])
+    )
 )]
 pub async fn list_permissions(
+    _admin: RequirePermission<PermissionsRead>,
     uri: Uri,
     Qs(req): Qs<ListPermissionsRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let use_case = ListPermissionsUseCase::new().with_scope(AccessScope::Administrator);
+    let use_case =
+        ListPermissionsUseCase::new().with_scope(req.scope.unwrap_or(AccessScope::Administrator));
 
     // Capture pagination values before moving req
     let page_number = req.page.number;
@@ -79,3 +101,37 @@ pub async fn list_permissions(
         ),
     ))
 }
+
+/// Show how well administrator permissions are served from the cache
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/permissions/cache",
+    responses(
+        (status = 200, description = "Permission cache statistics of this instance", body = JsonApiResponse<JsonApiResource<PermissionCacheStatsResource>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse)
+    ),
+    tag = "Admin / Permission Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn permission_cache_stats(
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<PermissionsCacheRead>,
+) -> Result<impl IntoResponse, AppError> {
+    let stats = permission_cache.stats();
+    let resource = JsonApiResource::new(
+        "permission-cache-stats",
+        "current",
+        PermissionCacheStatsResource {
+            hits: stats.hits,
+            misses: stats.misses,
+            hit_ratio: stats.hit_ratio(),
+            invalidations: stats.invalidations,
+            entries: stats.entries,
+        },
+    );
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
@@ -3,15 +3,23 @@ use crate::application::roles::create::{CreateRoleRequest, CreateRoleUseCase};
 use crate::application::roles::delete::DeleteRoleUseCase;
 use crate::application::roles::detach_permission::DetachPermissionUseCase;
 use crate::application::roles::get::GetRoleUseCase;
-use crate::application::roles::get_permissions::GetRolePermissionsUseCase;
+use crate::application::roles::get_permissions::{GetRolePermissionsUseCase, RolePermissions};
 use crate::application::roles::list::ListRolesUseCase;
+use crate::application::roles::parents::{
+    AttachParentRolesUseCase, DetachParentRolesUseCase, ParentRolesRequest,
+};
 use crate::application::roles::update::{UpdateRoleRequest, UpdateRoleUseCase};
 use crate::domain::access_scope::AccessScope;
 use crate::domain::permissions::Permission;
 use crate::domain::roles::{Role, RoleRepository};
 use crate::infrastructure::db::DbPool;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::infrastructure::repositories::groups::PostgresGroupRepository;
 use crate::infrastructure::repositories::roles::PostgresRoleRepository;
-use crate::presentation::dtos::PermissionDto;
+use crate::presentation::extractors::{
+    RequirePermission, RolesCreate, RolesDelete, RolesPermissionsAttach, RolesPermissionsDetach,
+    RolesPermissionsRead, RolesRead, RolesUpdate,
+};
 use crate::shared::error::{AppError, ErrorResponse};
 use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
 use crate::shared::validation::ValidatedJson;
@@ -33,6 +41,8 @@ pub struct RoleResource {
     pub id: String,
     pub name: String,
     pub description: Option<String>,
+    /// Roles this role inherits permissions from
+    pub parent_ids: Vec<String>,
     #[serde(with = "time::serde::iso8601")]
     #[schema(value_type = String)]
     pub created_at: time::OffsetDateTime,
@@ -47,6 +57,7 @@ impl From<Role> for RoleResource {
             id: role.id.to_string(),
             name: role.name,
             description: role.description,
+            parent_ids: role.parent_ids.iter().map(Uuid::to_string).collect(),
             created_at: role.created_at,
             updated_at: role.updated_at,
         }
@@ -63,6 +74,10 @@ pub struct ListRolesQuery {
     #[serde(default = "default_page_size")]
     #[param(example = 20, minimum = 1, maximum = 100)]
     pub per_page: i64,
+    /// Scope of the listed roles, administrator roles by default
+    pub scope: Option<AccessScope>,
+    /// Only list the roles of this group
+    pub group_id: Option<Uuid>,
 }
 
 /// Create a new role
@@ -81,10 +96,11 @@ pub struct ListRolesQuery {
 )]
 pub async fn create_role(
     State(pool): State<DbPool>,
+    _admin: RequirePermission<RolesCreate>,
     ValidatedJson(req): ValidatedJson<CreateRoleRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let repo = Arc::new(PostgresRoleRepository::new(pool));
-    let use_case = CreateRoleUseCase::new(repo);
+    let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
+    let use_case = CreateRoleUseCase::new(repo, Arc::new(PostgresGroupRepository::new(pool)));
 
     let role = use_case.execute(req).await?;
     let resource = JsonApiResource::new("roles", role.id.to_string(), RoleResource::from(role));
@@ -110,6 +126,7 @@ pub async fn create_role(
 )]
 pub async fn get_role(
     State(pool): State<DbPool>,
+    _admin: RequirePermission<RolesRead>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresRoleRepository::new(pool));
@@ -136,6 +153,7 @@ pub async fn get_role(
 )]
 pub async fn list_roles(
     State(pool): State<DbPool>,
+    _admin: RequirePermission<RolesRead>,
     uri: Uri,
     Query(query): Query<ListRolesQuery>,
 ) -> Result<impl IntoResponse, AppError> {
@@ -143,7 +161,12 @@ pub async fn list_roles(
     let use_case = ListRolesUseCase::new(repo.clone());
 
     let roles = use_case
-        .execute(AccessScope::Administrator, None, query.per_page, query.page)
+        .execute(
+            query.scope.unwrap_or(AccessScope::Administrator),
+            query.group_id,
+            query.per_page,
+            query.page,
+        )
         .await?;
 
     let total = repo.count().await.map_err(AppError::InternalServerError)?;
@@ -196,6 +219,7 @@ pub async fn list_roles(
 )]
 pub async fn update_role(
     State(pool): State<DbPool>,
+    _admin: RequirePermission<RolesUpdate>,
     Path(id): Path<Uuid>,
     ValidatedJson(req): ValidatedJson<UpdateRoleRequest>,
 ) -> Result<impl IntoResponse, AppError> {
@@ -226,12 +250,15 @@ pub async fn update_role(
 )]
 pub async fn delete_role(
     State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<RolesDelete>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresRoleRepository::new(pool));
     let use_case = DeleteRoleUseCase::new(repo);
 
     use_case.execute(id).await?;
+    permission_cache.invalidate_all();
 
     let meta = JsonApiMeta::new().with_extra(json!({ "deleted": true }));
     Ok((
@@ -259,14 +286,17 @@ pub async fn delete_role(
 )]
 pub async fn attach_permission(
     State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<RolesPermissionsAttach>,
     Path(id): Path<Uuid>,
     Json(req): Json<AttachPermissionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresRoleRepository::new(pool));
     let use_case = AttachPermissionUseCase::new(repo);
 
-    let permissions: Vec<Permission> = req.permissions.into_iter().map(|p| p.into()).collect();
-    use_case.execute(id, permissions).await?;
+    use_case.execute(id, req.permissions).await?;
+    // Any holder of the role may have gained permissions
+    permission_cache.invalidate_all();
 
     let meta = JsonApiMeta::new().with_extra(json!({ "attached": true }));
     Ok((
@@ -294,14 +324,16 @@ pub async fn attach_permission(
 )]
 pub async fn detach_permission(
     State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<RolesPermissionsDetach>,
     Path(id): Path<Uuid>,
     Json(req): Json<DetachPermissionRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresRoleRepository::new(pool));
     let use_case = DetachPermissionUseCase::new(repo);
 
-    let permissions: Vec<Permission> = req.permissions.into_iter().map(|p| p.into()).collect();
-    use_case.execute(id, permissions).await?;
+    use_case.execute(id, req.permissions).await?;
+    permission_cache.invalidate_all();
 
     let meta = JsonApiMeta::new().with_extra(json!({ "detached": true }));
     Ok((
@@ -312,14 +344,14 @@ pub async fn detach_permission(
 
 #[derive(Deserialize, ToSchema)]
 pub struct AttachPermissionRequest {
-    #[schema(example = json!(["administrator_management"]))]
-    pub permissions: Vec<PermissionDto>,
+    #[schema(example = json!(["users.read"]))]
+    pub permissions: Vec<Permission>,
 }
 
 #[derive(Deserialize, ToSchema)]
 pub struct DetachPermissionRequest {
-    #[schema(example = json!(["administrator_management"]))]
-    pub permissions: Vec<PermissionDto>,
+    #[schema(example = json!(["users.read"]))]
+    pub permissions: Vec<Permission>,
 }
 
 /// Get all permissions for a role
@@ -330,7 +362,7 @@ pub struct DetachPermissionRequest {
         ("id" = Uuid, Path, description = "Role ID")
     ),
     responses(
-        (status = 200, description = "List of permissions", body = JsonApiResponse<Vec<PermissionDto>>),
+        (status = 200, description = "Direct and inherited permissions", body = JsonApiResponse<RolePermissions>),
         (status = 404, description = "Role not found", body = ErrorResponse)
     ),
     tag = "Admin / Role Management",
@@ -340,6 +372,7 @@ pub struct DetachPermissionRequest {
 )]
 pub async fn get_role_permissions(
     State(pool): State<DbPool>,
+    _admin: RequirePermission<RolesPermissionsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresRoleRepository::new(pool));
@@ -347,7 +380,80 @@ pub async fn get_role_permissions(
 
     let permissions = use_case.execute(id).await?;
 
-    let permissions: Vec<PermissionDto> = permissions.into_iter().map(|p| p.into()).collect();
-
     Ok((StatusCode::OK, Json(JsonApiResponse::new(permissions))))
 }
+
+/// Make a role inherit the permissions of other roles
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/roles/{id}/parents",
+    params(
+        ("id" = Uuid, Path, description = "Role ID")
+    ),
+    request_body = ParentRolesRequest,
+    responses(
+        (status = 200, description = "Parent roles attached successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 404, description = "Role not found", body = ErrorResponse),
+        (status = 422, description = "Unknown parent, other scope or inheritance cycle", body = ErrorResponse)
+    ),
+    tag = "Admin / Role Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn attach_parents(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<RolesPermissionsAttach>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(req): ValidatedJson<ParentRolesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresRoleRepository::new(pool));
+    let use_case = AttachParentRolesUseCase::new(repo);
+
+    use_case.execute(id, req.parent_ids).await?;
+    permission_cache.invalidate_all();
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "attached": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Stop a role from inheriting the permissions of other roles
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/roles/{id}/parents",
+    params(
+        ("id" = Uuid, Path, description = "Role ID")
+    ),
+    request_body = ParentRolesRequest,
+    responses(
+        (status = 200, description = "Parent roles detached successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 404, description = "Role not found", body = ErrorResponse)
+    ),
+    tag = "Admin / Role Management",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn detach_parents(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    _admin: RequirePermission<RolesPermissionsDetach>,
+    Path(id): Path<Uuid>,
+    ValidatedJson(req): ValidatedJson<ParentRolesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = Arc::new(PostgresRoleRepository::new(pool));
+    let use_case = DetachParentRolesUseCase::new(repo);
+
+    use_case.execute(id, req.parent_ids).await?;
+    permission_cache.invalidate_all();
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "detached": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
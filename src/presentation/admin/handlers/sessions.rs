@@ -0,0 +1,114 @@
+use crate::infrastructure::db::DbPool;
+use crate::presentation::dtos::SessionResource;
+use crate::presentation::extractors::{
+    AdministratorsSessionsRead, AdministratorsSessionsRevoke, RequirePermission, UsersSessionsRead,
+    UsersSessionsRevoke,
+};
+use crate::presentation::sessions;
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiResource, JsonApiResponse};
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+/// List the active sessions of a user
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/users/{id}/sessions",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Active sessions", body = JsonApiResponse<Vec<JsonApiResource<SessionResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / User Management"
+)]
+pub async fn list_user_sessions(
+    State(pool): State<DbPool>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<UsersSessionsRead>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::list_sessions(pool, id, "user").await
+}
+
+/// Revoke a session of a user
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/users/{id}/sessions/{session_id}",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("session_id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / User Management"
+)]
+pub async fn revoke_user_session(
+    State(pool): State<DbPool>,
+    Path((id, session_id)): Path<(Uuid, Uuid)>,
+    _admin: RequirePermission<UsersSessionsRevoke>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::revoke_session(pool, id, "user", session_id).await
+}
+
+/// List the active sessions of an administrator
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/administrators/{id}/sessions",
+    params(
+        ("id" = Uuid, Path, description = "Administrator ID")
+    ),
+    responses(
+        (status = 200, description = "Active sessions", body = JsonApiResponse<Vec<JsonApiResource<SessionResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Administrator Management"
+)]
+pub async fn list_admin_sessions(
+    State(pool): State<DbPool>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<AdministratorsSessionsRead>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::list_sessions(pool, id, "admin").await
+}
+
+/// Revoke a session of an administrator
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/administrators/{id}/sessions/{session_id}",
+    params(
+        ("id" = Uuid, Path, description = "Administrator ID"),
+        ("session_id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Administrator Management"
+)]
+pub async fn revoke_admin_session(
+    State(pool): State<DbPool>,
+    Path((id, session_id)): Path<(Uuid, Uuid)>,
+    _admin: RequirePermission<AdministratorsSessionsRevoke>,
+) -> Result<impl IntoResponse, AppError> {
+    sessions::revoke_session(pool, id, "admin", session_id).await
+}
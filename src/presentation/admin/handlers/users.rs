@@ -1,18 +1,29 @@
+use crate::application::administrators::roles::DetachRolesRequest;
 use crate::application::users::list::{ListUsersRequest, ListUsersUseCase};
+use crate::application::users::roles::{AttachUserRoles, AttachUserRolesRequest, DetachUserRoles};
+use crate::domain::access_scope::AccessScope;
 use crate::domain::users::UserRepository;
 use crate::infrastructure::db::DbPool;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::infrastructure::repositories::groups::PostgresGroupRepository;
+use crate::infrastructure::repositories::roles::PostgresRoleRepository;
 use crate::infrastructure::repositories::users::PostgresUserRepository;
 use crate::presentation::dtos::UserResource;
-use crate::presentation::extractors::AuthUser;
+use crate::presentation::extractors::{
+    RequirePermission, UsersRead, UsersRolesAttach, UsersRolesDetach,
+};
 use crate::shared::error::{AppError, ErrorResponse};
 use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::{StatusCode, Uri},
     response::IntoResponse,
 };
+use serde_json::json;
 use std::sync::Arc;
+use uuid::Uuid;
 
 /// List all users with pagination
 #[utoipa::path(
@@ -73,7 +84,7 @@ pub async fn list_users(
     State(pool): State<DbPool>,
     uri: Uri,
     Query(req): Query<ListUsersRequest>,
-    _auth: AuthUser,
+    _admin: RequirePermission<UsersRead>,
 ) -> Result<impl IntoResponse, AppError> {
     let repo = Arc::new(PostgresUserRepository::new(pool));
     let use_case = ListUsersUseCase::new(repo.clone());
@@ -116,3 +127,79 @@ pub async fn list_users(
         ),
     ))
 }
+
+/// Attach user roles to a user
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/users/{id}/roles",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = AttachUserRolesRequest,
+    responses(
+        (status = 200, description = "Roles attached successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "User not found", body = ErrorResponse),
+        (status = 422, description = "A role does not exist or is not a user role", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / User Management"
+)]
+pub async fn attach_user_roles(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<UsersRolesAttach>,
+    ValidatedJson(req): ValidatedJson<AttachUserRolesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = AttachUserRoles::new(
+        Arc::new(PostgresUserRepository::new(pool.clone())),
+        Arc::new(PostgresRoleRepository::new(pool.clone())),
+        Arc::new(PostgresGroupRepository::new(pool)),
+    );
+
+    use_case.execute(id, req.role_ids).await?;
+    permission_cache.invalidate(AccessScope::User, id);
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
+
+/// Detach roles from a user
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/users/{id}/roles",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = DetachRolesRequest,
+    responses(
+        (status = 200, description = "Roles detached successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / User Management"
+)]
+pub async fn detach_user_roles(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    Path(id): Path<Uuid>,
+    _admin: RequirePermission<UsersRolesDetach>,
+    ValidatedJson(req): ValidatedJson<DetachRolesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = DetachUserRoles::new(Arc::new(PostgresUserRepository::new(pool)));
+
+    use_case.execute(id, req.role_ids).await?;
+    permission_cache.invalidate(AccessScope::User, id);
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
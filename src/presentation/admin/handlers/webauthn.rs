@@ -0,0 +1,271 @@
+use crate::application::auth::passkeys::{
+    DeletePasskeyUseCase, FinishPasskeyLoginUseCase, FinishPasskeyRegistrationUseCase,
+    ListPasskeysUseCase, PasskeyLoginOptionsRequest, PasskeyLoginRequest,
+    PasskeyRegistrationRequest, StartPasskeyLoginUseCase, StartPasskeyRegistrationUseCase,
+};
+use crate::application::auth::webauthn::{
+    DEFAULT_ORIGIN, DEFAULT_RP_ID, DEFAULT_RP_NAME, RelyingParty,
+};
+use crate::infrastructure::repositories::administrators::PostgresAdministratorRepository;
+use crate::infrastructure::repositories::one_time_tokens::PostgresOneTimeTokenRepository;
+use crate::infrastructure::repositories::passkeys::PostgresPasskeyRepository;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::state::AppState;
+use crate::presentation::dtos::{
+    AuthTokenResource, PasskeyCreationOptionsResource, PasskeyRequestOptionsResource,
+    PasskeyResource,
+};
+use crate::presentation::extractors::{AnyAdministrator, ClientInfo, RequirePermission};
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Relying party passkeys are bound to, from `WEBAUTHN_*` environment variables
+fn relying_party() -> RelyingParty {
+    RelyingParty::new(
+        std::env::var("WEBAUTHN_RP_ID").unwrap_or_else(|_| DEFAULT_RP_ID.to_string()),
+        std::env::var("WEBAUTHN_RP_NAME").unwrap_or_else(|_| DEFAULT_RP_NAME.to_string()),
+        std::env::var("WEBAUTHN_ORIGIN").unwrap_or_else(|_| DEFAULT_ORIGIN.to_string()),
+    )
+}
+
+/// Start passkey registration - returns options for `navigator.credentials.create()`
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/webauthn/register/options",
+    responses(
+        (status = 200, description = "Credential creation options", body = JsonApiResponse<JsonApiResource<PasskeyCreationOptionsResource>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Not an administrator", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn passkey_registration_options(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let pool = state.pool;
+    let use_case = StartPasskeyRegistrationUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresPasskeyRepository::new(pool.clone())),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+        relying_party(),
+    );
+    let options = use_case.execute(admin_id).await?;
+
+    let resource = JsonApiResource::new(
+        "passkey-creation-options",
+        admin_id.to_string(),
+        PasskeyCreationOptionsResource::from(options),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Finish passkey registration with the authenticator's attestation
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/webauthn/register",
+    request_body = PasskeyRegistrationRequest,
+    responses(
+        (status = 201, description = "Passkey registered", body = JsonApiResponse<JsonApiResource<PasskeyResource>>),
+        (status = 400, description = "Invalid challenge or attestation", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Passkey is already registered", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn register_passkey(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+    ValidatedJson(req): ValidatedJson<PasskeyRegistrationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let pool = state.pool;
+    let use_case = FinishPasskeyRegistrationUseCase::new(
+        Arc::new(PostgresPasskeyRepository::new(pool.clone())),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+        relying_party(),
+    );
+    let passkey = use_case.execute(admin_id, req).await?;
+
+    let resource = JsonApiResource::new(
+        "passkeys",
+        passkey.id.to_string(),
+        PasskeyResource::from(passkey),
+    );
+    Ok((StatusCode::CREATED, Json(JsonApiResponse::new(resource))))
+}
+
+/// Start a passkey login - returns options for `navigator.credentials.get()`
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/webauthn/login/options",
+    request_body = PasskeyLoginOptionsRequest,
+    responses(
+        (status = 200, description = "Credential request options; the response is the same for unknown emails", body = JsonApiResponse<JsonApiResource<PasskeyRequestOptionsResource>>),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn passkey_login_options(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<PasskeyLoginOptionsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let pool = state.pool;
+    let use_case = StartPasskeyLoginUseCase::new(
+        Arc::new(PostgresAdministratorRepository::new(pool.clone())),
+        Arc::new(PostgresPasskeyRepository::new(pool.clone())),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+        relying_party(),
+    );
+    let options = use_case.execute(req).await?;
+
+    let resource = JsonApiResource::new(
+        "passkey-request-options",
+        "challenge",
+        PasskeyRequestOptionsResource::from(options),
+    );
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Passkey login - exchanges a signed assertion for tokens.
+///
+/// Passkeys require user verification, so no TOTP challenge follows.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/auth/webauthn/login",
+    request_body = PasskeyLoginRequest,
+    responses(
+        (status = 200, description = "Passkey login successful", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
+        (status = 400, description = "Malformed assertion", body = ErrorResponse),
+        (status = 401, description = "Invalid challenge or passkey", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn passkey_login(
+    State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
+    ValidatedJson(req): ValidatedJson<PasskeyLoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "900".to_string())
+        .parse::<i64>()
+        .unwrap_or(900);
+    let refresh_token_expiry = std::env::var("JWT_REFRESH_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "604800".to_string())
+        .parse::<i64>()
+        .unwrap_or(604800);
+
+    let pool = state.pool;
+    let use_case = FinishPasskeyLoginUseCase::new(
+        Arc::new(PostgresPasskeyRepository::new(pool.clone())),
+        Arc::new(PostgresOneTimeTokenRepository::new(pool.clone())),
+        Arc::new(PostgresRefreshTokenRepository::new(pool)),
+        state.auth_service,
+        relying_party(),
+        access_token_expiry,
+        refresh_token_expiry,
+    )
+    .with_session_metadata(session_metadata);
+
+    let response = use_case.execute(req).await?;
+    let resource =
+        JsonApiResource::new("auth-tokens", "session", AuthTokenResource::from(response));
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// List the passkeys of the authenticated administrator
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/auth/webauthn/credentials",
+    responses(
+        (status = 200, description = "Registered passkeys", body = JsonApiResponse<Vec<JsonApiResource<PasskeyResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn list_passkeys(
+    State(state): State<AppState>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case = ListPasskeysUseCase::new(Arc::new(PostgresPasskeyRepository::new(state.pool)));
+    let passkeys = use_case.execute(admin_id).await?;
+    let total = passkeys.len() as i64;
+
+    let resources: Vec<JsonApiResource<PasskeyResource>> = passkeys
+        .into_iter()
+        .map(|passkey| {
+            JsonApiResource::new(
+                "passkeys",
+                passkey.id.to_string(),
+                PasskeyResource::from(passkey),
+            )
+        })
+        .collect();
+
+    let meta = JsonApiMeta::new().with_total(total);
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(resources).with_meta(meta)),
+    ))
+}
+
+/// Remove a passkey of the authenticated administrator
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/auth/webauthn/credentials/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Passkey ID")
+    ),
+    responses(
+        (status = 200, description = "Passkey removed", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Passkey not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Admin / Auth"
+)]
+pub async fn delete_passkey(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    admin: RequirePermission<AnyAdministrator>,
+) -> Result<impl IntoResponse, AppError> {
+    let admin_id = admin.administrator_id;
+
+    let use_case = DeletePasskeyUseCase::new(Arc::new(PostgresPasskeyRepository::new(state.pool)));
+    use_case.execute(admin_id, id).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "deleted": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
@@ -1,7 +1,7 @@
-use crate::presentation::admin::handlers::administrators;
+use crate::presentation::admin::handlers::{administrators, lockouts, sessions};
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{delete, get, post},
 };
 
 use crate::infrastructure::state::AppState;
@@ -18,6 +18,14 @@ pub fn routes() -> Router<AppState> {
         )
         .route(
             "/{id}/roles",
-            post(administrators::attach_admin_roles).delete(administrators::detach_admin_roles),
+            get(administrators::list_admin_roles)
+                .post(administrators::attach_admin_roles)
+                .delete(administrators::detach_admin_roles),
         )
+        .route("/{id}/sessions", get(sessions::list_admin_sessions))
+        .route(
+            "/{id}/sessions/{session_id}",
+            delete(sessions::revoke_admin_session),
+        )
+        .route("/{id}/unlock", post(lockouts::unlock_admin))
 }
@@ -1,9 +1,41 @@
-use crate::presentation::admin::handlers::auth;
-use axum::{Router, routing::post};
+use crate::presentation::admin::handlers::{auth, webauthn};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
 
 use crate::infrastructure::state::AppState;
 
 /// Admin Auth routes
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/login", post(auth::admin_login))
+    Router::new()
+        .route("/login", post(auth::admin_login))
+        .route("/mfa/verify", post(auth::admin_verify_mfa))
+        .route(
+            "/mfa/enroll",
+            post(auth::admin_start_required_mfa_enrollment),
+        )
+        .route(
+            "/mfa/enroll/confirm",
+            post(auth::admin_confirm_required_mfa_enrollment),
+        )
+        .route("/logout", post(auth::admin_logout))
+        .route("/logout-all", post(auth::admin_logout_all))
+        .route("/forgot-password", post(auth::admin_forgot_password))
+        .route("/reset-password", post(auth::admin_reset_password))
+        .route(
+            "/webauthn/register/options",
+            post(webauthn::passkey_registration_options),
+        )
+        .route("/webauthn/register", post(webauthn::register_passkey))
+        .route(
+            "/webauthn/login/options",
+            post(webauthn::passkey_login_options),
+        )
+        .route("/webauthn/login", post(webauthn::passkey_login))
+        .route("/webauthn/credentials", get(webauthn::list_passkeys))
+        .route(
+            "/webauthn/credentials/{id}",
+            delete(webauthn::delete_passkey),
+        )
 }
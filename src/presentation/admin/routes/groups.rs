@@ -0,0 +1,25 @@
+use crate::presentation::admin::handlers::groups;
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::infrastructure::state::AppState;
+
+/// Group routes - handles group CRUD operations and memberships
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(groups::create_group).get(groups::list_groups))
+        .route(
+            "/{id}",
+            get(groups::get_group)
+                .put(groups::update_group)
+                .delete(groups::delete_group),
+        )
+        .route(
+            "/{id}/members",
+            get(groups::list_group_members)
+                .post(groups::add_group_members)
+                .delete(groups::remove_group_members),
+        )
+}
@@ -0,0 +1,20 @@
+use crate::presentation::admin::handlers::me;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::infrastructure::state::AppState;
+
+/// Admin routes for the authenticated administrator
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/sessions", get(me::list_my_sessions))
+        .route("/sessions/{id}", delete(me::revoke_my_session))
+        .route("/password", post(me::change_my_password))
+        .route("/mfa", get(me::get_my_mfa_status))
+        .route("/mfa/totp", post(me::start_totp_enrollment))
+        .route("/mfa/totp/confirm", post(me::confirm_totp_enrollment))
+        .route("/mfa/totp/disable", post(me::disable_totp))
+        .route("/mfa/recovery-codes", post(me::regenerate_recovery_codes))
+}
@@ -1,5 +1,7 @@
 pub mod administrators;
 pub mod auth;
+pub mod groups;
+pub mod me;
 pub mod permissions;
 pub mod roles;
 pub mod users;
@@ -7,11 +9,13 @@ pub mod users;
 use crate::infrastructure::state::AppState;
 use axum::Router;
 
-pub fn routes(state: AppState) -> Router<AppState> {
+pub fn routes() -> Router<AppState> {
     Router::new()
         .nest("/administrators", administrators::routes())
-        .nest("/roles", roles::routes(state))
+        .nest("/roles", roles::routes())
+        .nest("/groups", groups::routes())
         .nest("/permissions", permissions::routes())
         .nest("/users", users::routes())
         .nest("/auth", auth::routes())
+        .nest("/me", me::routes())
 }
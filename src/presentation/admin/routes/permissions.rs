@@ -5,5 +5,7 @@ use crate::infrastructure::state::AppState;
 
 /// Permission routes - handles permission listing
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/", get(permissions::list_permissions))
+    Router::new()
+        .route("/", get(permissions::list_permissions))
+        .route("/cache", get(permissions::permission_cache_stats))
 }
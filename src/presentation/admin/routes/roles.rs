@@ -1,15 +1,13 @@
-use crate::domain::permissions::Permission;
 use crate::presentation::admin::handlers::roles;
-use crate::presentation::middleware::auth::{RequiredPermissions, check_permissions};
 use axum::{
-    Extension, Router, middleware,
+    Router,
     routing::{get, post},
 };
 
 use crate::infrastructure::state::AppState;
 
 /// Role routes - handles role CRUD operations and permission management
-pub fn routes(state: AppState) -> Router<AppState> {
+pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/", post(roles::create_role).get(roles::list_roles))
         .route(
@@ -24,9 +22,8 @@ pub fn routes(state: AppState) -> Router<AppState> {
                 .get(roles::get_role_permissions)
                 .delete(roles::detach_permission),
         )
-        .route_layer(middleware::from_fn_with_state(state, check_permissions))
-        .route_layer(Extension(RequiredPermissions {
-            user_type: "admin",
-            permissions: vec![Permission::RoleManagement],
-        }))
+        .route(
+            "/{id}/parents",
+            post(roles::attach_parents).delete(roles::detach_parents),
+        )
 }
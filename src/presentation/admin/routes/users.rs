@@ -1,9 +1,23 @@
-use crate::presentation::admin::handlers::users;
-use axum::{Router, routing::get};
+use crate::presentation::admin::handlers::{lockouts, sessions, users};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
 
 use crate::infrastructure::state::AppState;
 
 /// Admin User Management routes
 pub fn routes() -> Router<AppState> {
-    Router::new().route("/", get(users::list_users))
+    Router::new()
+        .route("/", get(users::list_users))
+        .route(
+            "/{id}/roles",
+            post(users::attach_user_roles).delete(users::detach_user_roles),
+        )
+        .route("/{id}/sessions", get(sessions::list_user_sessions))
+        .route(
+            "/{id}/sessions/{session_id}",
+            delete(sessions::revoke_user_session),
+        )
+        .route("/{id}/unlock", post(lockouts::unlock_user))
 }
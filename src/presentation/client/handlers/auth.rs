@@ -1,13 +1,27 @@
+use crate::application::auth::email_verification::{
+    EmailVerificationSender, ResendVerificationEmailUseCase, ResendVerificationRequest,
+    VerifyEmailRequest, VerifyEmailUseCase,
+};
 use crate::application::auth::login::{LoginRequest, LoginUseCase};
+use crate::application::auth::logout::{LogoutAllUseCase, LogoutRequest, LogoutUseCase};
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::application::auth::password_reset::{
+    ForgotPasswordRequest, RequestPasswordResetUseCase, ResetPasswordRequest, ResetPasswordUseCase,
+};
 use crate::application::auth::refresh::{RefreshTokenRequest, RefreshTokenUseCase};
+use crate::domain::notifications::NotificationService;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::repositories::one_time_tokens::PostgresOneTimeTokenRepository;
 use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
 use crate::infrastructure::repositories::users::PostgresUserRepository;
 use crate::infrastructure::state::AppState;
 use crate::presentation::dtos::AuthTokenResource;
+use crate::presentation::extractors::{AuthUser, ClientInfo};
 use crate::shared::error::{AppError, ErrorResponse};
-use crate::shared::response::{JsonApiResource, JsonApiResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
 use crate::shared::validation::ValidatedJson;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde_json::json;
 use std::sync::Arc;
 
 /// Login handler
@@ -18,12 +32,15 @@ use std::sync::Arc;
     responses(
         (status = 200, description = "Login successful", body = JsonApiResponse<JsonApiResource<AuthTokenResource>>),
         (status = 401, description = "Invalid credentials", body = ErrorResponse),
+        (status = 429, description = "Too many failed attempts (`login_throttled` or `account_locked`)", body = ErrorResponse),
+        (status = 403, description = "Email address not verified", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     ),
     tag = "Client / Auth"
 )]
 pub async fn login(
     State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
     ValidatedJson(req): ValidatedJson<LoginRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let auth_service = state.auth_service;
@@ -31,7 +48,7 @@ pub async fn login(
 
     let user_repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
-    let password_service = Arc::new(crate::infrastructure::password::PasswordService::new());
+    let password_service = state.password_service;
 
     let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
         .unwrap_or_else(|_| "900".to_string())
@@ -41,6 +58,9 @@ pub async fn login(
         .unwrap_or_else(|_| "604800".to_string())
         .parse::<i64>()
         .unwrap_or(604800);
+    let require_verified_email = std::env::var("REQUIRE_VERIFIED_EMAIL")
+        .map(|v| v == "true")
+        .unwrap_or(false);
 
     let use_case = LoginUseCase::new(
         user_repo,
@@ -49,7 +69,10 @@ pub async fn login(
         password_service,
         access_token_expiry,
         refresh_token_expiry,
-    );
+    )
+    .with_session_metadata(session_metadata)
+    .with_require_verified_email(require_verified_email)
+    .with_login_throttle(state.login_throttle);
 
     let response = use_case.execute(req).await?;
     let resource =
@@ -72,6 +95,7 @@ pub async fn login(
 )]
 pub async fn refresh_token(
     State(state): State<AppState>,
+    ClientInfo(session_metadata): ClientInfo,
     ValidatedJson(req): ValidatedJson<RefreshTokenRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     let access_token_expiry = std::env::var("JWT_ACCESS_TOKEN_EXPIRY")
@@ -92,7 +116,8 @@ pub async fn refresh_token(
         auth_service,
         access_token_expiry,
         refresh_token_expiry,
-    );
+    )
+    .with_session_metadata(session_metadata);
 
     let response = use_case.execute(req).await?;
     let resource =
@@ -100,3 +125,215 @@ pub async fn refresh_token(
 
     Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
 }
+
+/// Logout handler - revokes the presented refresh token and the current access token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    request_body = LogoutRequest,
+    responses(
+        (status = 200, description = "Logged out successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized or invalid refresh token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn logout(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidatedJson(req): ValidatedJson<LogoutRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = LogoutUseCase::new(refresh_token_repo, state.access_token_denylist);
+
+    use_case.execute(&auth.claims, req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "loggedOut": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Logout-all handler - revokes every token of the authenticated user
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout-all",
+    responses(
+        (status = 200, description = "All sessions revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn logout_all(
+    State(state): State<AppState>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = LogoutAllUseCase::new(refresh_token_repo, state.access_token_denylist);
+
+    let revoked = use_case.execute(&auth.claims).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "loggedOut": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Forgot-password handler - sends a password reset token to the account's email
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/forgot-password",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset requested; the response is the same for unknown emails", body = JsonApiResponse<serde_json::Value>),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn forgot_password(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let reset_token_expiry = std::env::var("PASSWORD_RESET_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse::<i64>()
+        .unwrap_or(3600);
+
+    let accounts =
+        PasswordAccounts::Users(Arc::new(PostgresUserRepository::new(state.pool.clone())));
+    let token_repo = Arc::new(PostgresOneTimeTokenRepository::new(state.pool));
+    let use_case =
+        RequestPasswordResetUseCase::new(accounts, token_repo, state.notification_service)
+            .with_token_ttl(time::Duration::seconds(reset_token_expiry));
+
+    use_case.execute(req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "resetRequested": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Reset-password handler - sets a new password using a reset token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/reset-password",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset, all sessions revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 400, description = "Invalid or expired reset token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn reset_password(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<ResetPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let accounts =
+        PasswordAccounts::Users(Arc::new(PostgresUserRepository::new(state.pool.clone())));
+    let token_repo = Arc::new(PostgresOneTimeTokenRepository::new(state.pool.clone()));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let password_service = state.password_service;
+    let use_case = ResetPasswordUseCase::new(
+        accounts,
+        token_repo,
+        password_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    )
+    .with_password_policy(state.password_policies.users);
+
+    let revoked = use_case.execute(req).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "passwordReset": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Build the sender used to deliver email verification tokens
+pub(crate) fn email_verification_sender(
+    pool: DbPool,
+    notification_service: Arc<dyn NotificationService>,
+) -> EmailVerificationSender {
+    let verification_token_expiry = std::env::var("EMAIL_VERIFICATION_TOKEN_EXPIRY")
+        .unwrap_or_else(|_| "86400".to_string())
+        .parse::<i64>()
+        .unwrap_or(86400);
+
+    EmailVerificationSender::new(
+        Arc::new(PostgresOneTimeTokenRepository::new(pool)),
+        notification_service,
+    )
+    .with_token_ttl(time::Duration::seconds(verification_token_expiry))
+}
+
+/// Verify-email handler - confirms ownership of the email address using a verification token
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/verify-email",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email address verified", body = JsonApiResponse<serde_json::Value>),
+        (status = 400, description = "Invalid or expired verification token", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn verify_email(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_repo = Arc::new(PostgresUserRepository::new(state.pool.clone()));
+    let token_repo = Arc::new(PostgresOneTimeTokenRepository::new(state.pool));
+    let use_case = VerifyEmailUseCase::new(user_repo, token_repo);
+
+    use_case.execute(req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "emailVerified": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
+
+/// Resend-verification handler - sends a new verification token to an unverified account
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/verify-email/resend",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Verification requested; the response is the same for unknown or verified emails", body = JsonApiResponse<serde_json::Value>),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Auth"
+)]
+pub async fn resend_verification(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<ResendVerificationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_repo = Arc::new(PostgresUserRepository::new(state.pool.clone()));
+    let sender = email_verification_sender(state.pool, state.notification_service);
+    let use_case = ResendVerificationEmailUseCase::new(user_repo, sender);
+
+    use_case.execute(req).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "verificationRequested": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
@@ -0,0 +1,226 @@
+use crate::application::groups::get::GetGroupUseCase;
+use crate::application::groups::members::{
+    AddGroupMembers, GroupMembersRequest, ListGroupMembers, RemoveGroupMembers,
+};
+use crate::application::groups::update::{UpdateGroupRequest, UpdateGroupUseCase};
+use crate::domain::access_scope::AccessScope;
+use crate::domain::groups::GroupRepository;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::permission_cache::PermissionCache;
+use crate::infrastructure::repositories::groups::PostgresGroupRepository;
+use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::presentation::dtos::{GroupResource, UserResource};
+use crate::presentation::extractors::{
+    AnyMember, ClientUser, GroupPermission, GroupsMembersAdd, GroupsMembersRead,
+    GroupsMembersRemove, GroupsUpdate,
+};
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde_json::json;
+use std::sync::Arc;
+
+/// List the groups of the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/v1/groups",
+    responses(
+        (status = 200, description = "Groups the user is a member of", body = JsonApiResponse<Vec<JsonApiResource<GroupResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_my_groups(
+    State(pool): State<DbPool>,
+    user: ClientUser,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = PostgresGroupRepository::new(pool);
+
+    let groups = repo.find_by_member(user.user_id).await?;
+    let resources: Vec<JsonApiResource<GroupResource>> = groups
+        .into_iter()
+        .map(|group| {
+            JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group))
+        })
+        .collect();
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resources))))
+}
+
+/// Get a group the user is a member of
+#[utoipa::path(
+    get,
+    path = "/api/v1/groups/{group_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group ID")
+    ),
+    responses(
+        (status = 200, description = "Group found", body = JsonApiResponse<JsonApiResource<GroupResource>>),
+        (status = 404, description = "Group not found or not a member", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_group(
+    State(pool): State<DbPool>,
+    member: GroupPermission<AnyMember>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = GetGroupUseCase::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    let group = use_case.execute(member.group_id).await?;
+    let resource = JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group));
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// Update a group, requires `groups.update` within the group
+#[utoipa::path(
+    put,
+    path = "/api/v1/groups/{group_id}",
+    params(
+        ("group_id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = UpdateGroupRequest,
+    responses(
+        (status = 200, description = "Group updated successfully", body = JsonApiResponse<JsonApiResource<GroupResource>>),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Group not found or not a member", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_group(
+    State(pool): State<DbPool>,
+    member: GroupPermission<GroupsUpdate>,
+    ValidatedJson(req): ValidatedJson<UpdateGroupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = UpdateGroupUseCase::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    let group = use_case.execute(member.group_id, req).await?;
+    let resource = JsonApiResource::new("groups", group.id.to_string(), GroupResource::from(group));
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resource))))
+}
+
+/// List the members of a group, requires `groups.members.read` within the group
+#[utoipa::path(
+    get,
+    path = "/api/v1/groups/{group_id}/members",
+    params(
+        ("group_id" = Uuid, Path, description = "Group ID")
+    ),
+    responses(
+        (status = 200, description = "List of members", body = JsonApiResponse<Vec<JsonApiResource<UserResource>>>),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Group not found or not a member", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_members(
+    State(pool): State<DbPool>,
+    member: GroupPermission<GroupsMembersRead>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = ListGroupMembers::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    let members = use_case.execute(member.group_id).await?;
+    let resources: Vec<JsonApiResource<UserResource>> = members
+        .into_iter()
+        .map(|user| JsonApiResource::new("users", user.id.to_string(), UserResource::from(user)))
+        .collect();
+
+    Ok((StatusCode::OK, Json(JsonApiResponse::new(resources))))
+}
+
+/// Add users to a group, requires `groups.members.add` within the group
+#[utoipa::path(
+    post,
+    path = "/api/v1/groups/{group_id}/members",
+    params(
+        ("group_id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = GroupMembersRequest,
+    responses(
+        (status = 200, description = "Members added successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Group not found or not a member", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn add_members(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    member: GroupPermission<GroupsMembersAdd>,
+    ValidatedJson(req): ValidatedJson<GroupMembersRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = AddGroupMembers::new(
+        Arc::new(PostgresGroupRepository::new(pool.clone())),
+        Arc::new(PostgresUserRepository::new(pool)),
+    );
+
+    use_case
+        .execute(member.group_id, req.user_ids.clone())
+        .await?;
+    for user_id in req.user_ids {
+        permission_cache.invalidate(AccessScope::User, user_id);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
+
+/// Remove users from a group, requires `groups.members.remove` within the group
+#[utoipa::path(
+    delete,
+    path = "/api/v1/groups/{group_id}/members",
+    params(
+        ("group_id" = Uuid, Path, description = "Group ID")
+    ),
+    request_body = GroupMembersRequest,
+    responses(
+        (status = 200, description = "Members removed successfully", body = JsonApiResponse<serde_json::Value>),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Group not found or not a member", body = ErrorResponse)
+    ),
+    tag = "Client / Group",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn remove_members(
+    State(pool): State<DbPool>,
+    State(permission_cache): State<Arc<PermissionCache>>,
+    member: GroupPermission<GroupsMembersRemove>,
+    ValidatedJson(req): ValidatedJson<GroupMembersRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let use_case = RemoveGroupMembers::new(Arc::new(PostgresGroupRepository::new(pool)));
+
+    use_case
+        .execute(member.group_id, req.user_ids.clone())
+        .await?;
+    for user_id in req.user_ids {
+        permission_cache.invalidate(AccessScope::User, user_id);
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!({ "success": true }))),
+    ))
+}
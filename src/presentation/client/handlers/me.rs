@@ -0,0 +1,133 @@
+use crate::application::auth::change_password::{ChangePasswordRequest, ChangePasswordUseCase};
+use crate::application::auth::password_accounts::PasswordAccounts;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::infrastructure::state::AppState;
+use crate::presentation::dtos::SessionResource;
+use crate::presentation::extractors::AuthUser;
+use crate::presentation::sessions;
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use crate::shared::validation::ValidatedJson;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// List the active sessions of the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/sessions",
+    responses(
+        (status = 200, description = "Active sessions", body = JsonApiResponse<Vec<JsonApiResource<SessionResource>>>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Client / Me"
+)]
+pub async fn list_sessions(
+    State(pool): State<DbPool>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = auth
+        .claims
+        .user_id()
+        .map_err(AppError::InternalServerError)?;
+
+    sessions::list_sessions(pool, user_id, &auth.claims.user_type).await
+}
+
+/// Revoke one of the authenticated user's sessions
+#[utoipa::path(
+    delete,
+    path = "/api/v1/me/sessions/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Client / Me"
+)]
+pub async fn revoke_session(
+    State(pool): State<DbPool>,
+    Path(id): Path<Uuid>,
+    auth: AuthUser,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = auth
+        .claims
+        .user_id()
+        .map_err(AppError::InternalServerError)?;
+
+    sessions::revoke_session(pool, user_id, &auth.claims.user_type, id).await
+}
+
+/// Change the authenticated user's password
+///
+/// Requires the current password. All sessions are signed out afterwards,
+/// including the one used for this request.
+#[utoipa::path(
+    post,
+    path = "/api/v1/me/password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed", body = JsonApiResponse<serde_json::Value>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Not a user token", body = ErrorResponse),
+        (status = 422, description = "Wrong current password or new password rejected by the policy", body = ErrorResponse),
+        (status = 429, description = "Too many wrong current passwords", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Client / Me"
+)]
+pub async fn change_password(
+    State(state): State<AppState>,
+    auth: AuthUser,
+    ValidatedJson(req): ValidatedJson<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if auth.claims.user_type != "user" {
+        return Err(AppError::Forbidden(
+            "Only users can change their password here".to_string(),
+        ));
+    }
+    let user_id = auth
+        .claims
+        .user_id()
+        .map_err(AppError::InternalServerError)?;
+
+    let accounts =
+        PasswordAccounts::Users(Arc::new(PostgresUserRepository::new(state.pool.clone())));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = ChangePasswordUseCase::new(
+        accounts,
+        state.password_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    )
+    .with_password_policy(state.password_policies.users)
+    .with_login_throttle(state.login_throttle);
+
+    let revoked = use_case.execute(user_id, req).await?;
+
+    let meta =
+        JsonApiMeta::new().with_extra(json!({ "passwordChanged": true, "revokedTokens": revoked }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    ))
+}
@@ -1,3 +1,7 @@
 pub mod auth;
+pub mod groups;
 pub mod health;
+pub mod me;
+pub mod oauth;
 pub mod users;
+pub mod well_known;
@@ -0,0 +1,57 @@
+use crate::application::auth::introspect::{
+    IntrospectTokenUseCase, IntrospectionRequest, IntrospectionResponse,
+};
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::infrastructure::state::AppState;
+use crate::presentation::extractors::OAuthClient;
+use crate::shared::error::{AppError, ErrorResponse};
+use crate::shared::validation::ValidatedForm;
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderValue, header},
+    response::IntoResponse,
+};
+use std::sync::Arc;
+
+/// Token introspection (RFC 7662) for resource servers that cannot verify tokens locally
+#[utoipa::path(
+    post,
+    path = "/api/v1/oauth/introspect",
+    request_body(content = IntrospectionRequest, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Token state, `active: false` for unknown or revoked tokens", body = IntrospectionResponse),
+        (status = 401, description = "Invalid client credentials", body = ErrorResponse),
+        (status = 422, description = "Validation error", body = ErrorResponse)
+    ),
+    security(
+        ("client_basic_auth" = [])
+    ),
+    tag = "OAuth"
+)]
+pub async fn introspect(
+    State(state): State<AppState>,
+    client: OAuthClient,
+    ValidatedForm(req): ValidatedForm<IntrospectionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool));
+    let use_case = IntrospectTokenUseCase::new(
+        state.auth_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    );
+
+    let response = use_case.execute(req).await?;
+
+    tracing::debug!(
+        client_id = %client.client_id,
+        active = response.active,
+        "token introspected"
+    );
+
+    Ok((
+        // Token state must not be cached by intermediaries
+        [(header::CACHE_CONTROL, HeaderValue::from_static("no-store"))],
+        Json(response),
+    ))
+}
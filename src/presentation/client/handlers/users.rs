@@ -2,13 +2,19 @@ use crate::application::users::create::{CreateUserRequest, CreateUserUseCase};
 use crate::application::users::delete::DeleteUserUseCase;
 use crate::application::users::get::GetUserUseCase;
 use crate::application::users::update::{UpdateUserRequest, UpdateUserUseCase};
+use crate::domain::notifications::NotificationService;
 use crate::infrastructure::db::DbPool;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
 use crate::infrastructure::repositories::users::PostgresUserRepository;
+use crate::infrastructure::state::AppState;
+use crate::presentation::client::handlers::auth::email_verification_sender;
 use crate::presentation::dtos::UserResource;
-use crate::presentation::extractors::AuthUser;
+use crate::presentation::extractors::{
+    AuthUser, ClientUser, PermissionRequirement, UsersDelete, UsersUpdate,
+};
 use crate::shared::error::{AppError, ErrorResponse};
 use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
-use crate::shared::validation::ValidatedJson;
+use crate::shared::validation::{PasswordPolicies, ValidatedJson};
 use axum::{
     Json,
     extract::{Path, State},
@@ -34,11 +40,15 @@ use crate::infrastructure::password::PasswordService;
 )]
 pub async fn create_user(
     State(pool): State<DbPool>,
+    State(hasher): State<Arc<PasswordService>>,
+    State(password_policies): State<PasswordPolicies>,
+    State(notification_service): State<Arc<dyn NotificationService>>,
     ValidatedJson(req): ValidatedJson<CreateUserRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let repo = Arc::new(PostgresUserRepository::new(pool));
-    let hasher = Arc::new(PasswordService::new());
-    let use_case = CreateUserUseCase::new(repo, hasher);
+    let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let use_case = CreateUserUseCase::new(repo, hasher)
+        .with_password_policy(password_policies.users)
+        .with_email_verification(email_verification_sender(pool, notification_service));
 
     let user = use_case.execute(req).await?;
     let resource = JsonApiResource::new("users", user.id.to_string(), UserResource::from(user));
@@ -94,7 +104,7 @@ pub async fn get_user(
     responses(
         (status = 200, description = "User updated successfully", body = JsonApiResponse<JsonApiResource<UserResource>>),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - Can only update your own account", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Other accounts require the users.update permission", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse),
         (status = 422, description = "Validation error", body = ErrorResponse)
     ),
@@ -104,25 +114,31 @@ pub async fn get_user(
     tag = "Client / User"
 )]
 pub async fn update_user(
-    State(pool): State<DbPool>,
+    State(state): State<AppState>,
     Path(id): Path<Uuid>,
-    auth: AuthUser,
+    user: ClientUser,
     ValidatedJson(req): ValidatedJson<UpdateUserRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify ownership: user can only update their own account
-    let auth_user_id = auth
-        .claims
-        .user_id()
-        .map_err(AppError::InternalServerError)?;
-    if auth_user_id != id {
+    // Users update their own account, other accounts need a granted permission
+    if user.user_id != id && !user.permissions.allows_any(UsersUpdate::PERMISSIONS) {
         return Err(AppError::Forbidden(
             "You can only update your own account".to_string(),
         ));
     }
 
-    let repo = Arc::new(PostgresUserRepository::new(pool));
-    let hasher = Arc::new(PasswordService::new());
-    let use_case = UpdateUserUseCase::new(repo, hasher);
+    let repo = Arc::new(PostgresUserRepository::new(state.pool.clone()));
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(state.pool.clone()));
+    let use_case = UpdateUserUseCase::new(
+        repo,
+        state.password_service,
+        refresh_token_repo,
+        state.access_token_denylist,
+    )
+    .with_password_policy(state.password_policies.users)
+    .with_email_verification(email_verification_sender(
+        state.pool,
+        state.notification_service,
+    ));
 
     let user = use_case.execute(id, req).await?;
     let resource = JsonApiResource::new("users", user.id.to_string(), UserResource::from(user));
@@ -140,7 +156,7 @@ pub async fn update_user(
     responses(
         (status = 200, description = "User deleted successfully", body = JsonApiResponse<serde_json::Value>),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
-        (status = 403, description = "Forbidden - Can only delete your own account", body = ErrorResponse),
+        (status = 403, description = "Forbidden - Other accounts require the users.delete permission", body = ErrorResponse),
         (status = 404, description = "User not found", body = ErrorResponse)
     ),
     security(
@@ -151,14 +167,10 @@ pub async fn update_user(
 pub async fn delete_user(
     State(pool): State<DbPool>,
     Path(id): Path<Uuid>,
-    auth: AuthUser,
+    user: ClientUser,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify ownership: user can only delete their own account
-    let auth_user_id = auth
-        .claims
-        .user_id()
-        .map_err(AppError::InternalServerError)?;
-    if auth_user_id != id {
+    // Users delete their own account, other accounts need a granted permission
+    if user.user_id != id && !user.permissions.allows_any(UsersDelete::PERMISSIONS) {
         return Err(AppError::Forbidden(
             "You can only delete your own account".to_string(),
         ));
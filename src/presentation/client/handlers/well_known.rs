@@ -0,0 +1,80 @@
+use crate::infrastructure::auth::{JwkSet, JwtAuthService};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderValue, header},
+    response::IntoResponse,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Discovery document describing how tokens issued by this service can be verified
+#[derive(Serialize, ToSchema)]
+pub struct DiscoveryDocument {
+    pub issuer: String,
+    pub jwks_uri: String,
+    pub introspection_endpoint: String,
+    pub subject_types_supported: Vec<String>,
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    pub claims_supported: Vec<String>,
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+/// Public keys used to verify issued tokens
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    responses(
+        (status = 200, description = "JSON Web Key Set", body = JwkSet)
+    ),
+    tag = "Discovery"
+)]
+pub async fn jwks(State(auth_service): State<Arc<JwtAuthService>>) -> impl IntoResponse {
+    (
+        // Verifiers may cache the keys, but should pick up rotations quickly
+        [(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=300"),
+        )],
+        Json(auth_service.jwks()),
+    )
+}
+
+/// OpenID-style discovery document
+#[utoipa::path(
+    get,
+    path = "/.well-known/openid-configuration",
+    responses(
+        (status = 200, description = "Discovery document", body = DiscoveryDocument)
+    ),
+    tag = "Discovery"
+)]
+pub async fn openid_configuration(
+    State(auth_service): State<Arc<JwtAuthService>>,
+) -> impl IntoResponse {
+    let issuer = auth_service.issuer();
+
+    Json(DiscoveryDocument {
+        issuer: issuer.to_string(),
+        jwks_uri: format!("{}/.well-known/jwks.json", issuer),
+        introspection_endpoint: format!("{}/api/v1/oauth/introspect", issuer),
+        subject_types_supported: strings(&["public"]),
+        id_token_signing_alg_values_supported: strings(&["ES256"]),
+        claims_supported: strings(&[
+            "sub",
+            "user_type",
+            "iat",
+            "iat_ms",
+            "exp",
+            "nbf",
+            "iss",
+            "aud",
+            "type",
+            "jti",
+        ]),
+    })
+}
@@ -8,4 +8,10 @@ pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/login", post(auth::login))
         .route("/refresh", post(auth::refresh_token))
+        .route("/logout", post(auth::logout))
+        .route("/logout-all", post(auth::logout_all))
+        .route("/forgot-password", post(auth::forgot_password))
+        .route("/reset-password", post(auth::reset_password))
+        .route("/verify-email", post(auth::verify_email))
+        .route("/verify-email/resend", post(auth::resend_verification))
 }
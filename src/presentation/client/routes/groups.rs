@@ -0,0 +1,20 @@
+use crate::presentation::client::handlers::groups;
+use axum::{Router, routing::get};
+
+use crate::infrastructure::state::AppState;
+
+/// Client group routes, permissions are checked within the `{group_id}` group
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(groups::list_my_groups))
+        .route(
+            "/{group_id}",
+            get(groups::get_group).put(groups::update_group),
+        )
+        .route(
+            "/{group_id}/members",
+            get(groups::list_members)
+                .post(groups::add_members)
+                .delete(groups::remove_members),
+        )
+}
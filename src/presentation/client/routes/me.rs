@@ -0,0 +1,15 @@
+use crate::presentation::client::handlers::me;
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::infrastructure::state::AppState;
+
+/// Client routes for the authenticated user
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/sessions", get(me::list_sessions))
+        .route("/sessions/{id}", delete(me::revoke_session))
+        .route("/password", post(me::change_password))
+}
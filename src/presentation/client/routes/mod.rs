@@ -1,4 +1,7 @@
 pub mod auth;
+pub mod groups;
+pub mod me;
+pub mod oauth;
 pub mod users;
 
 use crate::infrastructure::state::AppState;
@@ -7,5 +10,8 @@ use axum::Router;
 pub fn routes() -> Router<AppState> {
     Router::new()
         .nest("/auth", auth::routes())
+        .nest("/groups", groups::routes())
+        .nest("/me", me::routes())
+        .nest("/oauth", oauth::routes())
         .nest("/users", users::routes())
 }
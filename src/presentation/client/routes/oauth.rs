@@ -0,0 +1,9 @@
+use crate::presentation::client::handlers::oauth;
+use axum::{Router, routing::post};
+
+use crate::infrastructure::state::AppState;
+
+/// OAuth routes for other services
+pub fn routes() -> Router<AppState> {
+    Router::new().route("/introspect", post(oauth::introspect))
+}
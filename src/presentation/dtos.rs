@@ -1,37 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::domain::permissions::Permission;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum PermissionDto {
-    #[serde(rename = "*")]
-    Wildcard,
-    AdministratorManagement,
-    RoleManagement,
-}
-
-impl From<Permission> for PermissionDto {
-    fn from(p: Permission) -> Self {
-        match p {
-            Permission::Wildcard => PermissionDto::Wildcard,
-            Permission::AdministratorManagement => PermissionDto::AdministratorManagement,
-            Permission::RoleManagement => PermissionDto::RoleManagement,
-        }
-    }
-}
-
-impl From<PermissionDto> for Permission {
-    fn from(val: PermissionDto) -> Self {
-        match val {
-            PermissionDto::Wildcard => Permission::Wildcard,
-            PermissionDto::AdministratorManagement => Permission::AdministratorManagement,
-            PermissionDto::RoleManagement => Permission::RoleManagement,
-        }
-    }
-}
-
 use crate::application::auth::login::LoginResponse;
 
 #[derive(Serialize, ToSchema)]
@@ -62,6 +31,9 @@ pub struct UserResource {
     pub id: String,
     pub username: String,
     pub email: String,
+    #[serde(with = "time::serde::iso8601::option")]
+    #[schema(value_type = Option<String>)]
+    pub email_verified_at: Option<time::OffsetDateTime>,
     #[serde(with = "time::serde::iso8601")]
     #[schema(value_type = String)]
     pub created_at: time::OffsetDateTime,
@@ -76,32 +48,285 @@ impl From<User> for UserResource {
             id: user.id.to_string(),
             username: user.username,
             email: user.email,
+            email_verified_at: user.email_verified_at,
             created_at: user.created_at,
             updated_at: user.updated_at,
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+use crate::domain::groups::Group;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResource {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub created_at: time::OffsetDateTime,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl From<Group> for GroupResource {
+    fn from(group: Group) -> Self {
+        Self {
+            id: group.id.to_string(),
+            name: group.name,
+            description: group.description,
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+        }
+    }
+}
+
+use crate::domain::auth::Session;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionResource {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub device_name: Option<String>,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub created_at: time::OffsetDateTime,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub last_used_at: time::OffsetDateTime,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub expires_at: time::OffsetDateTime,
+}
+
+impl From<Session> for SessionResource {
+    fn from(session: Session) -> Self {
+        Self {
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            device_name: session.device_name,
+            created_at: session.created_at,
+            last_used_at: session.last_used_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
+
+use crate::application::auth::mfa::{
+    MfaChallenge, MfaStatusResponse, RecoveryCodesResponse, TotpEnrollmentResponse,
+};
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MfaChallengeResource {
+    pub challenge_token: String,
+    pub expires_in: i64,
+}
+
+impl From<MfaChallenge> for MfaChallengeResource {
+    fn from(challenge: MfaChallenge) -> Self {
+        Self {
+            challenge_token: challenge.challenge_token,
+            expires_in: challenge.expires_in,
+        }
+    }
+}
 
-    #[test]
-    fn test_permission_dto_conversion() {
-        // Test Wildcard conversion
-        let domain_wildcard = Permission::Wildcard;
-        let dto_wildcard: PermissionDto = domain_wildcard.into();
-        assert_eq!(dto_wildcard, PermissionDto::Wildcard);
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpEnrollmentResource {
+    pub secret: String,
+    pub otpauth_uri: String,
+}
 
-        let back_wildcard: Permission = dto_wildcard.into();
-        assert_eq!(back_wildcard, Permission::Wildcard);
+impl From<TotpEnrollmentResponse> for TotpEnrollmentResource {
+    fn from(response: TotpEnrollmentResponse) -> Self {
+        Self {
+            secret: response.secret,
+            otpauth_uri: response.otpauth_uri,
+        }
+    }
+}
 
-        // Test AdministratorManagement conversion
-        let domain_admin = Permission::AdministratorManagement;
-        let dto_admin: PermissionDto = domain_admin.into();
-        assert_eq!(dto_admin, PermissionDto::AdministratorManagement);
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryCodesResource {
+    pub recovery_codes: Vec<String>,
+}
 
-        let back_admin: Permission = dto_admin.into();
-        assert_eq!(back_admin, Permission::AdministratorManagement);
+impl From<RecoveryCodesResponse> for RecoveryCodesResource {
+    fn from(response: RecoveryCodesResponse) -> Self {
+        Self {
+            recovery_codes: response.recovery_codes,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MfaStatusResource {
+    pub totp_enabled: bool,
+    pub recovery_codes_remaining: i64,
+}
+
+impl From<MfaStatusResponse> for MfaStatusResource {
+    fn from(response: MfaStatusResponse) -> Self {
+        Self {
+            totp_enabled: response.totp_enabled,
+            recovery_codes_remaining: response.recovery_codes_remaining,
+        }
+    }
+}
+
+use crate::application::auth::passkeys::{PasskeyLoginOptions, PasskeyRegistrationOptions};
+use crate::application::auth::webauthn::COSE_ALG_ES256;
+use crate::domain::passkeys::Passkey;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyResource {
+    pub name: String,
+    pub credential_id: String,
+    #[serde(with = "time::serde::iso8601::option")]
+    #[schema(value_type = Option<String>)]
+    pub last_used_at: Option<time::OffsetDateTime>,
+    #[serde(with = "time::serde::iso8601")]
+    #[schema(value_type = String)]
+    pub created_at: time::OffsetDateTime,
+}
+
+impl From<Passkey> for PasskeyResource {
+    fn from(passkey: Passkey) -> Self {
+        Self {
+            name: passkey.name,
+            credential_id: passkey.credential_id,
+            last_used_at: passkey.last_used_at,
+            created_at: passkey.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RelyingPartyDto {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserDto {
+    /// Base64url encoded user handle
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCredentialParametersDto {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub alg: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyCredentialDescriptorDto {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    /// Base64url encoded credential ID
+    pub id: String,
+}
+
+impl PublicKeyCredentialDescriptorDto {
+    fn public_key(id: String) -> Self {
+        Self {
+            credential_type: "public-key".to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelectionDto {
+    pub resident_key: String,
+    pub user_verification: String,
+}
+
+/// `publicKey` options for `navigator.credentials.create()`, binary values base64url encoded
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyCreationOptionsResource {
+    pub challenge: String,
+    pub rp: RelyingPartyDto,
+    pub user: PasskeyUserDto,
+    pub pub_key_cred_params: Vec<PublicKeyCredentialParametersDto>,
+    pub timeout: i64,
+    pub exclude_credentials: Vec<PublicKeyCredentialDescriptorDto>,
+    pub authenticator_selection: AuthenticatorSelectionDto,
+    pub attestation: String,
+}
+
+impl From<PasskeyRegistrationOptions> for PasskeyCreationOptionsResource {
+    fn from(options: PasskeyRegistrationOptions) -> Self {
+        Self {
+            challenge: options.challenge,
+            rp: RelyingPartyDto {
+                id: options.rp_id,
+                name: options.rp_name,
+            },
+            user: PasskeyUserDto {
+                id: options.user_id,
+                name: options.user_name,
+                display_name: options.user_display_name,
+            },
+            pub_key_cred_params: vec![PublicKeyCredentialParametersDto {
+                credential_type: "public-key".to_string(),
+                alg: COSE_ALG_ES256,
+            }],
+            timeout: options.timeout_ms,
+            exclude_credentials: options
+                .exclude_credentials
+                .into_iter()
+                .map(PublicKeyCredentialDescriptorDto::public_key)
+                .collect(),
+            authenticator_selection: AuthenticatorSelectionDto {
+                resident_key: "preferred".to_string(),
+                user_verification: "required".to_string(),
+            },
+            attestation: "none".to_string(),
+        }
+    }
+}
+
+/// `publicKey` options for `navigator.credentials.get()`, binary values base64url encoded
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyRequestOptionsResource {
+    pub challenge: String,
+    pub rp_id: String,
+    pub timeout: i64,
+    pub allow_credentials: Vec<PublicKeyCredentialDescriptorDto>,
+    pub user_verification: String,
+}
+
+impl From<PasskeyLoginOptions> for PasskeyRequestOptionsResource {
+    fn from(options: PasskeyLoginOptions) -> Self {
+        Self {
+            challenge: options.challenge,
+            rp_id: options.rp_id,
+            timeout: options.timeout_ms,
+            allow_credentials: options
+                .allow_credentials
+                .into_iter()
+                .map(PublicKeyCredentialDescriptorDto::public_key)
+                .collect(),
+            user_verification: "required".to_string(),
+        }
     }
 }
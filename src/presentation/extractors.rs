@@ -1,7 +1,19 @@
-use crate::domain::auth::{AuthService, Claims};
+use crate::domain::access_scope::AccessScope;
+use crate::domain::auth::{AuthService, Claims, SessionMetadata};
+use crate::domain::permissions::Permission;
 use crate::infrastructure::state::AppState;
 use crate::shared::error::AppError;
-use axum::{extract::FromRequestParts, http::request::Parts};
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, RawPathParams},
+    http::{Extensions, request::Parts},
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use uuid::Uuid;
 
 /// Authenticated user extractor
 /// Validates JWT token from Authorization header
@@ -46,6 +58,417 @@ impl FromRequestParts<AppState> for AuthUser {
             return Err(AppError::Unauthorized("Invalid token type".to_string()));
         }
 
+        // Reject tokens revoked before their expiry (logout, password change, ...)
+        let denied = state
+            .access_token_denylist
+            .is_denied(&claims)
+            .await
+            .map_err(AppError::InternalServerError)?;
+
+        if denied {
+            return Err(AppError::Unauthorized("Token has been revoked".to_string()));
+        }
+
         Ok(AuthUser { claims })
     }
 }
+
+/// Permissions granted to an administrator or user through their roles
+#[derive(Debug, Clone, Default)]
+pub struct GrantedPermissions(Arc<Vec<Permission>>);
+
+impl GrantedPermissions {
+    /// Load the permissions of the `scope` account `subject_id`, resolved at
+    /// most once per request
+    pub async fn resolve(
+        extensions: &mut Extensions,
+        state: &AppState,
+        scope: AccessScope,
+        subject_id: Uuid,
+    ) -> Result<Self, AppError> {
+        if let Some(granted) = extensions.get::<GrantedPermissions>() {
+            return Ok(granted.clone());
+        }
+
+        let permissions = state
+            .permission_cache
+            .permissions(scope, subject_id)
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    "Failed to fetch permissions for {} {}: {}",
+                    scope,
+                    subject_id,
+                    e
+                );
+                AppError::InternalServerError(e)
+            })?;
+
+        let granted = Self(permissions);
+        extensions.insert(granted.clone());
+        Ok(granted)
+    }
+
+    /// Whether a granted permission or pattern covers `permission`
+    pub fn allows(&self, permission: &Permission) -> bool {
+        self.0.iter().any(|granted| granted.grants(permission))
+    }
+
+    /// Whether any of `permissions` is allowed, an empty list always is
+    pub fn allows_any(&self, permissions: &[Permission]) -> bool {
+        permissions.is_empty() || permissions.iter().any(|p| self.allows(p))
+    }
+
+    pub fn as_slice(&self) -> &[Permission] {
+        &self.0
+    }
+}
+
+/// Permissions a handler requires, any one of them grants access
+pub trait PermissionRequirement {
+    const PERMISSIONS: &'static [Permission];
+}
+
+/// Any administrator, whatever their permissions
+pub struct AnyAdministrator;
+
+impl PermissionRequirement for AnyAdministrator {
+    const PERMISSIONS: &'static [Permission] = &[];
+}
+
+/// Any member of the group, whatever their permissions
+pub struct AnyMember;
+
+impl PermissionRequirement for AnyMember {
+    const PERMISSIONS: &'static [Permission] = &[];
+}
+
+/// Declare a requirement type per registered permission
+macro_rules! permission_requirements {
+    ($($name:ident => $permission:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Accounts granted `", $permission, "`")]
+            pub struct $name;
+
+            impl PermissionRequirement for $name {
+                const PERMISSIONS: &'static [Permission] = &[Permission::from_static($permission)];
+            }
+        )*
+
+        #[cfg(test)]
+        const DECLARED_REQUIREMENTS: &[&str] = &[$($permission),*];
+    };
+}
+
+permission_requirements! {
+    AdministratorsRead => "administrators.read",
+    AdministratorsCreate => "administrators.create",
+    AdministratorsUpdate => "administrators.update",
+    AdministratorsDelete => "administrators.delete",
+    AdministratorsRolesAttach => "administrators.roles.attach",
+    AdministratorsRolesDetach => "administrators.roles.detach",
+    AdministratorsSessionsRead => "administrators.sessions.read",
+    AdministratorsSessionsRevoke => "administrators.sessions.revoke",
+    AdministratorsUnlock => "administrators.unlock",
+    UsersRead => "users.read",
+    UsersSessionsRead => "users.sessions.read",
+    UsersSessionsRevoke => "users.sessions.revoke",
+    UsersUpdate => "users.update",
+    UsersDelete => "users.delete",
+    UsersUnlock => "users.unlock",
+    UsersRolesAttach => "users.roles.attach",
+    UsersRolesDetach => "users.roles.detach",
+    GroupsRead => "groups.read",
+    GroupsCreate => "groups.create",
+    GroupsUpdate => "groups.update",
+    GroupsDelete => "groups.delete",
+    GroupsMembersRead => "groups.members.read",
+    GroupsMembersAdd => "groups.members.add",
+    GroupsMembersRemove => "groups.members.remove",
+    RolesRead => "roles.read",
+    RolesCreate => "roles.create",
+    RolesUpdate => "roles.update",
+    RolesDelete => "roles.delete",
+    RolesPermissionsRead => "roles.permissions.read",
+    RolesPermissionsAttach => "roles.permissions.attach",
+    RolesPermissionsDetach => "roles.permissions.detach",
+    PermissionsRead => "permissions.read",
+    PermissionsCacheRead => "permissions.cache.read",
+}
+
+/// Authenticated administrator extractor
+/// Rejects other token types with 403 and administrators lacking the permissions of `P`.
+pub struct RequirePermission<P> {
+    pub administrator_id: Uuid,
+    pub claims: Claims,
+    pub permissions: GrantedPermissions,
+    requirement: PhantomData<fn() -> P>,
+}
+
+impl<P: PermissionRequirement> FromRequestParts<AppState> for RequirePermission<P> {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let AuthUser { claims } = AuthUser::from_request_parts(parts, state).await?;
+
+        if claims.user_type != "admin" {
+            return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+        }
+
+        let administrator_id = claims
+            .user_id()
+            .map_err(|e| AppError::Unauthorized(e.to_string()))?;
+
+        let permissions = GrantedPermissions::resolve(
+            &mut parts.extensions,
+            state,
+            AccessScope::Administrator,
+            administrator_id,
+        )
+        .await?;
+
+        if !permissions.allows_any(P::PERMISSIONS) {
+            return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+        }
+
+        Ok(RequirePermission {
+            administrator_id,
+            claims,
+            permissions,
+            requirement: PhantomData,
+        })
+    }
+}
+
+/// Authenticated client user extractor
+/// Rejects other token types with 403 and resolves the permissions granted
+/// through the user's roles, leaving it to the handler which ones it needs.
+pub struct ClientUser {
+    pub user_id: Uuid,
+    pub claims: Claims,
+    pub permissions: GrantedPermissions,
+}
+
+impl FromRequestParts<AppState> for ClientUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let AuthUser { claims } = AuthUser::from_request_parts(parts, state).await?;
+
+        if claims.user_type != "user" {
+            return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+        }
+
+        let user_id = claims
+            .user_id()
+            .map_err(|e| AppError::Unauthorized(e.to_string()))?;
+
+        let permissions =
+            GrantedPermissions::resolve(&mut parts.extensions, state, AccessScope::User, user_id)
+                .await?;
+
+        Ok(ClientUser {
+            user_id,
+            claims,
+            permissions,
+        })
+    }
+}
+
+/// Group member extractor for routes with a `{group_id}` path parameter
+/// Permissions are those granted by the group's roles. Non-members get 404 so
+/// group ids can't be probed, members lacking the permissions of `P` get 403.
+pub struct GroupPermission<P> {
+    pub user_id: Uuid,
+    pub group_id: Uuid,
+    pub claims: Claims,
+    pub permissions: GrantedPermissions,
+    requirement: PhantomData<fn() -> P>,
+}
+
+impl<P: PermissionRequirement> FromRequestParts<AppState> for GroupPermission<P> {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let not_found = || AppError::NotFound("Group not found".to_string());
+
+        let AuthUser { claims } = AuthUser::from_request_parts(parts, state).await?;
+
+        if claims.user_type != "user" {
+            return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+        }
+
+        let user_id = claims
+            .user_id()
+            .map_err(|e| AppError::Unauthorized(e.to_string()))?;
+
+        let group_id = RawPathParams::from_request_parts(parts, state)
+            .await
+            .ok()
+            .and_then(|params| {
+                params
+                    .iter()
+                    .find(|(name, _)| *name == "group_id")
+                    .and_then(|(_, value)| value.parse::<Uuid>().ok())
+            })
+            .ok_or_else(not_found)?;
+
+        let permissions = state
+            .permission_cache
+            .group_permissions(user_id, group_id)
+            .await
+            .map_err(AppError::InternalServerError)?
+            .map(GrantedPermissions)
+            .ok_or_else(not_found)?;
+
+        if !permissions.allows_any(P::PERMISSIONS) {
+            return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+        }
+
+        Ok(GroupPermission {
+            user_id,
+            group_id,
+            claims,
+            permissions,
+            requirement: PhantomData,
+        })
+    }
+}
+
+/// Client details extractor
+/// Collects user agent, client IP and the optional `X-Device-Name` header
+/// so they can be stored with the issued session.
+pub struct ClientInfo(pub SessionMetadata);
+
+impl FromRequestParts<AppState> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| {
+            parts
+                .headers
+                .get(name)
+                .and_then(|h| h.to_str().ok())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+
+        // Only trust X-Forwarded-For when running behind a known proxy.
+        // The proxy appends the address it saw, everything left of it is client supplied.
+        let forwarded_ip = if state.trust_proxy_headers {
+            parts
+                .headers
+                .get_all("X-Forwarded-For")
+                .iter()
+                .filter_map(|h| h.to_str().ok())
+                .flat_map(|v| v.split(','))
+                .next_back()
+                .and_then(|ip| ip.trim().parse::<IpAddr>().ok())
+        } else {
+            None
+        };
+
+        let ip_address = forwarded_ip
+            .or_else(|| {
+                parts
+                    .extensions
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| addr.ip())
+            })
+            .map(|ip| ip.to_string());
+
+        Ok(ClientInfo(SessionMetadata {
+            user_agent: header("User-Agent"),
+            ip_address,
+            // Matches the column size of refresh_tokens.device_name
+            device_name: header("X-Device-Name").map(|v| v.chars().take(255).collect()),
+        }))
+    }
+}
+
+/// Authenticated OAuth client extractor
+/// Validates HTTP Basic client credentials against the configured clients.
+pub struct OAuthClient {
+    pub client_id: String,
+}
+
+impl FromRequestParts<AppState> for OAuthClient {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let invalid_client = || AppError::Unauthorized("Invalid client credentials".to_string());
+
+        let encoded = parts
+            .headers
+            .get("Authorization")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.strip_prefix("Basic "))
+            .ok_or_else(|| AppError::Unauthorized("Missing client credentials".to_string()))?;
+
+        let decoded = STANDARD
+            .decode(encoded.trim())
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid_client)?;
+
+        let (client_id, client_secret) = decoded.split_once(':').ok_or_else(invalid_client)?;
+
+        if !state.introspection_clients.verify(client_id, client_secret) {
+            return Err(invalid_client());
+        }
+
+        Ok(OAuthClient {
+            client_id: client_id.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::permissions::REGISTRY;
+
+    fn granting(names: &[&str]) -> GrantedPermissions {
+        GrantedPermissions(Arc::new(names.iter().map(|n| n.parse().unwrap()).collect()))
+    }
+
+    #[test]
+    fn test_granted_permissions_allows_any() {
+        let granted = granting(&["roles.*", "users.read"]);
+
+        assert!(granted.allows_any(RolesPermissionsAttach::PERMISSIONS));
+        assert!(granted.allows_any(UsersRead::PERMISSIONS));
+        assert!(granted.allows_any(AnyAdministrator::PERMISSIONS));
+        assert!(!granted.allows_any(UsersUnlock::PERMISSIONS));
+
+        assert!(granting(&["*"]).allows(&Permission::from_static("administrators.delete")));
+        assert!(!GrantedPermissions::default().allows(&Permission::from_static("roles.read")));
+    }
+
+    #[test]
+    fn test_requirements_name_registered_permissions() {
+        for permission in DECLARED_REQUIREMENTS {
+            assert!(
+                REGISTRY.iter().any(|d| d.name == *permission),
+                "{} is not registered",
+                permission
+            );
+        }
+    }
+}
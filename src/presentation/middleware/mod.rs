@@ -1,3 +1,2 @@
 pub mod cors;
 pub mod rate_limit;
-pub mod auth;
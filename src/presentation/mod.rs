@@ -5,3 +5,4 @@ pub mod extractors;
 pub mod middleware;
 pub mod openapi;
 pub mod router;
+pub mod sessions;
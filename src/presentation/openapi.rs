@@ -1,17 +1,43 @@
 #[allow(unused_imports)]
 use crate::application::auth::admin_login::AdminLoginRequest;
+use crate::application::auth::change_password::ChangePasswordRequest;
+use crate::application::auth::email_verification::{ResendVerificationRequest, VerifyEmailRequest};
+use crate::application::auth::introspect::{IntrospectionRequest, IntrospectionResponse};
 use crate::application::auth::login::{LoginRequest, LoginResponse};
+use crate::application::auth::logout::LogoutRequest;
+use crate::application::auth::mfa::{MfaEnrollmentRequest, MfaVerifyRequest, TotpCodeRequest};
+use crate::application::auth::passkeys::{
+    PasskeyLoginOptionsRequest, PasskeyLoginRequest, PasskeyRegistrationRequest,
+};
+use crate::application::auth::password_reset::{ForgotPasswordRequest, ResetPasswordRequest};
 use crate::application::auth::refresh::{RefreshTokenRequest, RefreshTokenResponse};
+use crate::application::groups::create::CreateGroupRequest;
+use crate::application::groups::members::GroupMembersRequest;
+use crate::application::groups::update::UpdateGroupRequest;
 use crate::application::roles::create::CreateRoleRequest;
+use crate::application::roles::get_permissions::RolePermissions;
+use crate::application::roles::parents::ParentRolesRequest;
 use crate::application::roles::update::UpdateRoleRequest;
 use crate::application::users::create::CreateUserRequest;
 use crate::application::users::list::ListUsersRequest;
 use crate::application::users::update::UpdateUserRequest;
-use crate::presentation::admin::handlers::permissions::PermissionResource;
+use crate::domain::permissions::Permission;
+use crate::infrastructure::auth::{Jwk, JwkSet};
+use crate::presentation::admin::handlers::groups::ListGroupsQuery;
+use crate::presentation::admin::handlers::permissions::{
+    PermissionCacheStatsResource, PermissionResource,
+};
 use crate::presentation::admin::handlers::roles::{
     AttachPermissionRequest, DetachPermissionRequest, ListRolesQuery, RoleResource,
 };
-use crate::presentation::dtos::{AuthTokenResource, PermissionDto, UserResource};
+use crate::presentation::client::handlers::well_known::DiscoveryDocument;
+use crate::presentation::dtos::{
+    AuthTokenResource, AuthenticatorSelectionDto, GroupResource, MfaChallengeResource,
+    MfaStatusResource, PasskeyCreationOptionsResource, PasskeyRequestOptionsResource,
+    PasskeyResource, PasskeyUserDto, PublicKeyCredentialDescriptorDto,
+    PublicKeyCredentialParametersDto, RecoveryCodesResource, RelyingPartyDto, SessionResource,
+    TotpEnrollmentResource, UserResource,
+};
 use crate::shared::error::{ErrorResponse, JsonApiError, JsonApiErrorSource};
 use crate::shared::response::{JsonApiLinks, JsonApiMeta, JsonApiResource, JsonApiResponse};
 use utoipa::OpenApi;
@@ -34,9 +60,47 @@ use utoipa::OpenApi;
         crate::presentation::client::handlers::auth::login,
         crate::presentation::admin::handlers::auth::admin_login,
         crate::presentation::client::handlers::auth::refresh_token,
+        crate::presentation::client::handlers::auth::logout,
+        crate::presentation::client::handlers::auth::logout_all,
+        crate::presentation::client::handlers::auth::forgot_password,
+        crate::presentation::client::handlers::auth::reset_password,
+        crate::presentation::client::handlers::auth::verify_email,
+        crate::presentation::client::handlers::auth::resend_verification,
+        crate::presentation::admin::handlers::auth::admin_logout,
+        crate::presentation::admin::handlers::auth::admin_logout_all,
+        crate::presentation::admin::handlers::auth::admin_verify_mfa,
+        crate::presentation::admin::handlers::auth::admin_start_required_mfa_enrollment,
+        crate::presentation::admin::handlers::auth::admin_confirm_required_mfa_enrollment,
+        crate::presentation::admin::handlers::auth::admin_forgot_password,
+        crate::presentation::admin::handlers::auth::admin_reset_password,
+        crate::presentation::admin::handlers::webauthn::passkey_registration_options,
+        crate::presentation::admin::handlers::webauthn::register_passkey,
+        crate::presentation::admin::handlers::webauthn::passkey_login_options,
+        crate::presentation::admin::handlers::webauthn::passkey_login,
+        crate::presentation::admin::handlers::webauthn::list_passkeys,
+        crate::presentation::admin::handlers::webauthn::delete_passkey,
+        crate::presentation::client::handlers::me::list_sessions,
+        crate::presentation::client::handlers::me::revoke_session,
+        crate::presentation::client::handlers::me::change_password,
+        crate::presentation::admin::handlers::me::list_my_sessions,
+        crate::presentation::admin::handlers::me::revoke_my_session,
+        crate::presentation::admin::handlers::me::change_my_password,
+        crate::presentation::admin::handlers::me::get_my_mfa_status,
+        crate::presentation::admin::handlers::me::start_totp_enrollment,
+        crate::presentation::admin::handlers::me::confirm_totp_enrollment,
+        crate::presentation::admin::handlers::me::disable_totp,
+        crate::presentation::admin::handlers::me::regenerate_recovery_codes,
+        crate::presentation::admin::handlers::sessions::list_user_sessions,
+        crate::presentation::admin::handlers::sessions::revoke_user_session,
+        crate::presentation::admin::handlers::sessions::list_admin_sessions,
+        crate::presentation::admin::handlers::sessions::revoke_admin_session,
+        crate::presentation::admin::handlers::lockouts::unlock_user,
+        crate::presentation::admin::handlers::lockouts::unlock_admin,
         crate::presentation::client::handlers::users::create_user,
         crate::presentation::client::handlers::users::get_user,
         crate::presentation::admin::handlers::users::list_users,
+        crate::presentation::admin::handlers::users::attach_user_roles,
+        crate::presentation::admin::handlers::users::detach_user_roles,
         crate::presentation::client::handlers::users::update_user,
         crate::presentation::client::handlers::users::delete_user,
         crate::presentation::admin::handlers::administrators::create_admin,
@@ -44,6 +108,7 @@ use utoipa::OpenApi;
         crate::presentation::admin::handlers::administrators::list_admins,
         crate::presentation::admin::handlers::administrators::update_admin,
         crate::presentation::admin::handlers::administrators::delete_admin,
+        crate::presentation::admin::handlers::administrators::list_admin_roles,
         crate::presentation::admin::handlers::administrators::attach_admin_roles,
         crate::presentation::admin::handlers::administrators::detach_admin_roles,
         crate::presentation::admin::handlers::roles::create_role,
@@ -54,12 +119,31 @@ use utoipa::OpenApi;
         crate::presentation::admin::handlers::roles::attach_permission,
         crate::presentation::admin::handlers::roles::detach_permission,
         crate::presentation::admin::handlers::roles::get_role_permissions,
+        crate::presentation::admin::handlers::roles::attach_parents,
+        crate::presentation::admin::handlers::roles::detach_parents,
+        crate::presentation::admin::handlers::groups::create_group,
+        crate::presentation::admin::handlers::groups::list_groups,
+        crate::presentation::admin::handlers::groups::get_group,
+        crate::presentation::admin::handlers::groups::update_group,
+        crate::presentation::admin::handlers::groups::delete_group,
+        crate::presentation::admin::handlers::groups::list_group_members,
+        crate::presentation::admin::handlers::groups::add_group_members,
+        crate::presentation::admin::handlers::groups::remove_group_members,
+        crate::presentation::client::handlers::groups::list_my_groups,
+        crate::presentation::client::handlers::groups::get_group,
+        crate::presentation::client::handlers::groups::update_group,
+        crate::presentation::client::handlers::groups::list_members,
+        crate::presentation::client::handlers::groups::add_members,
+        crate::presentation::client::handlers::groups::remove_members,
         crate::presentation::admin::handlers::permissions::list_permissions,
+        crate::presentation::admin::handlers::permissions::permission_cache_stats,
+        crate::presentation::client::handlers::well_known::jwks,
+        crate::presentation::client::handlers::well_known::openid_configuration,
+        crate::presentation::client::handlers::oauth::introspect,
     ),
     components(
         schemas(
-            // Domain models removed (using Resources/DTOs)
-            PermissionDto,
+            Permission,
 
             // Request DTOs
             CreateUserRequest,
@@ -69,32 +153,95 @@ use utoipa::OpenApi;
             UpdateRoleRequest,
             AttachPermissionRequest,
             DetachPermissionRequest,
+            ParentRolesRequest,
             ListRolesQuery,
+            CreateGroupRequest,
+            UpdateGroupRequest,
+            GroupMembersRequest,
+            ListGroupsQuery,
             LoginRequest,
 
             AdminLoginRequest,
             LoginResponse,
             RefreshTokenRequest,
             RefreshTokenResponse,
+            LogoutRequest,
+            ForgotPasswordRequest,
+            ResetPasswordRequest,
+            ChangePasswordRequest,
+            VerifyEmailRequest,
+            ResendVerificationRequest,
+            MfaVerifyRequest,
+            MfaEnrollmentRequest,
+            TotpCodeRequest,
+            PasskeyRegistrationRequest,
+            PasskeyLoginOptionsRequest,
+            PasskeyLoginRequest,
+
+            // Discovery documents
+            Jwk,
+            JwkSet,
+            DiscoveryDocument,
+            IntrospectionRequest,
+            IntrospectionResponse,
 
             // JSON:API Resource types
             UserResource,
             RoleResource,
+            GroupResource,
             PermissionResource,
+            PermissionCacheStatsResource,
             AuthTokenResource,
+            SessionResource,
+            MfaChallengeResource,
+            MfaStatusResource,
+            TotpEnrollmentResource,
+            RecoveryCodesResource,
+            PasskeyResource,
+            PasskeyCreationOptionsResource,
+            PasskeyRequestOptionsResource,
+            RelyingPartyDto,
+            PasskeyUserDto,
+            PublicKeyCredentialParametersDto,
+            PublicKeyCredentialDescriptorDto,
+            AuthenticatorSelectionDto,
             JsonApiResource<UserResource>,
             JsonApiResource<RoleResource>,
+            JsonApiResource<GroupResource>,
             JsonApiResource<PermissionResource>,
+            JsonApiResource<PermissionCacheStatsResource>,
             JsonApiResource<AuthTokenResource>,
+            JsonApiResource<SessionResource>,
+            JsonApiResource<MfaChallengeResource>,
+            JsonApiResource<MfaStatusResource>,
+            JsonApiResource<TotpEnrollmentResource>,
+            JsonApiResource<RecoveryCodesResource>,
+            JsonApiResource<PasskeyResource>,
+            JsonApiResource<PasskeyCreationOptionsResource>,
+            JsonApiResource<PasskeyRequestOptionsResource>,
 
             // JSON:API Response types
             JsonApiResponse<JsonApiResource<UserResource>>,
             JsonApiResponse<Vec<JsonApiResource<UserResource>>>,
             JsonApiResponse<JsonApiResource<RoleResource>>,
             JsonApiResponse<Vec<JsonApiResource<RoleResource>>>,
+            JsonApiResponse<JsonApiResource<GroupResource>>,
+            JsonApiResponse<Vec<JsonApiResource<GroupResource>>>,
             JsonApiResponse<Vec<JsonApiResource<PermissionResource>>>,
-            JsonApiResponse<Vec<PermissionDto>>,
+            JsonApiResponse<JsonApiResource<PermissionCacheStatsResource>>,
+            JsonApiResponse<Vec<Permission>>,
+            RolePermissions,
+            JsonApiResponse<RolePermissions>,
             JsonApiResponse<JsonApiResource<AuthTokenResource>>,
+            JsonApiResponse<Vec<JsonApiResource<SessionResource>>>,
+            JsonApiResponse<JsonApiResource<MfaChallengeResource>>,
+            JsonApiResponse<JsonApiResource<MfaStatusResource>>,
+            JsonApiResponse<JsonApiResource<TotpEnrollmentResource>>,
+            JsonApiResponse<JsonApiResource<RecoveryCodesResource>>,
+            JsonApiResponse<JsonApiResource<PasskeyResource>>,
+            JsonApiResponse<Vec<JsonApiResource<PasskeyResource>>>,
+            JsonApiResponse<JsonApiResource<PasskeyCreationOptionsResource>>,
+            JsonApiResponse<JsonApiResource<PasskeyRequestOptionsResource>>,
             JsonApiResponse<serde_json::Value>,
 
             // JSON:API Metadata and Links
@@ -110,11 +257,17 @@ use utoipa::OpenApi;
     tags(
         (name = "Client / Auth", description = "Client Authentication endpoints"),
         (name = "Client / User", description = "User endpoints"),
+        (name = "Client / Me", description = "Endpoints for the authenticated user"),
+        (name = "Client / Group", description = "Endpoints for the groups of the authenticated user"),
         (name = "Admin / Auth", description = "Administrator Authentication endpoints"),
+        (name = "Admin / Me", description = "Endpoints for the authenticated administrator"),
         (name = "Admin / Administrator Management", description = "Administrator management endpoints"),
         (name = "Admin / User Management", description = "User management endpoints"),
         (name = "Admin / Role Management", description = "Role management endpoints"),
-        (name = "Admin / Permission Management", description = "Permission management endpoints")
+        (name = "Admin / Group Management", description = "Group management endpoints"),
+        (name = "Admin / Permission Management", description = "Permission management endpoints"),
+        (name = "Discovery", description = "Token verification keys and metadata"),
+        (name = "OAuth", description = "Endpoints for other services")
     ),
     modifiers(&SecurityAddon)
 )]
@@ -136,7 +289,11 @@ impl Modify for SecurityAddon {
                         .bearer_format("JWT")
                         .build(),
                 ),
-            )
+            );
+            components.add_security_scheme(
+                "client_basic_auth",
+                SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Basic).build()),
+            );
         }
     }
 }
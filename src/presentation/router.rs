@@ -23,10 +23,19 @@ pub fn app(state: AppState) -> anyhow::Result<Router> {
                 ),
         )
         .route("/health", get(client::handlers::health::health_check))
+        // Token verification metadata for other services
+        .route(
+            "/.well-known/jwks.json",
+            get(client::handlers::well_known::jwks),
+        )
+        .route(
+            "/.well-known/openid-configuration",
+            get(client::handlers::well_known::openid_configuration),
+        )
         // Client routes (Auth, Users) nested under /api/v1
         .nest("/api/v1", client::routes::routes())
         // Admin routes nested under /api/v1/admin
-        .nest("/api/v1/admin", admin::routes::routes(state.clone()))
+        .nest("/api/v1/admin", admin::routes::routes())
         .layer(CompressionLayer::new())
         .layer(TraceLayer::new_for_http())
         .layer(middleware::cors::cors_layer()?)
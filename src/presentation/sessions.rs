@@ -0,0 +1,67 @@
+use crate::application::sessions::list::ListSessionsUseCase;
+use crate::application::sessions::revoke::RevokeSessionUseCase;
+use crate::infrastructure::db::DbPool;
+use crate::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
+use crate::presentation::dtos::SessionResource;
+use crate::shared::error::AppError;
+use crate::shared::response::{JsonApiMeta, JsonApiResource, JsonApiResponse};
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Respond with the active sessions of a user or administrator
+pub(crate) async fn list_sessions(
+    pool: DbPool,
+    owner_id: Uuid,
+    owner_type: &str,
+) -> Result<Response, AppError> {
+    let repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
+    let use_case = ListSessionsUseCase::new(repo);
+
+    let sessions = use_case.execute(owner_id, owner_type).await?;
+    let total = sessions.len() as i64;
+
+    let resources: Vec<JsonApiResource<SessionResource>> = sessions
+        .into_iter()
+        .map(|session| {
+            JsonApiResource::new(
+                "sessions",
+                session.id.to_string(),
+                SessionResource::from(session),
+            )
+        })
+        .collect();
+
+    let meta = JsonApiMeta::new().with_total(total);
+
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(resources).with_meta(meta)),
+    )
+        .into_response())
+}
+
+/// Revoke a session of a user or administrator
+pub(crate) async fn revoke_session(
+    pool: DbPool,
+    owner_id: Uuid,
+    owner_type: &str,
+    session_id: Uuid,
+) -> Result<Response, AppError> {
+    let repo = Arc::new(PostgresRefreshTokenRepository::new(pool));
+    let use_case = RevokeSessionUseCase::new(repo);
+
+    use_case.execute(owner_id, owner_type, session_id).await?;
+
+    let meta = JsonApiMeta::new().with_extra(json!({ "revoked": true }));
+    Ok((
+        StatusCode::OK,
+        Json(JsonApiResponse::new(json!(null)).with_meta(meta)),
+    )
+        .into_response())
+}
@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
 use serde::Serialize;
@@ -123,6 +123,13 @@ pub enum AppError {
     Forbidden(String),
     #[error("Unprocessable Entity: {0}")]
     UnprocessableEntity(String),
+    /// Login attempts are throttled; `code` tells back-off and lockout apart
+    #[error("Too many attempts: {detail}")]
+    TooManyAttempts {
+        code: &'static str,
+        detail: String,
+        retry_after_secs: i64,
+    },
     #[error("Internal server error: {0}")]
     InternalServerError(#[from] anyhow::Error),
 }
@@ -275,6 +282,23 @@ impl IntoResponse for AppError {
                 )
                     .into_response()
             }
+            AppError::TooManyAttempts {
+                code,
+                detail,
+                retry_after_secs,
+            } => {
+                let error =
+                    JsonApiError::new(StatusCode::TOO_MANY_REQUESTS, "Too Many Requests", detail)
+                        .with_code(code);
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(header::RETRY_AFTER, retry_after_secs.max(1).to_string())],
+                    Json(ErrorResponse {
+                        errors: vec![error],
+                    }),
+                )
+                    .into_response()
+            }
             AppError::InternalServerError(e) => {
                 tracing::error!("Internal server error: {:?}", e);
                 let error = JsonApiError::new(
@@ -416,6 +440,26 @@ mod tests {
         assert_eq!(body_json["errors"][0]["code"], "forbidden");
     }
 
+    #[tokio::test]
+    async fn test_too_many_attempts_error_response() {
+        let err = AppError::TooManyAttempts {
+            code: "account_locked",
+            detail: "Account is temporarily locked".to_string(),
+            retry_after_secs: 900,
+        };
+        let response = err.into_response();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "900");
+
+        let body_bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+
+        assert_eq!(body_json["errors"][0]["status"], "429");
+        assert_eq!(body_json["errors"][0]["title"], "Too Many Requests");
+        assert_eq!(body_json["errors"][0]["code"], "account_locked");
+    }
+
     #[tokio::test]
     async fn test_internal_server_error_response() {
         let err = AppError::InternalServerError(anyhow::anyhow!("Something went wrong"));
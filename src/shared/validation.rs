@@ -1,9 +1,12 @@
 use crate::shared::error::{AppError, FieldError};
 use axum::{
-    Json,
+    Form, Json,
     extract::{FromRequest, Request},
 };
 use serde::de::DeserializeOwned;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
 use validator::Validate;
 
 fn format_validation_error(err: &validator::ValidationError) -> String {
@@ -111,6 +114,29 @@ where
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedForm<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedForm<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Form(value) = Form::<T>::from_request(req, state).await.map_err(|e| {
+            AppError::ValidationError(vec![FieldError::new("request_body", e.to_string())])
+        })?;
+
+        value
+            .validate()
+            .map_err(|e| AppError::ValidationError(flatten_validation_errors(e)))?;
+
+        Ok(ValidatedForm(value))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct MaybeValidatedJson<T>(pub Option<T>);
 
@@ -137,6 +163,144 @@ where
     }
 }
 
+/// Rules a new password has to satisfy
+#[derive(Debug, Clone)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Reject passwords containing the account's username or the name part of its email
+    pub disallow_identifiers: bool,
+    /// Accept a new password in profile updates, which do not ask for the current one
+    pub allow_change_without_current_password: bool,
+    /// Lowercased passwords known from breaches
+    pub breached_passwords: Arc<HashSet<String>>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            disallow_identifiers: true,
+            allow_change_without_current_password: false,
+            breached_passwords: Arc::new(HashSet::new()),
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Reject passwords from a breached-password list, see [`Self::load_breached_passwords`]
+    pub fn with_breached_passwords(mut self, breached_passwords: Arc<HashSet<String>>) -> Self {
+        self.breached_passwords = breached_passwords;
+        self
+    }
+
+    /// Read a list with one password per line, blank lines and `#` comments are skipped
+    pub fn load_breached_passwords(path: impl AsRef<Path>) -> std::io::Result<HashSet<String>> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_lowercase)
+            .collect())
+    }
+
+    /// Message for the first rule the password breaks.
+    ///
+    /// `identifiers` are the account's username and email address.
+    pub fn check(&self, password: &str, identifiers: &[&str]) -> Result<(), String> {
+        let length = password.chars().count();
+        if length < self.min_length {
+            return Err(format!(
+                "Password must be at least {} characters",
+                self.min_length
+            ));
+        }
+        if length > self.max_length {
+            return Err(format!(
+                "Password must be at most {} characters",
+                self.max_length
+            ));
+        }
+
+        let classes = [
+            (
+                self.require_lowercase,
+                "a lowercase letter",
+                char::is_lowercase as fn(char) -> bool,
+            ),
+            (
+                self.require_uppercase,
+                "an uppercase letter",
+                char::is_uppercase,
+            ),
+            (self.require_digit, "a digit", |c: char| c.is_ascii_digit()),
+            (self.require_symbol, "a symbol", |c: char| {
+                !c.is_alphanumeric()
+            }),
+        ];
+        for (required, name, matches) in classes {
+            if required && !password.chars().any(matches) {
+                return Err(format!("Password must contain {}", name));
+            }
+        }
+
+        let lowercased = password.to_lowercase();
+        if self.disallow_identifiers {
+            let contains_identifier = identifiers
+                .iter()
+                .map(|identifier| {
+                    identifier
+                        .split('@')
+                        .next()
+                        .unwrap_or_default()
+                        .to_lowercase()
+                })
+                // Very short names would reject too many unrelated passwords
+                .filter(|identifier| identifier.chars().count() >= 3)
+                .any(|identifier| lowercased.contains(&identifier));
+            if contains_identifier {
+                return Err("Password must not contain your username or email address".to_string());
+            }
+        }
+
+        if self.breached_passwords.contains(&lowercased) {
+            return Err(
+                "Password has appeared in a data breach, choose a different one".to_string(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validate `password` as the given request field
+    pub fn validate(
+        &self,
+        field: &str,
+        password: &str,
+        identifiers: &[&str],
+    ) -> Result<(), AppError> {
+        self.check(password, identifiers)
+            .map_err(|message| AppError::ValidationError(vec![FieldError::new(field, message)]))
+    }
+}
+
+/// Password policies per audience, administrators usually get the stricter one
+#[derive(Debug, Clone, Default)]
+pub struct PasswordPolicies {
+    pub users: Arc<PasswordPolicy>,
+    pub administrators: Arc<PasswordPolicy>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +371,97 @@ mod tests {
             _ => panic!("Expected ValidationError"),
         }
     }
+
+    #[test]
+    fn test_password_policy_rules() {
+        let policy = PasswordPolicy {
+            require_uppercase: true,
+            require_digit: true,
+            ..PasswordPolicy::default()
+        };
+
+        assert!(policy.check("Sunflower42", &[]).is_ok());
+        assert_eq!(
+            policy.check("Sh0rt", &[]).unwrap_err(),
+            "Password must be at least 8 characters"
+        );
+        assert_eq!(
+            policy.check(&"A1".repeat(65), &[]).unwrap_err(),
+            "Password must be at most 128 characters"
+        );
+        assert_eq!(
+            policy.check("sunflower42", &[]).unwrap_err(),
+            "Password must contain an uppercase letter"
+        );
+        assert_eq!(
+            policy.check("Sunflowers", &[]).unwrap_err(),
+            "Password must contain a digit"
+        );
+    }
+
+    #[test]
+    fn test_password_policy_rejects_identifiers() {
+        let policy = PasswordPolicy::default();
+        let identifiers = ["johndoe", "jane.smith@example.com"];
+
+        assert!(policy.check("xxJohnDoe99", &identifiers).is_err());
+        assert!(policy.check("jane.smith2024", &identifiers).is_err());
+        assert!(policy.check("correct horse", &identifiers).is_ok());
+        // Too short to be meaningful
+        assert!(policy.check("abcdefgh", &["ab"]).is_ok());
+    }
+
+    #[test]
+    fn test_password_policy_rejects_breached_passwords() {
+        let path = std::env::temp_dir().join(format!("breached-{}.txt", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "# common passwords\nPassword123\n\nletmein99\n").unwrap();
+        let breached = PasswordPolicy::load_breached_passwords(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(breached.len(), 2);
+
+        let policy = PasswordPolicy::default().with_breached_passwords(Arc::new(breached));
+        match policy.validate("password", "password123", &[]).unwrap_err() {
+            AppError::ValidationError(errors) => {
+                assert_eq!(errors[0].field, "password");
+                assert!(errors[0].message.contains("data breach"));
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+        assert!(policy.validate("password", "letmein100", &[]).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validated_form_success() {
+        let req = Request::builder()
+            .method("POST")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("name=John&email=john%40example.com"))
+            .unwrap();
+
+        let ValidatedForm(data) = ValidatedForm::<TestData>::from_request(req, &())
+            .await
+            .unwrap();
+        assert_eq!(data.name, "John");
+        assert_eq!(data.email, "john@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_validated_form_validation_error() {
+        let req = Request::builder()
+            .method("POST")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from("name=Jo&email=john%40example.com"))
+            .unwrap();
+
+        match ValidatedForm::<TestData>::from_request(req, &())
+            .await
+            .unwrap_err()
+        {
+            AppError::ValidationError(errors) => {
+                assert!(errors.iter().any(|e| e.field == "name"));
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+    }
 }
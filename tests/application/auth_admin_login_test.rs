@@ -1,6 +1,7 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
-use caxur::application::auth::admin_login::{AdminLoginRequest, AdminLoginUseCase};
+use caxur::application::auth::admin_login::{
+    AdminLoginRequest, AdminLoginResponse, AdminLoginUseCase,
+};
 use caxur::domain::administrators::{AdministratorRepository, NewAdministrator};
 use caxur::domain::password::PasswordHashingService;
 use caxur::infrastructure::password::PasswordService;
@@ -53,7 +54,11 @@ async fn test_admin_login_success() {
         password: password.to_string(),
     };
 
-    let response = use_case.execute(req).await.expect("Login failed");
+    let AdminLoginResponse::Authenticated(response) =
+        use_case.execute(req).await.expect("Login failed")
+    else {
+        panic!("Expected tokens without a second factor");
+    };
 
     assert!(!response.access_token.is_empty());
     assert!(!response.refresh_token.is_empty());
@@ -155,7 +160,9 @@ async fn test_admin_login_invalid_password() {
 // Faulty Mocks for Error Handling Tests
 
 use async_trait::async_trait;
-use caxur::domain::administrators::{Administrator, UpdateAdministrator};
+use caxur::domain::administrators::{
+    Administrator, AssignedRole, RoleAssignment, UpdateAdministrator,
+};
 use caxur::domain::permissions::Permission;
 
 struct FaultyAdministratorRepository;
@@ -195,6 +202,7 @@ impl AdministratorRepository for FaultyAdministratorRepository {
         &self,
         _admin_id: uuid::Uuid,
         _role_ids: Vec<uuid::Uuid>,
+        _assignment: RoleAssignment,
     ) -> Result<(), anyhow::Error> {
         unimplemented!()
     }
@@ -202,15 +210,25 @@ impl AdministratorRepository for FaultyAdministratorRepository {
         &self,
         _admin_id: uuid::Uuid,
         _role_ids: Vec<uuid::Uuid>,
+        _revoked_by: uuid::Uuid,
     ) -> Result<(), anyhow::Error> {
         unimplemented!()
     }
+    async fn find_role_assignments(
+        &self,
+        _admin_id: uuid::Uuid,
+    ) -> Result<Vec<AssignedRole>, anyhow::Error> {
+        unimplemented!()
+    }
     async fn get_permissions(
         &self,
         _admin_id: uuid::Uuid,
     ) -> Result<Vec<Permission>, anyhow::Error> {
         unimplemented!()
     }
+    async fn delete_expired_role_assignments(&self) -> Result<u64, anyhow::Error> {
+        unimplemented!()
+    }
 }
 
 struct FaultyPasswordService;
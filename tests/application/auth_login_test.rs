@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::auth::login::{LoginRequest, LoginUseCase};
 use caxur::domain::password::PasswordHashingService;
 use caxur::domain::users::{NewUser, UserRepository};
@@ -115,6 +114,10 @@ impl UserRepository for FaultyUserRepository {
         unimplemented!()
     }
 
+    async fn mark_email_verified(&self, _id: uuid::Uuid) -> Result<bool, anyhow::Error> {
+        unimplemented!()
+    }
+
     async fn count(&self) -> Result<i64, anyhow::Error> {
         unimplemented!()
     }
@@ -122,6 +125,37 @@ impl UserRepository for FaultyUserRepository {
     async fn find_all(&self, _limit: i64, _offset: i64) -> Result<Vec<User>, anyhow::Error> {
         unimplemented!()
     }
+
+    async fn attach_roles(
+        &self,
+        _user_id: uuid::Uuid,
+        _role_ids: Vec<uuid::Uuid>,
+    ) -> Result<(), anyhow::Error> {
+        unimplemented!()
+    }
+
+    async fn detach_roles(
+        &self,
+        _user_id: uuid::Uuid,
+        _role_ids: Vec<uuid::Uuid>,
+    ) -> Result<(), anyhow::Error> {
+        unimplemented!()
+    }
+
+    async fn get_permissions(
+        &self,
+        _user_id: uuid::Uuid,
+    ) -> Result<Vec<caxur::domain::permissions::Permission>, anyhow::Error> {
+        unimplemented!()
+    }
+
+    async fn get_group_permissions(
+        &self,
+        _user_id: uuid::Uuid,
+        _group_id: uuid::Uuid,
+    ) -> Result<Option<Vec<caxur::domain::permissions::Permission>>, anyhow::Error> {
+        unimplemented!()
+    }
 }
 
 struct FaultyPasswordService;
@@ -212,3 +246,52 @@ async fn test_login_password_service_error() {
         Err(caxur::shared::error::AppError::InternalServerError(_))
     ));
 }
+
+#[tokio::test]
+#[serial]
+async fn test_login_requires_verified_email_when_enabled() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+    let user_repo = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let refresh_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let auth_service = common::create_test_auth_service();
+    let password_service = Arc::new(PasswordService::new());
+
+    let prefix = uuid::Uuid::new_v4().to_string();
+    let email = format!("unverified_{}@example.com", prefix);
+    let hash = password_service.hash_password("password123").unwrap();
+
+    let user = user_repo
+        .create(NewUser {
+            username: format!("unverified_{}", prefix),
+            email: email.clone(),
+            password_hash: hash,
+        })
+        .await
+        .expect("Failed to create user");
+    assert!(user.email_verified_at.is_none());
+
+    let use_case = LoginUseCase::new(
+        user_repo.clone(),
+        refresh_repo,
+        auth_service,
+        password_service,
+        3600,
+        7200,
+    )
+    .with_require_verified_email(true);
+
+    let login_request = || LoginRequest {
+        email: email.clone(),
+        password: "password123".to_string(),
+    };
+
+    let result = use_case.execute(login_request()).await;
+    assert!(matches!(
+        result,
+        Err(caxur::shared::error::AppError::Forbidden(_))
+    ));
+
+    user_repo.mark_email_verified(user.id).await.unwrap();
+    assert!(use_case.execute(login_request()).await.is_ok());
+}
@@ -1,8 +1,9 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::roles::create::{CreateRoleRequest, CreateRoleUseCase};
 use caxur::domain::access_scope::AccessScope;
+use caxur::infrastructure::repositories::groups::PostgresGroupRepository;
 use caxur::infrastructure::repositories::roles::PostgresRoleRepository;
+use caxur::shared::error::AppError;
 use serial_test::serial;
 use std::sync::Arc;
 
@@ -12,7 +13,8 @@ async fn test_create_role_success() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
     let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
-    let use_case = CreateRoleUseCase::new(repo);
+    let group_repo = Arc::new(PostgresGroupRepository::new(pool.clone()));
+    let use_case = CreateRoleUseCase::new(repo, group_repo);
 
     let prefix = uuid::Uuid::new_v4().to_string();
     let req = CreateRoleRequest {
@@ -34,7 +36,8 @@ async fn test_create_role_duplicate_name() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
     let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
-    let use_case = CreateRoleUseCase::new(repo);
+    let group_repo = Arc::new(PostgresGroupRepository::new(pool.clone()));
+    let use_case = CreateRoleUseCase::new(repo, group_repo);
 
     let prefix = uuid::Uuid::new_v4().to_string();
     let name = format!("DupRole_{}", prefix);
@@ -60,3 +63,23 @@ async fn test_create_role_duplicate_name() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+#[serial]
+async fn test_create_group_role_requires_existing_group() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+    let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
+    let group_repo = Arc::new(PostgresGroupRepository::new(pool.clone()));
+    let use_case = CreateRoleUseCase::new(repo, group_repo);
+
+    let req = CreateRoleRequest {
+        name: "Group editor".to_string(),
+        description: None,
+        scope: AccessScope::User,
+        group_id: Some(uuid::Uuid::new_v4()),
+    };
+    let result = use_case.execute(req).await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
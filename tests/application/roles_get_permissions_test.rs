@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::roles::attach_permission::AttachPermissionUseCase;
 use caxur::application::roles::get_permissions::GetRolePermissionsUseCase;
 use caxur::domain::access_scope::AccessScope;
@@ -33,8 +32,8 @@ async fn test_get_permissions_success() {
         .expect("Failed to create role");
 
     let permissions = vec![
-        Permission::AdministratorManagement,
-        Permission::RoleManagement,
+        Permission::from_static("administrators.*"),
+        Permission::from_static("roles.read"),
     ];
     attach_use_case
         .execute(role.id, permissions.clone())
@@ -46,9 +45,11 @@ async fn test_get_permissions_success() {
         .await
         .expect("Failed to get permissions");
 
-    assert!(retrieved_permissions.contains(&Permission::AdministratorManagement));
-    assert!(retrieved_permissions.contains(&Permission::RoleManagement));
-    assert_eq!(retrieved_permissions.len(), 2);
+    let direct = &retrieved_permissions.direct;
+    assert!(direct.contains(&Permission::from_static("administrators.*")));
+    assert!(direct.contains(&Permission::from_static("roles.read")));
+    assert_eq!(direct.len(), 2);
+    assert!(retrieved_permissions.inherited.is_empty());
 }
 
 #[tokio::test]
@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::roles::attach_permission::AttachPermissionUseCase;
 use caxur::application::roles::detach_permission::DetachPermissionUseCase;
 use caxur::domain::access_scope::AccessScope;
@@ -32,8 +31,8 @@ async fn test_attach_permission_success() {
         .expect("Failed to create role");
 
     let permissions = vec![
-        Permission::AdministratorManagement,
-        Permission::RoleManagement,
+        Permission::from_static("administrators.*"),
+        Permission::from_static("roles.read"),
     ];
 
     use_case
@@ -48,8 +47,8 @@ async fn test_attach_permission_success() {
     // We expect attached permissions to contain what we added.
     // Note: implementation of get_permissions might differ, let's assume it returns Vec<Permission>.
     // Verify at least one match.
-    assert!(attached.contains(&Permission::AdministratorManagement));
-    assert!(attached.contains(&Permission::RoleManagement));
+    assert!(attached.contains(&Permission::from_static("administrators.*")));
+    assert!(attached.contains(&Permission::from_static("roles.read")));
 }
 
 #[tokio::test]
@@ -61,7 +60,7 @@ async fn test_attach_permission_not_found() {
     let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
     let use_case = AttachPermissionUseCase::new(repo);
 
-    let permissions = vec![Permission::AdministratorManagement];
+    let permissions = vec![Permission::from_static("administrators.*")];
     let result = use_case.execute(Uuid::new_v4(), permissions).await;
 
     match result {
@@ -94,8 +93,8 @@ async fn test_detach_permission_success() {
         .expect("Failed to create role");
 
     let permissions = vec![
-        Permission::AdministratorManagement,
-        Permission::RoleManagement,
+        Permission::from_static("administrators.*"),
+        Permission::from_static("roles.read"),
     ];
     attach_use_case
         .execute(role.id, permissions.clone())
@@ -104,7 +103,7 @@ async fn test_detach_permission_success() {
 
     // Detach RoleManagement
     detach_use_case
-        .execute(role.id, vec![Permission::RoleManagement])
+        .execute(role.id, vec![Permission::from_static("roles.read")])
         .await
         .expect("Failed to detach permission");
 
@@ -112,8 +111,8 @@ async fn test_detach_permission_success() {
         .get_permissions(role.id)
         .await
         .expect("Failed to get permissions");
-    assert!(attached.contains(&Permission::AdministratorManagement));
-    assert!(!attached.contains(&Permission::RoleManagement));
+    assert!(attached.contains(&Permission::from_static("administrators.*")));
+    assert!(!attached.contains(&Permission::from_static("roles.read")));
 }
 
 #[tokio::test]
@@ -125,7 +124,7 @@ async fn test_detach_permission_not_found() {
     let repo = Arc::new(PostgresRoleRepository::new(pool.clone()));
     let use_case = DetachPermissionUseCase::new(repo);
 
-    let permissions = vec![Permission::AdministratorManagement];
+    let permissions = vec![Permission::from_static("administrators.*")];
     let result = use_case.execute(Uuid::new_v4(), permissions).await;
 
     match result {
@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::roles::update::{UpdateRoleRequest, UpdateRoleUseCase};
 use caxur::domain::access_scope::AccessScope;
 use caxur::domain::roles::RoleRepository;
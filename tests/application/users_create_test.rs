@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::users::create::{CreateUserRequest, CreateUserUseCase};
 use caxur::infrastructure::password::PasswordService;
 use caxur::infrastructure::repositories::users::PostgresUserRepository;
@@ -56,7 +55,7 @@ async fn test_create_user_duplicate_email() {
     // Create second user with same email
     let req2 = CreateUserRequest {
         username: format!("user2_{}", prefix),
-        email: email,
+        email,
         password: "password456".to_string(),
     };
     let result = use_case.execute(req2).await;
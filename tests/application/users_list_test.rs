@@ -1,5 +1,4 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use caxur::application::users::list::{ListUsersRequest, ListUsersUseCase, PageParams};
 use caxur::domain::users::{NewUser, UserRepository};
 use caxur::infrastructure::repositories::users::PostgresUserRepository;
@@ -1,16 +1,26 @@
 use crate::common;
-use crate::setup_test_db_or_skip;
 use anyhow::anyhow;
 use caxur::application::users::update::{UpdateUserRequest, UpdateUserUseCase};
 use caxur::domain::password::PasswordHashingService;
 use caxur::domain::users::UserRepository;
 use caxur::infrastructure::password::PasswordService;
+use caxur::infrastructure::repositories::access_token_denylist::PostgresAccessTokenDenylist;
+use caxur::infrastructure::repositories::refresh_tokens::PostgresRefreshTokenRepository;
 use caxur::infrastructure::repositories::users::PostgresUserRepository;
 use caxur::shared::error::AppError;
+use caxur::shared::validation::PasswordPolicy;
 use serial_test::serial;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Policy that still accepts a new password in profile updates
+fn allow_password_updates() -> Arc<PasswordPolicy> {
+    Arc::new(PasswordPolicy {
+        allow_change_without_current_password: true,
+        ..PasswordPolicy::default()
+    })
+}
+
 #[tokio::test]
 #[serial]
 async fn test_update_user_success() {
@@ -19,7 +29,15 @@ async fn test_update_user_success() {
 
     let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let password_service = Arc::new(PasswordService::new());
-    let use_case = UpdateUserUseCase::new(repo.clone(), password_service.clone());
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case = UpdateUserUseCase::new(
+        repo.clone(),
+        password_service.clone(),
+        refresh_token_repo,
+        denylist,
+    )
+    .with_password_policy(allow_password_updates());
 
     // Create a user first
     let prefix = Uuid::new_v4().to_string();
@@ -64,7 +82,9 @@ async fn test_update_user_not_found() {
 
     let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let password_service = Arc::new(PasswordService::new());
-    let use_case = UpdateUserUseCase::new(repo, password_service);
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case = UpdateUserUseCase::new(repo, password_service, refresh_token_repo, denylist);
 
     let req = UpdateUserRequest {
         username: Some("new_name".to_string()),
@@ -90,7 +110,10 @@ async fn test_update_user_email_conflict() {
 
     let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let password_service = Arc::new(PasswordService::new());
-    let use_case = UpdateUserUseCase::new(repo.clone(), password_service);
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case =
+        UpdateUserUseCase::new(repo.clone(), password_service, refresh_token_repo, denylist);
 
     let prefix = Uuid::new_v4().to_string();
     // User 1
@@ -150,7 +173,11 @@ async fn test_update_user_hash_error() {
 
     let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let password_service = Arc::new(FaultyPasswordHashingService);
-    let use_case = UpdateUserUseCase::new(repo.clone(), password_service);
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case =
+        UpdateUserUseCase::new(repo.clone(), password_service, refresh_token_repo, denylist)
+            .with_password_policy(allow_password_updates());
 
     // Create user
     let user = repo
@@ -189,7 +216,10 @@ async fn test_update_user_own_email() {
 
     let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let password_service = Arc::new(PasswordService::new());
-    let use_case = UpdateUserUseCase::new(repo.clone(), password_service);
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case =
+        UpdateUserUseCase::new(repo.clone(), password_service, refresh_token_repo, denylist);
 
     let prefix = Uuid::new_v4().to_string();
     let user = repo
@@ -211,3 +241,42 @@ async fn test_update_user_own_email() {
     let result = use_case.execute(user.id, req).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+#[serial]
+async fn test_update_user_password_rejected_by_default() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let repo = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let password_service = Arc::new(PasswordService::new());
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let denylist = Arc::new(PostgresAccessTokenDenylist::new(pool.clone(), 900));
+    let use_case =
+        UpdateUserUseCase::new(repo.clone(), password_service, refresh_token_repo, denylist);
+
+    let user = repo
+        .create(caxur::domain::users::NewUser {
+            username: "user_password_update".to_string(),
+            email: "user_password_update@example.com".to_string(),
+            password_hash: "old_hash".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let req = UpdateUserRequest {
+        username: None,
+        email: None,
+        password: Some("newpassword123".to_string()),
+    };
+
+    match use_case.execute(user.id, req).await {
+        Err(AppError::ValidationError(errors)) => {
+            assert_eq!(errors[0].field, "password");
+        }
+        other => panic!("Expected ValidationError, got {:?}", other.map(|u| u.id)),
+    }
+
+    let unchanged = repo.find_by_id(user.id).await.unwrap().unwrap();
+    assert_eq!(unchanged.password_hash, "old_hash");
+}
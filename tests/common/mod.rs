@@ -74,7 +74,7 @@ macro_rules! setup_test_db_or_skip {
 #[allow(dead_code)]
 pub async fn cleanup_test_db(pool: &PgPool) {
     sqlx::query(
-        "TRUNCATE users, user_administrators, refresh_tokens, roles, role_permissions CASCADE",
+        "TRUNCATE users, user_administrators, refresh_tokens, roles, role_permissions, groups, access_token_denylist, access_token_subject_denylist, one_time_tokens, administrator_totp, administrator_recovery_codes, administrator_passkeys, login_attempts, administrator_role_history CASCADE",
     )
     .execute(pool)
     .await
@@ -154,7 +154,7 @@ pub async fn create_admin_with_permissions(pool: &PgPool) -> (Uuid, String) {
         role_id,
         format!("Super Admin {}", Uuid::new_v4()),
         "Test Super Admin",
-        "ADMINISTRATOR"
+        "administrator"
     )
     .execute(pool)
     .await
@@ -183,3 +183,226 @@ pub async fn create_admin_with_permissions(pool: &PgPool) -> (Uuid, String) {
 
     (admin_id, generate_admin_token(admin_id))
 }
+
+/// Create an administrator whose only role grants `permissions`, returns the
+/// administrator id, the role id and an access token
+pub async fn create_admin_with_role(pool: &PgPool, permissions: &[&str]) -> (Uuid, Uuid, String) {
+    let admin_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO user_administrators (id, email, password_hash, first_name, last_name, created_at, updated_at) VALUES ($1, $2, 'hash', 'Test', 'Admin', NOW(), NOW())",
+    )
+    .bind(admin_id)
+    .bind(format!("admin_{}@example.com", admin_id))
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let role_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO roles (id, name, description, scope, created_at, updated_at) VALUES ($1, $2, NULL, 'administrator', NOW(), NOW())",
+    )
+    .bind(role_id)
+    .bind(format!("Role {}", role_id))
+    .execute(pool)
+    .await
+    .unwrap();
+
+    for permission in permissions {
+        sqlx::query("INSERT INTO role_permissions (role_id, permission) VALUES ($1, $2)")
+            .bind(role_id)
+            .bind(permission)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    sqlx::query(
+        "INSERT INTO administrator_roles (administrator_id, role_id, assigned_at) VALUES ($1, $2, NOW())",
+    )
+    .bind(admin_id)
+    .bind(role_id)
+    .execute(pool)
+    .await
+    .unwrap();
+
+    (admin_id, role_id, generate_admin_token(admin_id))
+}
+
+/// Create a user whose only role grants `permissions`, returns the user id,
+/// the role id and an access token
+pub async fn create_user_with_role(pool: &PgPool, permissions: &[&str]) -> (Uuid, Uuid, String) {
+    let user_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO users (id, username, email, password_hash) VALUES ($1, $2, $3, 'hash')",
+    )
+    .bind(user_id)
+    .bind(format!("user_{}", user_id.simple()))
+    .bind(format!("user_{}@example.com", user_id))
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let role_id = Uuid::new_v4();
+    sqlx::query(
+        "INSERT INTO roles (id, name, description, scope, created_at, updated_at) VALUES ($1, $2, NULL, 'user', NOW(), NOW())",
+    )
+    .bind(role_id)
+    .bind(format!("Role {}", role_id))
+    .execute(pool)
+    .await
+    .unwrap();
+
+    for permission in permissions {
+        sqlx::query("INSERT INTO role_permissions (role_id, permission) VALUES ($1, $2)")
+            .bind(role_id)
+            .bind(permission)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    sqlx::query("INSERT INTO user_roles (user_id, role_id) VALUES ($1, $2)")
+        .bind(user_id)
+        .bind(role_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    (user_id, role_id, generate_test_token(user_id))
+}
+
+/// Notification service that keeps sent notifications in memory for assertions
+#[derive(Default)]
+pub struct RecordingNotificationService {
+    sent: std::sync::Mutex<Vec<caxur::domain::notifications::Notification>>,
+}
+
+impl RecordingNotificationService {
+    pub fn sent(&self) -> Vec<caxur::domain::notifications::Notification> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl caxur::domain::notifications::NotificationService for RecordingNotificationService {
+    async fn send(
+        &self,
+        notification: caxur::domain::notifications::Notification,
+    ) -> anyhow::Result<()> {
+        self.sent.lock().unwrap().push(notification);
+        Ok(())
+    }
+}
+
+/// Create app state whose notifications are captured by the returned service
+pub fn create_test_app_state_with_notifications(
+    pool: PgPool,
+) -> (AppState, Arc<RecordingNotificationService>) {
+    let notifications = Arc::new(RecordingNotificationService::default());
+    let state = create_test_app_state(pool).with_notification_service(notifications.clone());
+    (state, notifications)
+}
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+    response::Response,
+};
+use caxur::domain::password::PasswordHashingService;
+use serde_json::json;
+use tower::ServiceExt;
+
+/// Read a JSON body from a response
+pub async fn json_body(response: Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+/// Send a JSON body, optionally with a bearer token
+pub async fn send_json(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    access_token: Option<&str>,
+    body: serde_json::Value,
+) -> Response {
+    let mut builder = Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("content-type", "application/json");
+    if let Some(token) = access_token {
+        builder = builder.header("Authorization", format!("Bearer {}", token));
+    }
+
+    app.clone()
+        .oneshot(builder.body(Body::from(body.to_string())).unwrap())
+        .await
+        .unwrap()
+}
+
+/// POST a JSON body without authentication
+pub async fn post_json(app: &Router, uri: &str, body: serde_json::Value) -> Response {
+    send_json(app, "POST", uri, None, body).await
+}
+
+/// Register a client user with the password "password123", returning the created resource
+pub async fn register_user(app: &Router, email: &str) -> serde_json::Value {
+    let response = post_json(
+        app,
+        "/api/v1/users",
+        json!({
+            "username": format!("user_{}", Uuid::new_v4()),
+            "email": email,
+            "password": "password123"
+        }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    json_body(response).await
+}
+
+/// Create an administrator with a known password, returning its id
+pub async fn create_admin(pool: &PgPool, email: &str, password: &str) -> Uuid {
+    let password_service = caxur::infrastructure::password::PasswordService::new();
+    let hash = password_service.hash_password(password).unwrap();
+    let admin_id = Uuid::new_v4();
+
+    sqlx::query(
+        "INSERT INTO user_administrators (id, email, password_hash, first_name, last_name, created_at, updated_at) VALUES ($1, $2, $3, 'Test', 'Admin', NOW(), NOW())",
+    )
+    .bind(admin_id)
+    .bind(email)
+    .bind(hash)
+    .execute(pool)
+    .await
+    .expect("Failed to create admin");
+
+    admin_id
+}
+
+/// Attempt a login against `uri`, the client or the admin login endpoint
+pub async fn login_response(app: &Router, uri: &str, email: &str, password: &str) -> Response {
+    post_json(app, uri, json!({ "email": email, "password": password })).await
+}
+
+/// Log in and return (access_token, refresh_token)
+pub async fn login(app: &Router, uri: &str, email: &str, password: &str) -> (String, String) {
+    let response = login_response(app, uri, email, password).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = json_body(response).await;
+    (
+        json["data"]["attributes"]["accessToken"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+        json["data"]["attributes"]["refreshToken"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+    )
+}
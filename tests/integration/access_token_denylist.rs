@@ -0,0 +1,406 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use caxur::domain::auth::{AccessTokenDenylist, Claims};
+use caxur::infrastructure::repositories::access_token_denylist::PostgresAccessTokenDenylist;
+use caxur::shared::validation::{PasswordPolicies, PasswordPolicy};
+use serde_json::json;
+use serial_test::serial;
+use std::sync::Arc;
+use time::OffsetDateTime;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: Option<serde_json::Value>,
+) -> axum::response::Response {
+    let mut builder = Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("Authorization", format!("Bearer {}", token));
+
+    let body = match body {
+        Some(body) => {
+            builder = builder.header("content-type", "application/json");
+            Body::from(body.to_string())
+        }
+        None => Body::empty(),
+    };
+
+    app.clone()
+        .oneshot(builder.body(body).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_revokes_access_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "denylist_logout@example.com").await;
+    let (access_token, refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_logout@example.com",
+        "password123",
+    )
+    .await;
+    let (other_access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_logout@example.com",
+        "password123",
+    )
+    .await;
+
+    let response = send(&app, "GET", "/api/v1/me/sessions", &access_token, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        &app,
+        "POST",
+        "/api/v1/auth/logout",
+        &access_token,
+        Some(json!({ "refresh_token": refresh_token })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The access token used to log out is rejected, other sessions keep working
+    let response = send(&app, "GET", "/api/v1/me/sessions", &access_token, None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let json = common::json_body(response).await;
+    assert_eq!(json["errors"][0]["detail"], "Token has been revoked");
+
+    let response = send(
+        &app,
+        "GET",
+        "/api/v1/me/sessions",
+        &other_access_token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_all_revokes_every_access_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "denylist_all@example.com").await;
+    let (first_access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_all@example.com",
+        "password123",
+    )
+    .await;
+    let (second_access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_all@example.com",
+        "password123",
+    )
+    .await;
+
+    let response = send(
+        &app,
+        "POST",
+        "/api/v1/auth/logout-all",
+        &first_access_token,
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for token in [&first_access_token, &second_access_token] {
+        let response = send(&app, "GET", "/api/v1/me/sessions", token, None).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_password_change_revokes_access_tokens() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let user_id = common::register_user(&app, "denylist_password@example.com").await["data"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_password@example.com",
+        "password123",
+    )
+    .await;
+
+    // Changing anything but the password keeps the token valid
+    let uri = format!("/api/v1/users/{}", user_id);
+    let response = send(
+        &app,
+        "PUT",
+        &uri,
+        &access_token,
+        Some(json!({ "username": format!("renamed_{}", Uuid::new_v4()) })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = send(&app, "GET", &uri, &access_token, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        &app,
+        "POST",
+        "/api/v1/me/password",
+        &access_token,
+        Some(json!({ "current_password": "password123", "new_password": "newpassword123" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(&app, "GET", &uri, &access_token, None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Signing in right after the revocation yields a working token
+    let (new_access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_password@example.com",
+        "newpassword123",
+    )
+    .await;
+    let response = send(&app, "GET", &uri, &new_access_token, None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_password_update_revokes_refresh_tokens() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let allow_password_updates = Arc::new(PasswordPolicy {
+        allow_change_without_current_password: true,
+        ..PasswordPolicy::default()
+    });
+    let state =
+        common::create_test_app_state(pool.clone()).with_password_policies(PasswordPolicies {
+            users: allow_password_updates.clone(),
+            administrators: allow_password_updates,
+        });
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let user_id = common::register_user(&app, "denylist_update@example.com").await["data"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (access_token, refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "denylist_update@example.com",
+        "password123",
+    )
+    .await;
+
+    let response = send(
+        &app,
+        "PUT",
+        &format!("/api/v1/users/{}", user_id),
+        &access_token,
+        Some(json!({ "password": "newpassword123" })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/refresh")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "refresh_token": refresh_token }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_deletion_revokes_access_tokens() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, manager_token) = common::create_admin_with_permissions(&pool).await;
+
+    let admin_id = common::create_admin(&pool, "denylist_admin@example.com", "adminpassword").await;
+
+    let (admin_access_token, _) = common::login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "denylist_admin@example.com",
+        "adminpassword",
+    )
+    .await;
+
+    let response = send(
+        &app,
+        "GET",
+        "/api/v1/admin/me/sessions",
+        &admin_access_token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/administrators/{}", admin_id),
+        &manager_token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send(
+        &app,
+        "GET",
+        "/api/v1/admin/me/sessions",
+        &admin_access_token,
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Refresh tokens of the deleted administrator are gone too
+    let remaining: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM refresh_tokens WHERE user_id = $1")
+            .bind(admin_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(remaining, 0);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_postgres_denylist_entries() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let denylist = PostgresAccessTokenDenylist::new(pool.clone(), 900);
+    let user_id = Uuid::new_v4();
+
+    let claims = Claims::new_access_token(user_id, "user".to_string(), 900);
+    assert!(!denylist.is_denied(&claims).await.unwrap());
+
+    // Single tokens
+    let expires_at = OffsetDateTime::from_unix_timestamp(claims.exp).unwrap();
+    denylist.deny_token(&claims.jti, expires_at).await.unwrap();
+    denylist.deny_token(&claims.jti, expires_at).await.unwrap();
+    assert!(denylist.is_denied(&claims).await.unwrap());
+
+    // Subjects: earlier tokens are denied, later ones and other user types are not
+    let mut earlier = Claims::new_access_token(user_id, "user".to_string(), 900);
+    earlier.iat_ms = earlier.iat_ms.map(|ms| ms - 60_000);
+    let mut later = Claims::new_access_token(user_id, "user".to_string(), 900);
+    later.iat_ms = later.iat_ms.map(|ms| ms + 60_000);
+    let admin = Claims::new_access_token(user_id, "admin".to_string(), 900);
+
+    denylist.deny_subject(user_id, "user").await.unwrap();
+    assert!(denylist.is_denied(&earlier).await.unwrap());
+    assert!(!denylist.is_denied(&later).await.unwrap());
+    assert!(!denylist.is_denied(&admin).await.unwrap());
+
+    // A token issued right after the revocation is accepted
+    let reissued = Claims::new_access_token(user_id, "user".to_string(), 900);
+    assert!(!denylist.is_denied(&reissued).await.unwrap());
+
+    // Revocations are compared at millisecond precision, within the same second a token
+    // issued before is denied and one issued after is accepted
+    let revoked_second = reissued.iat + 60;
+    sqlx::query(
+        "UPDATE access_token_subject_denylist SET revoked_before = to_timestamp($1) WHERE user_id = $2",
+    )
+    .bind(revoked_second as f64 + 0.5)
+    .bind(user_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let issued_in_revocation_second = |iat_ms: Option<i64>| {
+        let mut claims = Claims::new_access_token(user_id, "user".to_string(), 900);
+        claims.iat = revoked_second;
+        claims.iat_ms = iat_ms;
+        claims
+    };
+    let just_before = issued_in_revocation_second(Some(revoked_second * 1000 + 499));
+    let just_after = issued_in_revocation_second(Some(revoked_second * 1000 + 501));
+    assert!(denylist.is_denied(&just_before).await.unwrap());
+    assert!(!denylist.is_denied(&just_after).await.unwrap());
+
+    // Tokens without a millisecond issue time count from the start of their second
+    let legacy = issued_in_revocation_second(None);
+    assert!(denylist.is_denied(&legacy).await.unwrap());
+
+    // Nothing has expired yet
+    assert_eq!(denylist.delete_expired().await.unwrap(), 0);
+
+    sqlx::query("UPDATE access_token_denylist SET expires_at = NOW() - INTERVAL '1 second'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        "UPDATE access_token_subject_denylist SET expires_at = NOW() - INTERVAL '1 second'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    assert_eq!(denylist.delete_expired().await.unwrap(), 2);
+    assert!(!denylist.is_denied(&claims).await.unwrap());
+    assert!(!denylist.is_denied(&earlier).await.unwrap());
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -0,0 +1,282 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Every admin route that needs an administrator token
+const PROTECTED_ROUTES: &[(&str, &str)] = &[
+    ("POST", "/api/v1/admin/administrators"),
+    ("GET", "/api/v1/admin/administrators"),
+    ("GET", "/api/v1/admin/administrators/{id}"),
+    ("PUT", "/api/v1/admin/administrators/{id}"),
+    ("DELETE", "/api/v1/admin/administrators/{id}"),
+    ("POST", "/api/v1/admin/administrators/{id}/roles"),
+    ("DELETE", "/api/v1/admin/administrators/{id}/roles"),
+    ("GET", "/api/v1/admin/administrators/{id}/sessions"),
+    ("DELETE", "/api/v1/admin/administrators/{id}/sessions/{id}"),
+    ("POST", "/api/v1/admin/administrators/{id}/unlock"),
+    ("POST", "/api/v1/admin/roles"),
+    ("GET", "/api/v1/admin/roles"),
+    ("GET", "/api/v1/admin/roles/{id}"),
+    ("PUT", "/api/v1/admin/roles/{id}"),
+    ("DELETE", "/api/v1/admin/roles/{id}"),
+    ("POST", "/api/v1/admin/roles/{id}/permissions"),
+    ("GET", "/api/v1/admin/roles/{id}/permissions"),
+    ("DELETE", "/api/v1/admin/roles/{id}/permissions"),
+    ("POST", "/api/v1/admin/roles/{id}/parents"),
+    ("DELETE", "/api/v1/admin/roles/{id}/parents"),
+    ("POST", "/api/v1/admin/groups"),
+    ("GET", "/api/v1/admin/groups"),
+    ("GET", "/api/v1/admin/groups/{id}"),
+    ("PUT", "/api/v1/admin/groups/{id}"),
+    ("DELETE", "/api/v1/admin/groups/{id}"),
+    ("GET", "/api/v1/admin/groups/{id}/members"),
+    ("POST", "/api/v1/admin/groups/{id}/members"),
+    ("DELETE", "/api/v1/admin/groups/{id}/members"),
+    ("GET", "/api/v1/admin/permissions"),
+    ("GET", "/api/v1/admin/permissions/cache"),
+    ("GET", "/api/v1/admin/users"),
+    ("POST", "/api/v1/admin/users/{id}/roles"),
+    ("DELETE", "/api/v1/admin/users/{id}/roles"),
+    ("GET", "/api/v1/admin/users/{id}/sessions"),
+    ("DELETE", "/api/v1/admin/users/{id}/sessions/{id}"),
+    ("POST", "/api/v1/admin/users/{id}/unlock"),
+    ("POST", "/api/v1/admin/auth/logout"),
+    ("POST", "/api/v1/admin/auth/logout-all"),
+    ("POST", "/api/v1/admin/auth/webauthn/register/options"),
+    ("POST", "/api/v1/admin/auth/webauthn/register"),
+    ("GET", "/api/v1/admin/auth/webauthn/credentials"),
+    ("DELETE", "/api/v1/admin/auth/webauthn/credentials/{id}"),
+    ("GET", "/api/v1/admin/me/sessions"),
+    ("DELETE", "/api/v1/admin/me/sessions/{id}"),
+    ("POST", "/api/v1/admin/me/password"),
+    ("GET", "/api/v1/admin/me/mfa"),
+    ("POST", "/api/v1/admin/me/mfa/totp"),
+    ("POST", "/api/v1/admin/me/mfa/totp/confirm"),
+    ("POST", "/api/v1/admin/me/mfa/totp/disable"),
+    ("POST", "/api/v1/admin/me/mfa/recovery-codes"),
+];
+
+async fn call(app: &Router, method: &str, path: &str, token: &str) -> StatusCode {
+    let uri = path.replace("{id}", &Uuid::new_v4().to_string());
+
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from("{}"))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_user_token_is_forbidden_on_every_admin_route() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let token = common::generate_test_token(Uuid::new_v4());
+
+    for (method, path) in PROTECTED_ROUTES {
+        assert_eq!(
+            call(&app, method, path, &token).await,
+            StatusCode::FORBIDDEN,
+            "{} {} accepted a user token",
+            method,
+            path
+        );
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_missing_token_is_unauthorized_on_every_admin_route() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    for (method, path) in PROTECTED_ROUTES {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(path.replace("{id}", &Uuid::new_v4().to_string()))
+                    .method(*method)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.status(),
+            StatusCode::UNAUTHORIZED,
+            "{} {} accepted a request without a token",
+            method,
+            path
+        );
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_namespace_wildcards_grant_administrator_and_user_routes() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, _, token) =
+        common::create_admin_with_role(&pool, &["administrators.*", "users.*", "permissions.*"])
+            .await;
+
+    for path in [
+        "/api/v1/admin/administrators",
+        "/api/v1/admin/users",
+        "/api/v1/admin/permissions",
+    ] {
+        assert_eq!(call(&app, "GET", path, &token).await, StatusCode::OK);
+    }
+    assert_eq!(
+        call(&app, "GET", "/api/v1/admin/roles", &token).await,
+        StatusCode::FORBIDDEN
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_role_permissions_cannot_manage_administrators_or_users() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, _, token) = common::create_admin_with_role(&pool, &["roles.*", "permissions.*"]).await;
+
+    for (method, path) in [
+        ("GET", "/api/v1/admin/administrators"),
+        ("POST", "/api/v1/admin/administrators"),
+        ("DELETE", "/api/v1/admin/administrators/{id}"),
+        ("POST", "/api/v1/admin/administrators/{id}/roles"),
+        ("GET", "/api/v1/admin/users"),
+        ("POST", "/api/v1/admin/users/{id}/unlock"),
+    ] {
+        assert_eq!(
+            call(&app, method, path, &token).await,
+            StatusCode::FORBIDDEN,
+            "{} {} allowed with role permissions only",
+            method,
+            path
+        );
+    }
+
+    // The permission catalog is needed to edit roles
+    assert_eq!(
+        call(&app, "GET", "/api/v1/admin/roles", &token).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        call(&app, "GET", "/api/v1/admin/permissions", &token).await,
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_read_permission_does_not_grant_writes() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (admin_id, _, token) =
+        common::create_admin_with_role(&pool, &["administrators.read", "*.sessions.read"]).await;
+
+    for path in [
+        "/api/v1/admin/administrators".to_string(),
+        format!("/api/v1/admin/administrators/{}", admin_id),
+        format!("/api/v1/admin/administrators/{}/sessions", admin_id),
+    ] {
+        assert_eq!(call(&app, "GET", &path, &token).await, StatusCode::OK);
+    }
+
+    for (method, path) in [
+        ("POST", "/api/v1/admin/administrators"),
+        ("PUT", "/api/v1/admin/administrators/{id}"),
+        ("DELETE", "/api/v1/admin/administrators/{id}"),
+        ("POST", "/api/v1/admin/administrators/{id}/roles"),
+        ("DELETE", "/api/v1/admin/administrators/{id}/sessions/{id}"),
+        ("POST", "/api/v1/admin/administrators/{id}/unlock"),
+        ("GET", "/api/v1/admin/users"),
+    ] {
+        assert_eq!(
+            call(&app, method, path, &token).await,
+            StatusCode::FORBIDDEN,
+            "{} {} allowed with read permissions only",
+            method,
+            path
+        );
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_without_permissions_can_only_manage_own_account() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, _, token) = common::create_admin_with_role(&pool, &[]).await;
+
+    assert_eq!(
+        call(&app, "GET", "/api/v1/admin/me/sessions", &token).await,
+        StatusCode::OK
+    );
+    for path in [
+        "/api/v1/admin/administrators",
+        "/api/v1/admin/users",
+        "/api/v1/admin/roles",
+        "/api/v1/admin/permissions",
+    ] {
+        assert_eq!(
+            call(&app, "GET", path, &token).await,
+            StatusCode::FORBIDDEN,
+            "GET {} allowed without permissions",
+            path
+        );
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -18,9 +18,7 @@ async fn test_create_admin() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    // Generate a valid token for authentication
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     let create_request = json!({
         "firstName": "Admin",
@@ -66,8 +64,7 @@ async fn test_create_admin_duplicate_email() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     let create_request = json!({
         "firstName": "Admin",
@@ -121,8 +118,7 @@ async fn test_get_admin() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create an admin
     let create_request = json!({
@@ -158,7 +154,7 @@ async fn test_get_admin() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}", admin_id))
                 .method("GET")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -181,8 +177,7 @@ async fn test_list_admins() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create an admin
     let create_request = json!({
@@ -233,8 +228,7 @@ async fn test_update_admin() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create an admin
     let create_request = json!({
@@ -273,7 +267,7 @@ async fn test_update_admin() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}", admin_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -297,8 +291,7 @@ async fn test_delete_admin() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create an admin
     let create_request = json!({
@@ -332,7 +325,7 @@ async fn test_delete_admin() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}", admin_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -355,8 +348,7 @@ async fn test_update_admin_not_found() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
     let fake_id = Uuid::new_v4();
 
     let update_request = json!({
@@ -366,7 +358,7 @@ async fn test_update_admin_not_found() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", fake_id))
+                .uri(format!("/api/v1/admin/administrators/{}", fake_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -377,6 +369,11 @@ async fn test_update_admin_not_found() {
         .unwrap();
 
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["errors"][0]["detail"], "Administrator not found");
 
     common::cleanup_test_db(&pool).await;
 }
@@ -390,14 +387,13 @@ async fn test_delete_admin_not_found() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
     let fake_id = Uuid::new_v4();
 
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", fake_id))
+                .uri(format!("/api/v1/admin/administrators/{}", fake_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -420,8 +416,7 @@ async fn test_update_admin_duplicate_email() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create first admin
     let create_request1 = json!({
@@ -481,7 +476,7 @@ async fn test_update_admin_duplicate_email() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", admin2_id))
+                .uri(format!("/api/v1/admin/administrators/{}", admin2_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -498,15 +493,14 @@ async fn test_update_admin_duplicate_email() {
 
 #[tokio::test]
 #[serial]
-async fn test_update_admin_password() {
+async fn test_update_admin_password_requires_current_password() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
     // Create admin
     let create_request = json!({
@@ -544,7 +538,7 @@ async fn test_update_admin_password() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}", admin_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -554,7 +548,16 @@ async fn test_update_admin_password() {
         .await
         .unwrap();
 
-    assert_eq!(response.status(), StatusCode::OK);
+    // Password changes go through /admin/me/password, which asks for the current one
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        json["errors"][0]["source"]["pointer"],
+        "/data/attributes/password"
+    );
 
     common::cleanup_test_db(&pool).await;
 }
@@ -568,15 +571,14 @@ async fn test_get_admin_not_found() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
     let fake_id = Uuid::new_v4();
 
     // Try to get a non-existent admin
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}", fake_id))
+                .uri(format!("/api/v1/admin/administrators/{}", fake_id))
                 .method("GET")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -598,7 +600,8 @@ use caxur::application::administrators::create::{
     CreateAdministratorRequest, CreateAdministratorUseCase,
 };
 use caxur::domain::administrators::{
-    Administrator, AdministratorRepository, NewAdministrator, UpdateAdministrator,
+    Administrator, AdministratorRepository, AssignedRole, NewAdministrator, RoleAssignment,
+    UpdateAdministrator,
 };
 use caxur::infrastructure::password::PasswordService;
 use caxur::infrastructure::repositories::administrators::PostgresAdministratorRepository;
@@ -667,7 +670,7 @@ async fn test_use_case_create_admin_duplicate_email() {
         last_name: "User".to_string(),
         suffix: None,
         contact_number: None,
-        email: email,
+        email,
         password: "password456".to_string(),
     };
     let result = use_case.execute(req2).await;
@@ -811,11 +814,25 @@ impl AdministratorRepository for FaultyAdministratorRepository {
         unimplemented!()
     }
 
-    async fn attach_roles(&self, _admin_id: Uuid, _role_ids: Vec<Uuid>) -> anyhow::Result<()> {
+    async fn attach_roles(
+        &self,
+        _admin_id: Uuid,
+        _role_ids: Vec<Uuid>,
+        _assignment: RoleAssignment,
+    ) -> anyhow::Result<()> {
         unimplemented!()
     }
 
-    async fn detach_roles(&self, _admin_id: Uuid, _role_ids: Vec<Uuid>) -> anyhow::Result<()> {
+    async fn detach_roles(
+        &self,
+        _admin_id: Uuid,
+        _role_ids: Vec<Uuid>,
+        _revoked_by: Uuid,
+    ) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    async fn find_role_assignments(&self, _admin_id: Uuid) -> anyhow::Result<Vec<AssignedRole>> {
         unimplemented!()
     }
 
@@ -825,6 +842,10 @@ impl AdministratorRepository for FaultyAdministratorRepository {
     ) -> anyhow::Result<Vec<caxur::domain::permissions::Permission>> {
         unimplemented!()
     }
+
+    async fn delete_expired_role_assignments(&self) -> anyhow::Result<u64> {
+        unimplemented!()
+    }
 }
 
 #[tokio::test]
@@ -865,8 +886,7 @@ async fn test_attach_detach_roles() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
-    let user_id = Uuid::new_v4();
-    let token = common::generate_test_token(user_id);
+    let (manager_id, token) = common::create_admin_with_permissions(&pool).await;
 
     // 1. Create an admin
     let create_request = json!({
@@ -917,7 +937,7 @@ async fn test_attach_detach_roles() {
         .clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}/roles", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}/roles", admin_id))
                 .method("POST")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -948,7 +968,7 @@ async fn test_attach_detach_roles() {
     let detach_response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/administrators/{}/roles", admin_id))
+                .uri(format!("/api/v1/admin/administrators/{}/roles", admin_id))
                 .method("DELETE")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -971,5 +991,327 @@ async fn test_attach_detach_roles() {
     .unwrap();
     assert_eq!(role_count_after.0, 0);
 
+    let events: Vec<(String, Option<Uuid>)> = sqlx::query_as(
+        "SELECT event, actor_id FROM administrator_role_history WHERE administrator_id = $1 AND role_id = $2 ORDER BY occurred_at",
+    )
+    .bind(Uuid::parse_str(admin_id).unwrap())
+    .bind(role_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(
+        events,
+        vec![
+            ("granted".to_string(), Some(manager_id)),
+            ("revoked".to_string(), Some(manager_id)),
+        ]
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_temporary_role_assignment() {
+    use caxur::application::maintenance::purge_expired_tokens::PurgeExpiredRoleAssignmentsJob;
+    use caxur::domain::maintenance::MaintenanceJob;
+    use caxur::domain::permissions::Permission;
+    use time::format_description::well_known::Rfc3339;
+
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (granter_id, token) = common::create_admin_with_permissions(&pool).await;
+    let (on_call_id, _, on_call_token) = common::create_admin_with_role(&pool, &[]).await;
+    let (_, role_id, _) = common::create_admin_with_role(&pool, &["roles.*"]).await;
+    let roles_uri = format!("/api/v1/admin/administrators/{}/roles", on_call_id);
+
+    let attach = |expires_at: time::OffsetDateTime| {
+        let body = json!({
+            "role_ids": [role_id],
+            "expires_at": expires_at.format(&Rfc3339).unwrap(),
+        });
+        app.clone().oneshot(
+            Request::builder()
+                .uri(&roles_uri)
+                .method("POST")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+    };
+
+    let now = time::OffsetDateTime::now_utc();
+    let response = attach(now - time::Duration::minutes(1)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = attach(now + time::Duration::hours(4)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let (assigned_by, expires_at): (Option<Uuid>, Option<time::OffsetDateTime>) = sqlx::query_as(
+        "SELECT assigned_by, expires_at FROM administrator_roles WHERE administrator_id = $1 AND role_id = $2",
+    )
+    .bind(on_call_id)
+    .bind(role_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(assigned_by, Some(granter_id));
+    assert!(expires_at.is_some());
+
+    // The terms of the assignment can be read back
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(&roles_uri)
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let assignment = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|resource| resource["id"] == role_id.to_string())
+        .unwrap();
+    assert_eq!(assignment["type"], "role-assignments");
+    assert_eq!(
+        assignment["attributes"]["assignedBy"],
+        granter_id.to_string()
+    );
+    assert!(assignment["attributes"]["assignedAt"].is_string());
+    assert!(assignment["attributes"]["expiresAt"].is_string());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/roles")
+                .header("authorization", format!("Bearer {}", on_call_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The shift ends
+    let expires_at_after_shift: Option<time::OffsetDateTime> = sqlx::query_scalar(
+        "UPDATE administrator_roles SET expires_at = NOW() - INTERVAL '1 minute' WHERE administrator_id = $1 AND role_id = $2 RETURNING expires_at",
+    )
+    .bind(on_call_id)
+    .bind(role_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let repo = Arc::new(PostgresAdministratorRepository::new(pool.clone()));
+    let permissions = repo.get_permissions(on_call_id).await.unwrap();
+    assert!(!permissions.contains(&Permission::from_static("roles.*")));
+
+    let purged = PurgeExpiredRoleAssignmentsJob::new(repo)
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(purged, 1);
+
+    let remaining: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM administrator_roles WHERE role_id = $1")
+            .bind(role_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    // Only the permanent assignment of the role's original holder is left
+    assert_eq!(remaining, 1);
+
+    // The purged grant is still on record
+    let history: Vec<(String, Option<Uuid>, Option<time::OffsetDateTime>)> = sqlx::query_as(
+        "SELECT event, actor_id, expires_at FROM administrator_role_history WHERE administrator_id = $1 AND role_id = $2 ORDER BY occurred_at",
+    )
+    .bind(on_call_id)
+    .bind(role_id)
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].0, "granted");
+    assert_eq!(history[0].1, Some(granter_id));
+    assert!(history[0].2.is_some());
+    assert_eq!(history[1].0, "expired");
+    assert_eq!(history[1].1, None);
+    assert_eq!(history[1].2, expires_at_after_shift);
+
+    // The history can't be rewritten
+    assert!(
+        sqlx::query("DELETE FROM administrator_role_history")
+            .execute(&pool)
+            .await
+            .is_err()
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_reassigning_permanent_role_keeps_it_permanent() {
+    use time::format_description::well_known::Rfc3339;
+
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+    let (holder_id, role_id, _) = common::create_admin_with_role(&pool, &["roles.*"]).await;
+    let roles_uri = format!("/api/v1/admin/administrators/{}/roles", holder_id);
+
+    let attach = |body: serde_json::Value| {
+        app.clone().oneshot(
+            Request::builder()
+                .uri(&roles_uri)
+                .method("POST")
+                .header("content-type", "application/json")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+    };
+    let expires_at = || async {
+        sqlx::query_scalar::<_, Option<time::OffsetDateTime>>(
+            "SELECT expires_at FROM administrator_roles WHERE administrator_id = $1 AND role_id = $2",
+        )
+        .bind(holder_id)
+        .bind(role_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+    };
+
+    let temporary = json!({
+        "role_ids": [role_id],
+        "expires_at": (time::OffsetDateTime::now_utc() + time::Duration::hours(4))
+            .format(&Rfc3339)
+            .unwrap(),
+    });
+
+    // A temporary grant doesn't cut a permanent one short
+    let response = attach(temporary).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(expires_at().await, None);
+
+    // A temporary grant can still be made permanent
+    sqlx::query(
+        "UPDATE administrator_roles SET expires_at = NOW() + INTERVAL '1 hour' WHERE administrator_id = $1 AND role_id = $2",
+    )
+    .bind(holder_id)
+    .bind(role_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    let response = attach(json!({ "role_ids": [role_id] })).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(expires_at().await, None);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_only_administrator_roles_grant_administrator_permissions() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+    let (holder_id, _, holder_token) = common::create_admin_with_role(&pool, &[]).await;
+
+    let group_id = Uuid::new_v4();
+    sqlx::query("INSERT INTO groups (id, name) VALUES ($1, $2)")
+        .bind(group_id)
+        .bind(format!("Group {}", group_id))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let user_role_id = Uuid::new_v4();
+    let group_role_id = Uuid::new_v4();
+    for (role_id, scope, role_group_id) in [
+        (user_role_id, "user", None),
+        (group_role_id, "administrator", Some(group_id)),
+    ] {
+        sqlx::query("INSERT INTO roles (id, name, scope, group_id) VALUES ($1, $2, $3, $4)")
+            .bind(role_id)
+            .bind(format!("Role {}", role_id))
+            .bind(scope)
+            .bind(role_group_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO role_permissions (role_id, permission) VALUES ($1, 'administrators.read')",
+        )
+        .bind(role_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    for role_id in [user_role_id, group_role_id] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/admin/administrators/{}/roles", holder_id))
+                    .method("POST")
+                    .header("content-type", "application/json")
+                    .header("authorization", format!("Bearer {}", token))
+                    .body(Body::from(json!({ "role_ids": [role_id] }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // Assignments that bypassed the check grant nothing either
+    for role_id in [user_role_id, group_role_id] {
+        sqlx::query("INSERT INTO administrator_roles (administrator_id, role_id) VALUES ($1, $2)")
+            .bind(holder_id)
+            .bind(role_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/administrators")
+                .method("GET")
+                .header("authorization", format!("Bearer {}", holder_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
     common::cleanup_test_db(&pool).await;
 }
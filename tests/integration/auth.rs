@@ -297,6 +297,105 @@ async fn test_refresh_token_invalid() {
     common::cleanup_test_db(&pool).await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_refresh_token_reuse_revokes_family() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let create_request = json!({
+        "username": "testuser",
+        "email": "test@example.com",
+        "password": "password123"
+    });
+
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(create_request.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let login_request = json!({
+        "email": "test@example.com",
+        "password": "password123"
+    });
+
+    let login_response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/login")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(login_request.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = axum::body::to_bytes(login_response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let original_token = json["data"]["attributes"]["refreshToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let refresh = |token: String| {
+        let app = app.clone();
+        async move {
+            app.oneshot(
+                Request::builder()
+                    .uri("/api/v1/auth/refresh")
+                    .method("POST")
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "refresh_token": token }).to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+        }
+    };
+
+    // Legitimate rotation
+    let response = refresh(original_token.clone()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let rotated_token = json["data"]["attributes"]["refreshToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // Replaying the already rotated token is rejected...
+    let response = refresh(original_token).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // ...and revokes the descendant token as well
+    let response = refresh(rotated_token).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM refresh_tokens")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(remaining, 0);
+
+    common::cleanup_test_db(&pool).await;
+}
+
 #[tokio::test]
 #[serial]
 async fn test_auth_user_extractor_missing_header() {
@@ -537,3 +636,149 @@ async fn test_admin_login_invalid_credentials() {
 
     common::cleanup_test_db(&pool).await;
 }
+
+/// Helper to build an app that hashes with a higher Argon2 cost than the default
+fn app_with_stronger_hashing(pool: &sqlx::PgPool) -> axum::Router {
+    let params = argon2::Params::new(32768, 3, 1, None).unwrap();
+    let state = common::create_test_app_state(pool.clone()).with_password_service(
+        caxur::infrastructure::password::PasswordService::new().with_params(params),
+    );
+    caxur::presentation::router::app(state).unwrap()
+}
+
+/// Helper to attempt a login
+async fn login(
+    app: &axum::Router,
+    uri: &str,
+    email: &str,
+    password: &str,
+) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "email": email, "password": password }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_login_upgrades_outdated_password_hash() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_stronger_hashing(&pool);
+
+    let user_id = uuid::Uuid::new_v4();
+    let hash = caxur::infrastructure::password::PasswordService::new()
+        .hash_password("password123")
+        .unwrap();
+    sqlx::query!(
+        "INSERT INTO users (id, username, email, password_hash, created_at, updated_at) VALUES ($1, $2, $3, $4, NOW(), NOW())",
+        user_id,
+        "rehash",
+        "rehash@example.com",
+        hash
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // A failed attempt leaves the hash alone
+    let response = login(&app, "/api/v1/auth/login", "rehash@example.com", "wrong").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let stored: String =
+        sqlx::query_scalar!("SELECT password_hash FROM users WHERE id = $1", user_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(stored, hash);
+
+    let response = login(
+        &app,
+        "/api/v1/auth/login",
+        "rehash@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let upgraded: String =
+        sqlx::query_scalar!("SELECT password_hash FROM users WHERE id = $1", user_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(upgraded.contains("m=32768,t=3,p=1"));
+
+    // The upgraded hash keeps working
+    let response = login(
+        &app,
+        "/api/v1/auth/login",
+        "rehash@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_login_upgrades_seeded_password_hash() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_stronger_hashing(&pool);
+
+    // Same parameters as the seeded administrator
+    let admin_id = uuid::Uuid::new_v4();
+    let hash = "$argon2id$v=19$m=19456,t=2,p=1$SNdmdsg99eWhTp1gfhgQng$Ew5Zwb7you3ebqt849JzCR3+sKbzn8jW7DIsE3OMo7c";
+    sqlx::query!(
+        "INSERT INTO user_administrators (id, email, password_hash, first_name, last_name, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, NOW(), NOW())",
+        admin_id,
+        "seeded@example.com",
+        hash,
+        "Seeded",
+        "Admin"
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let response = login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "seeded@example.com",
+        "123123123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let upgraded: String = sqlx::query_scalar!(
+        "SELECT password_hash FROM user_administrators WHERE id = $1",
+        admin_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(upgraded.contains("m=32768,t=3,p=1"));
+
+    let response = login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "seeded@example.com",
+        "123123123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
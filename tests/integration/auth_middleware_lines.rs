@@ -1,21 +1,18 @@
 use crate::common;
 use axum::{
-    Extension, Router,
+    Router,
     body::Body,
     http::{Request, StatusCode},
-    middleware,
     routing::get,
 };
 use caxur::domain::auth::AuthService;
-use caxur::domain::permissions::Permission;
-use caxur::presentation::middleware::auth::{RequiredPermissions, check_permissions};
+use caxur::presentation::extractors::{AdministratorsRead, AnyAdministrator, RequirePermission};
 use serial_test::serial;
 use tower::ServiceExt;
 
 #[tokio::test]
 #[serial]
-async fn test_cover_line_35_user_type_mismatch() {
-    // Target Line 35: "Access denied: User {} type '{}' does not match required '{}'"
+async fn test_require_permission_rejects_user_token() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -28,19 +25,11 @@ async fn test_cover_line_35_user_type_mismatch() {
 
     let state = common::create_test_app_state(pool.clone());
 
-    // Route requires "admin" type
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AnyAdministrator>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -61,26 +50,18 @@ async fn test_cover_line_35_user_type_mismatch() {
 
 #[tokio::test]
 #[serial]
-async fn test_cover_lines_47_48_db_error() {
-    // Target Lines 47-48: tracing::error!("Failed to fetch permissions..."); AppError::InternalServerError(e)
+async fn test_require_permission_db_error() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
-    let (admin_id, token) = common::create_admin_with_permissions(&pool).await;
+    let (_admin_id, token) = common::create_admin_with_permissions(&pool).await;
     let state = common::create_test_app_state(pool.clone());
 
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![Permission::Wildcard], // Requires DB check
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AdministratorsRead>| async { "Success" }),
+        )
         .with_state(state);
 
     // Force DB error by closing pool
@@ -104,8 +85,7 @@ async fn test_cover_lines_47_48_db_error() {
 
 #[tokio::test]
 #[serial]
-async fn test_cover_line_60_insufficient_perms() {
-    // Target Line 60: "Access denied: User {} lacks required permissions {:?}"
+async fn test_require_permission_insufficient_perms() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -127,19 +107,11 @@ async fn test_cover_line_60_insufficient_perms() {
     let token = common::generate_admin_token(admin_id);
     let state = common::create_test_app_state(pool.clone());
 
-    // Context requires Wildcard, but user has NONE
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![Permission::Wildcard],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AdministratorsRead>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -160,8 +132,7 @@ async fn test_cover_line_60_insufficient_perms() {
 
 #[tokio::test]
 #[serial]
-async fn test_cover_lines_69_70_rbac_not_impl() {
-    // Target Lines 69-70: "RBAC not implemented for user type: {}"; Forbidden
+async fn test_require_permission_rejects_unknown_user_type() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -174,20 +145,11 @@ async fn test_cover_lines_69_70_rbac_not_impl() {
 
     let state = common::create_test_app_state(pool.clone());
 
-    // Route requires "merchant"
-    // Since "merchant" != "admin", it goes to the else block (Line 66)
-    let required_permissions = RequiredPermissions {
-        user_type: "merchant",
-        permissions: vec![],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AnyAdministrator>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
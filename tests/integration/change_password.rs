@@ -0,0 +1,239 @@
+use crate::common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use caxur::application::auth::login_throttle::{
+    LoginThrottle, LoginThrottlePolicy, ThrottleLimits,
+};
+use caxur::infrastructure::repositories::login_attempts::PostgresLoginAttemptRepository;
+use serde_json::json;
+use serial_test::serial;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+#[tokio::test]
+#[serial]
+async fn test_user_changes_password_with_current_password() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "changer@example.com").await;
+    let (token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "changer@example.com",
+        "password123",
+    )
+    .await;
+
+    // A token alone is not enough
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/me/password",
+        Some(&token),
+        json!({ "current_password": "guessed", "new_password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["source"]["pointer"],
+        "/data/attributes/current_password"
+    );
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/me/password",
+        Some(&token),
+        json!({ "current_password": "password123", "new_password": "password123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["source"]["pointer"],
+        "/data/attributes/new_password"
+    );
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/me/password",
+        Some(&token),
+        json!({ "current_password": "password123", "new_password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["passwordChanged"], true);
+    assert_eq!(json["meta"]["revokedTokens"], 1);
+
+    // Every session ends, including the one that made the change
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/me/sessions")
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    assert_eq!(
+        common::login_response(
+            &app,
+            "/api/v1/auth/login",
+            "changer@example.com",
+            "password123"
+        )
+        .await
+        .status(),
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        common::login_response(
+            &app,
+            "/api/v1/auth/login",
+            "changer@example.com",
+            "newpassword123"
+        )
+        .await
+        .status(),
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_administrator_changes_password() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::create_admin(&pool, "changing_admin@example.com", "adminpassword").await;
+
+    let (token, _) = common::login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "changing_admin@example.com",
+        "adminpassword",
+    )
+    .await;
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/password",
+        Some(&token),
+        json!({ "current_password": "adminpassword", "new_password": "newadminpassword" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        common::login_response(
+            &app,
+            "/api/v1/admin/auth/login",
+            "changing_admin@example.com",
+            "newadminpassword"
+        )
+        .await
+        .status(),
+        StatusCode::OK
+    );
+
+    // Client tokens cannot use the administrator endpoint
+    let user_token = common::generate_test_token(Uuid::new_v4());
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/password",
+        Some(&user_token),
+        json!({ "current_password": "password123", "new_password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_wrong_current_passwords_are_throttled() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let policy = LoginThrottlePolicy {
+        account: ThrottleLimits {
+            backoff_after: 2,
+            lockout_after: 10,
+        },
+        base_delay: time::Duration::minutes(1),
+        ..LoginThrottlePolicy::default()
+    };
+    let throttle = LoginThrottle::new(Arc::new(PostgresLoginAttemptRepository::new(pool.clone())))
+        .with_policy(policy);
+    let state = common::create_test_app_state(pool.clone()).with_login_throttle(throttle);
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "guessed@example.com").await;
+    let (token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "guessed@example.com",
+        "password123",
+    )
+    .await;
+
+    for _ in 0..2 {
+        let response = common::send_json(
+            &app,
+            "POST",
+            "/api/v1/me/password",
+            Some(&token),
+            json!({ "current_password": "guess", "new_password": "newpassword123" }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/me/password",
+        Some(&token),
+        json!({ "current_password": "password123", "new_password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // Guessed current passwords do not count against signing in
+    assert_eq!(
+        common::login_response(
+            &app,
+            "/api/v1/auth/login",
+            "guessed@example.com",
+            "password123"
+        )
+        .await
+        .status(),
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
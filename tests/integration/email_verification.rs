@@ -0,0 +1,236 @@
+use crate::common;
+
+use axum::http::StatusCode;
+use caxur::domain::notifications::Notification;
+use serde_json::json;
+use serial_test::serial;
+use uuid::Uuid;
+
+/// Tokens of every email verification notification sent so far, oldest first
+fn verification_tokens(notifications: &common::RecordingNotificationService) -> Vec<String> {
+    notifications
+        .sent()
+        .into_iter()
+        .filter_map(|notification| match notification {
+            Notification::EmailVerification { token, .. } => Some(token),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Helper to read `emailVerifiedAt` of a user
+async fn email_verified_at(pool: &sqlx::PgPool, email: &str) -> Option<time::OffsetDateTime> {
+    sqlx::query_scalar("SELECT email_verified_at FROM users WHERE email = $1")
+        .bind(email)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_signup_sends_verification_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let json = common::register_user(&app, "verify_me@example.com").await;
+    assert!(json["data"]["attributes"]["emailVerifiedAt"].is_null());
+
+    let sent = notifications.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].recipient(), "verify_me@example.com");
+    let token = verification_tokens(&notifications).pop().unwrap();
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["emailVerified"], true);
+    assert!(
+        email_verified_at(&pool, "verify_me@example.com")
+            .await
+            .is_some()
+    );
+
+    // The token is single-use
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_verify_email_rejects_invalid_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, _) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": "not-a-token" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["detail"],
+        "Invalid or expired verification token"
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_email_change_requires_new_verification() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let json = common::register_user(&app, "old_address@example.com").await;
+    let user_id: Uuid = json["data"]["id"].as_str().unwrap().parse().unwrap();
+    let token = verification_tokens(&notifications).pop().unwrap();
+    common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": token }),
+    )
+    .await;
+
+    // Updating other fields keeps the verification
+    let access_token = common::generate_test_token(user_id);
+    let response = common::send_json(
+        &app,
+        "PUT",
+        &format!("/api/v1/users/{}", user_id),
+        Some(&access_token),
+        json!({ "email": "old_address@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert!(!json["data"]["attributes"]["emailVerifiedAt"].is_null());
+    assert_eq!(verification_tokens(&notifications).len(), 1);
+
+    let response = common::send_json(
+        &app,
+        "PUT",
+        &format!("/api/v1/users/{}", user_id),
+        Some(&access_token),
+        json!({ "email": "new_address@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert!(json["data"]["attributes"]["emailVerifiedAt"].is_null());
+
+    let sent = notifications.sent();
+    assert_eq!(sent.last().unwrap().recipient(), "new_address@example.com");
+    let token = verification_tokens(&notifications).pop().unwrap();
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        email_verified_at(&pool, "new_address@example.com")
+            .await
+            .is_some()
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_resend_verification_replaces_previous_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "resend@example.com").await;
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email/resend",
+        None,
+        json!({ "email": "resend@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["verificationRequested"], true);
+
+    let tokens = verification_tokens(&notifications);
+    assert_eq!(tokens.len(), 2);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": tokens[0] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/verify-email",
+        None,
+        json!({ "token": tokens[1] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Verified and unknown addresses get the same response but no notification
+    for email in ["resend@example.com", "nobody@example.com"] {
+        let response = common::send_json(
+            &app,
+            "POST",
+            "/api/v1/auth/verify-email/resend",
+            None,
+            json!({ "email": email }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    assert_eq!(verification_tokens(&notifications).len(), 2);
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -0,0 +1,337 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use serde_json::{Value, json};
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn request(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, json)
+}
+
+async fn create_group(app: &Router, admin_token: &str, name: &str) -> String {
+    let (status, body) = request(
+        app,
+        "POST",
+        "/api/v1/admin/groups",
+        admin_token,
+        json!({ "name": name }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    body["data"]["id"].as_str().unwrap().to_string()
+}
+
+/// Create a role of the group holding `permissions` and give it to a new member
+async fn create_group_member(
+    app: &Router,
+    pool: &sqlx::PgPool,
+    admin_token: &str,
+    group_id: &str,
+    permissions: &[&str],
+) -> (Uuid, String) {
+    let (user_id, _, token) = common::create_user_with_role(pool, &[]).await;
+
+    let (status, _) = request(
+        app,
+        "POST",
+        &format!("/api/v1/admin/groups/{}/members", group_id),
+        admin_token,
+        json!({ "user_ids": [user_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = request(
+        app,
+        "POST",
+        "/api/v1/admin/roles",
+        admin_token,
+        json!({
+            "name": format!("Editor {}", user_id),
+            "scope": "user",
+            "group_id": group_id,
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let role_id = body["data"]["id"].as_str().unwrap().to_string();
+
+    if !permissions.is_empty() {
+        let (status, _) = request(
+            app,
+            "POST",
+            &format!("/api/v1/admin/roles/{}/permissions", role_id),
+            admin_token,
+            json!({ "permissions": permissions }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, _) = request(
+        app,
+        "POST",
+        &format!("/api/v1/admin/users/{}/roles", user_id),
+        admin_token,
+        json!({ "role_ids": [role_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    (user_id, token)
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_manages_groups() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+
+    let group_id = create_group(&app, &admin_token, "Acme Corp").await;
+    let uri = format!("/api/v1/admin/groups/{}", group_id);
+
+    let (status, _) = request(
+        &app,
+        "POST",
+        "/api/v1/admin/groups",
+        &admin_token,
+        json!({ "name": "Acme Corp" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, body) = request(
+        &app,
+        "PUT",
+        &uri,
+        &admin_token,
+        json!({ "description": "Customer organization" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["attributes"]["name"], "Acme Corp");
+    assert_eq!(
+        body["data"]["attributes"]["description"],
+        "Customer organization"
+    );
+
+    let (status, body) =
+        request(&app, "GET", "/api/v1/admin/groups", &admin_token, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["meta"]["total"], 1);
+
+    let (user_id, _) = create_group_member(&app, &pool, &admin_token, &group_id, &[]).await;
+    let (status, body) = request(
+        &app,
+        "GET",
+        &format!("{}/members", uri),
+        &admin_token,
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"][0]["id"], user_id.to_string());
+
+    let (status, _) = request(&app, "DELETE", &uri, &admin_token, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = request(&app, "GET", &uri, &admin_token, json!({})).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // The group's roles went with it
+    let roles: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM roles WHERE group_id IS NOT NULL")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(roles, 0);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_roles_only_apply_within_their_group() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+
+    let acme = create_group(&app, &admin_token, "Acme Corp").await;
+    let globex = create_group(&app, &admin_token, "Globex").await;
+
+    let (_, editor_token) =
+        create_group_member(&app, &pool, &admin_token, &acme, &["groups.update"]).await;
+    let (_, viewer_token) = create_group_member(&app, &pool, &admin_token, &acme, &[]).await;
+    create_group_member(&app, &pool, &admin_token, &globex, &["groups.update"]).await;
+
+    let update = json!({ "description": "Updated by a member" });
+
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/groups/{}", acme),
+        &editor_token,
+        update.clone(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Members without the permission are forbidden, non-members can't tell
+    // the group exists
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/groups/{}", acme),
+        &viewer_token,
+        update.clone(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/groups/{}", globex),
+        &editor_token,
+        update.clone(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = request(
+        &app,
+        "GET",
+        &format!("/api/v1/groups/{}", globex),
+        &editor_token,
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = request(&app, "GET", "/api/v1/groups", &viewer_token, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+    assert_eq!(body["data"][0]["id"], acme);
+
+    // Group permissions don't carry over to the rest of the API
+    let (other_id, _, _) = common::create_user_with_role(&pool, &[]).await;
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/users/{}", other_id),
+        &editor_token,
+        json!({ "username": "renamed_by_editor" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_group_roles_require_membership() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+
+    let group_id = create_group(&app, &admin_token, "Acme Corp").await;
+    let (user_id, token) =
+        create_group_member(&app, &pool, &admin_token, &group_id, &["groups.update"]).await;
+    let (outsider_id, _, _) = common::create_user_with_role(&pool, &[]).await;
+
+    let group_role_id: Uuid = sqlx::query_scalar("SELECT id FROM roles WHERE group_id = $1")
+        .bind(Uuid::parse_str(&group_id).unwrap())
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    let (status, _) = request(
+        &app,
+        "POST",
+        &format!("/api/v1/admin/users/{}/roles", outsider_id),
+        &admin_token,
+        json!({ "role_ids": [group_role_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = request(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/groups/{}/members", group_id),
+        &admin_token,
+        json!({ "user_ids": [user_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/groups/{}", group_id),
+        &token,
+        json!({ "description": "Former member" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Rejoining doesn't bring back the group roles
+    let (status, _) = request(
+        &app,
+        "POST",
+        &format!("/api/v1/admin/groups/{}/members", group_id),
+        &admin_token,
+        json!({ "user_ids": [user_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = request(
+        &app,
+        "PUT",
+        &format!("/api/v1/groups/{}", group_id),
+        &token,
+        json!({ "description": "Returning member" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
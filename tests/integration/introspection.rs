@@ -0,0 +1,184 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use caxur::infrastructure::client_credentials::ClientCredentials;
+use serde_json::json;
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const CLIENT_ID: &str = "orders-service";
+const CLIENT_SECRET: &str = "orders-secret";
+
+fn test_app(pool: sqlx::PgPool) -> Router {
+    let state = common::create_test_app_state(pool)
+        .with_introspection_clients(ClientCredentials::new().with_client(CLIENT_ID, CLIENT_SECRET));
+    caxur::presentation::router::app(state).unwrap()
+}
+
+fn basic_auth(client_id: &str, client_secret: &str) -> String {
+    format!(
+        "Basic {}",
+        STANDARD.encode(format!("{}:{}", client_id, client_secret))
+    )
+}
+
+async fn introspect(
+    app: &Router,
+    authorization: Option<&str>,
+    form: &str,
+) -> axum::response::Response {
+    let mut builder = Request::builder()
+        .uri("/api/v1/oauth/introspect")
+        .method("POST")
+        .header("content-type", "application/x-www-form-urlencoded");
+
+    if let Some(authorization) = authorization {
+        builder = builder.header("Authorization", authorization);
+    }
+
+    app.clone()
+        .oneshot(builder.body(Body::from(form.to_string())).unwrap())
+        .await
+        .unwrap()
+}
+
+/// Helper to register and login a client user, returns (access_token, refresh_token)
+async fn login(app: &Router, email: &str) -> (String, String) {
+    common::register_user(app, email).await;
+    common::login(app, "/api/v1/auth/login", email, "password123").await
+}
+
+#[tokio::test]
+#[serial]
+async fn test_introspection_requires_client_credentials() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = test_app(pool.clone());
+
+    let response = introspect(&app, None, "token=abc").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = introspect(&app, Some(&basic_auth(CLIENT_ID, "wrong")), "token=abc").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // A user's bearer token is not a client credential
+    let token = common::generate_test_token(Uuid::new_v4());
+    let response = introspect(&app, Some(&format!("Bearer {}", token)), "token=abc").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_introspect_access_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = test_app(pool.clone());
+    let auth = basic_auth(CLIENT_ID, CLIENT_SECRET);
+    let (access_token, refresh_token) = login(&app, "introspect@example.com").await;
+
+    let response = introspect(&app, Some(&auth), &format!("token={}", access_token)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["cache-control"], "no-store");
+
+    let json = common::json_body(response).await;
+    assert_eq!(json["active"], true);
+    assert_eq!(json["token_type"], "access_token");
+    assert_eq!(json["user_type"], "user");
+    assert!(json["sub"].is_string());
+    assert!(json["exp"].is_i64());
+
+    // Logging out revokes the access token
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/logout")
+                .method("POST")
+                .header("content-type", "application/json")
+                .header("Authorization", format!("Bearer {}", access_token))
+                .body(Body::from(
+                    json!({ "refresh_token": refresh_token }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = introspect(&app, Some(&auth), &format!("token={}", access_token)).await;
+    let json = common::json_body(response).await;
+    assert_eq!(json, json!({ "active": false }));
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_introspect_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = test_app(pool.clone());
+    let auth = basic_auth(CLIENT_ID, CLIENT_SECRET);
+    let (_, refresh_token) = login(&app, "introspect_refresh@example.com").await;
+
+    let form = format!("token={}&token_type_hint=refresh_token", refresh_token);
+    let json = common::json_body(introspect(&app, Some(&auth), &form).await).await;
+    assert_eq!(json["active"], true);
+    assert_eq!(json["token_type"], "refresh_token");
+
+    // Once rotated, the old refresh token is no longer active
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/refresh")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "refresh_token": refresh_token }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = common::json_body(introspect(&app, Some(&auth), &form).await).await;
+    assert_eq!(json, json!({ "active": false }));
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_introspect_invalid_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = test_app(pool.clone());
+    let auth = basic_auth(CLIENT_ID, CLIENT_SECRET);
+
+    let response = introspect(&app, Some(&auth), "token=not-a-jwt").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        common::json_body(response).await,
+        json!({ "active": false })
+    );
+
+    let response = introspect(&app, Some(&auth), "token=").await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -0,0 +1,312 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use caxur::application::auth::login_throttle::{
+    LoginThrottle, LoginThrottlePolicy, ThrottleLimits,
+};
+use caxur::domain::password::PasswordHashingService;
+use caxur::infrastructure::repositories::login_attempts::PostgresLoginAttemptRepository;
+use serial_test::serial;
+use std::sync::Arc;
+use time::Duration;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Helper to build the app with the given account limits
+fn app_with_limits(pool: &sqlx::PgPool, backoff_after: i32, lockout_after: i32) -> Router {
+    let policy = LoginThrottlePolicy {
+        account: ThrottleLimits {
+            backoff_after,
+            lockout_after,
+        },
+        base_delay: Duration::minutes(1),
+        ..LoginThrottlePolicy::default()
+    };
+    let throttle = LoginThrottle::new(Arc::new(PostgresLoginAttemptRepository::new(pool.clone())))
+        .with_policy(policy);
+
+    let state = common::create_test_app_state(pool.clone()).with_login_throttle(throttle);
+    caxur::presentation::router::app(state).unwrap()
+}
+
+/// Helper to create a user with a known password, returning its id
+async fn create_user(pool: &sqlx::PgPool, email: &str, password: &str) -> Uuid {
+    let password_service = caxur::infrastructure::password::PasswordService::new();
+    let hash = password_service.hash_password(password).unwrap();
+    let id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO users (id, username, email, password_hash, created_at, updated_at) VALUES ($1, $2, $3, $4, NOW(), NOW())",
+        id,
+        "throttled",
+        email,
+        hash
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to create user");
+
+    id
+}
+
+#[tokio::test]
+#[serial]
+async fn test_login_backs_off_after_failed_attempts() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_limits(&pool, 2, 10);
+    create_user(&pool, "slow@example.com", "password123").await;
+
+    for _ in 0..2 {
+        let response =
+            common::login_response(&app, "/api/v1/auth/login", "slow@example.com", "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // Even the right password has to wait for the back-off
+    let response = common::login_response(
+        &app,
+        "/api/v1/auth/login",
+        "slow@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: i64 = response.headers()["retry-after"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 0 && retry_after <= 60);
+    let json = common::json_body(response).await;
+    assert_eq!(json["errors"][0]["code"], "login_throttled");
+
+    // Emails differing only in case share the counter
+    let response =
+        common::login_response(&app, "/api/v1/auth/login", "SLOW@example.com", "wrong").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_unknown_email_is_throttled_like_existing_accounts() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_limits(&pool, 2, 10);
+
+    for _ in 0..2 {
+        let response =
+            common::login_response(&app, "/api/v1/auth/login", "nobody@example.com", "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let response =
+        common::login_response(&app, "/api/v1/auth/login", "nobody@example.com", "wrong").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_successful_login_resets_failed_attempts() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_limits(&pool, 3, 10);
+    create_user(&pool, "reset@example.com", "password123").await;
+
+    for _ in 0..2 {
+        let response =
+            common::login_response(&app, "/api/v1/auth/login", "reset@example.com", "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let response = common::login_response(
+        &app,
+        "/api/v1/auth/login",
+        "reset@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Counter starts over, so two more failures are not yet throttled
+    for _ in 0..2 {
+        let response =
+            common::login_response(&app, "/api/v1/auth/login", "reset@example.com", "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    let response = common::login_response(
+        &app,
+        "/api/v1/auth/login",
+        "reset@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_locked_user_is_unlocked_by_admin() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    // No back-off, so the lockout is reached with consecutive attempts
+    let app = app_with_limits(&pool, 100, 3);
+    let user_id = create_user(&pool, "locked@example.com", "password123").await;
+    let (_admin_id, admin_token) = common::create_admin_with_permissions(&pool).await;
+
+    for _ in 0..3 {
+        let response =
+            common::login_response(&app, "/api/v1/auth/login", "locked@example.com", "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let response = common::login_response(
+        &app,
+        "/api/v1/auth/login",
+        "locked@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let json = common::json_body(response).await;
+    assert_eq!(json["errors"][0]["code"], "account_locked");
+
+    let unlock = |id: Uuid| {
+        Request::builder()
+            .uri(format!("/api/v1/admin/users/{}/unlock", id))
+            .method("POST")
+            .header("Authorization", format!("Bearer {}", admin_token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(unlock(user_id)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["unlocked"], true);
+
+    let response = common::login_response(
+        &app,
+        "/api/v1/auth/login",
+        "locked@example.com",
+        "password123",
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Nothing left to clear
+    let response = app.clone().oneshot(unlock(user_id)).await.unwrap();
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["unlocked"], false);
+
+    let response = app.clone().oneshot(unlock(Uuid::new_v4())).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_locked_administrator_is_unlocked_by_admin() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_limits(&pool, 100, 3);
+    let (admin_id, admin_token) = common::create_admin_with_permissions(&pool).await;
+    let password_service = caxur::infrastructure::password::PasswordService::new();
+    let hash = password_service.hash_password("adminpassword").unwrap();
+    let email: String = sqlx::query_scalar!(
+        "UPDATE user_administrators SET password_hash = $1 WHERE id = $2 RETURNING email",
+        hash,
+        admin_id
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    for _ in 0..3 {
+        let response =
+            common::login_response(&app, "/api/v1/admin/auth/login", &email, "wrong").await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let response =
+        common::login_response(&app, "/api/v1/admin/auth/login", &email, "adminpassword").await;
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let json = common::json_body(response).await;
+    assert_eq!(json["errors"][0]["code"], "account_locked");
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/admin/administrators/{}/unlock", admin_id))
+                .method("POST")
+                .header("Authorization", format!("Bearer {}", admin_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["unlocked"], true);
+
+    let response =
+        common::login_response(&app, "/api/v1/admin/auth/login", &email, "adminpassword").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_unlock_requires_administrator_management() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app = app_with_limits(&pool, 100, 3);
+    let user_id = create_user(&pool, "locked@example.com", "password123").await;
+    let (admin_id, _) = common::create_admin_with_permissions(&pool).await;
+    let user_token = common::generate_test_token(user_id);
+    // A valid administrator token without any role
+    let unprivileged_token = common::generate_admin_token(Uuid::new_v4());
+
+    let uris = [
+        format!("/api/v1/admin/users/{}/unlock", user_id),
+        format!("/api/v1/admin/administrators/{}/unlock", admin_id),
+    ];
+    for uri in &uris {
+        for token in [&user_token, &unprivileged_token] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri(uri)
+                        .method("POST")
+                        .header("Authorization", format!("Bearer {}", token))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
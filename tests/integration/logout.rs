@@ -0,0 +1,314 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use serde_json::json;
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Helper to POST a JSON body with a bearer token
+async fn post_authenticated(
+    app: &Router,
+    uri: &str,
+    access_token: &str,
+    body: serde_json::Value,
+) -> axum::response::Response {
+    common::send_json(app, "POST", uri, Some(access_token), body).await
+}
+
+/// Helper to attempt a refresh and return the status code
+async fn refresh_status(app: &Router, refresh_token: &str) -> StatusCode {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/refresh")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "refresh_token": refresh_token }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    response.status()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_revokes_presented_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "logout@example.com").await;
+    let (access_token, refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "logout@example.com",
+        "password123",
+    )
+    .await;
+    let (_, other_refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "logout@example.com",
+        "password123",
+    )
+    .await;
+
+    let response = post_authenticated(
+        &app,
+        "/api/v1/auth/logout",
+        &access_token,
+        json!({ "refresh_token": refresh_token }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["loggedOut"], true);
+
+    // The revoked token can no longer be used, other sessions stay valid
+    assert_eq!(
+        refresh_status(&app, &refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        refresh_status(&app, &other_refresh_token).await,
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_rejects_token_of_another_user() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "owner@example.com").await;
+    common::register_user(&app, "intruder@example.com").await;
+    let (_, owner_refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "owner@example.com",
+        "password123",
+    )
+    .await;
+    let (intruder_access_token, _) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "intruder@example.com",
+        "password123",
+    )
+    .await;
+
+    let response = post_authenticated(
+        &app,
+        "/api/v1/auth/logout",
+        &intruder_access_token,
+        json!({ "refresh_token": owner_refresh_token }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Owner's session is untouched
+    assert_eq!(
+        refresh_status(&app, &owner_refresh_token).await,
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_unknown_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let token = common::generate_test_token(Uuid::new_v4());
+    let response = post_authenticated(
+        &app,
+        "/api/v1/auth/logout",
+        &token,
+        json!({ "refresh_token": "not-a-stored-token" }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_requires_authentication() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/logout")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "refresh_token": "token" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_logout_all_revokes_every_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "everywhere@example.com").await;
+    let (access_token, first_refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "everywhere@example.com",
+        "password123",
+    )
+    .await;
+    let (_, second_refresh_token) = common::login(
+        &app,
+        "/api/v1/auth/login",
+        "everywhere@example.com",
+        "password123",
+    )
+    .await;
+
+    let response =
+        post_authenticated(&app, "/api/v1/auth/logout-all", &access_token, json!({})).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["loggedOut"], true);
+    assert_eq!(json["meta"]["revokedTokens"], 2);
+
+    assert_eq!(
+        refresh_status(&app, &first_refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        refresh_status(&app, &second_refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_logout_revokes_presented_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::create_admin(&pool, "admin_logout@example.com", "adminpassword").await;
+    let (access_token, refresh_token) = common::login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "admin_logout@example.com",
+        "adminpassword",
+    )
+    .await;
+
+    let response = post_authenticated(
+        &app,
+        "/api/v1/admin/auth/logout",
+        &access_token,
+        json!({ "refresh_token": refresh_token }),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        refresh_status(&app, &refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_logout_all_revokes_every_refresh_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::create_admin(&pool, "admin_logout_all@example.com", "adminpassword").await;
+    let (access_token, first_refresh_token) = common::login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "admin_logout_all@example.com",
+        "adminpassword",
+    )
+    .await;
+    let (_, second_refresh_token) = common::login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "admin_logout_all@example.com",
+        "adminpassword",
+    )
+    .await;
+
+    let response = post_authenticated(
+        &app,
+        "/api/v1/admin/auth/logout-all",
+        &access_token,
+        json!({}),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["revokedTokens"], 2);
+
+    assert_eq!(
+        refresh_status(&app, &first_refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        refresh_status(&app, &second_refresh_token).await,
+        StatusCode::UNAUTHORIZED
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
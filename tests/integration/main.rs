@@ -2,12 +2,28 @@
 #[macro_use]
 pub mod common;
 
+mod access_token_denylist;
+mod admin_authorization;
 mod administrators;
 mod auth;
 mod auth_middleware_lines;
+mod change_password;
+mod email_verification;
+mod groups;
 mod health;
+mod introspection;
+mod login_throttle;
+mod logout;
+mod mfa;
 mod middleware;
+mod passkeys;
+mod password_policy;
+mod password_reset;
+mod permission_cache;
 mod permissions;
 mod refresh_tokens;
 mod roles;
+mod sessions;
+mod user_permissions;
 mod users;
+mod well_known;
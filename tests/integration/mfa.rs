@@ -0,0 +1,514 @@
+use crate::common;
+
+use axum::{Router, http::StatusCode};
+use caxur::application::auth::mfa::MAX_MFA_CHALLENGE_ATTEMPTS;
+use caxur::application::auth::totp;
+use caxur::domain::password::PasswordHashingService;
+use data_encoding::BASE32_NOPAD;
+use serde_json::json;
+use serial_test::serial;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Helper to run the password step of an administrator login
+async fn admin_login(app: &Router, email: &str) -> serde_json::Value {
+    let response =
+        common::login_response(app, "/api/v1/admin/auth/login", email, "adminpassword").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    common::json_body(response).await
+}
+
+/// Code of the given offset from the current time step
+fn totp_code(secret: &str, step_offset: i64) -> String {
+    let key = BASE32_NOPAD.decode(secret.as_bytes()).unwrap();
+    totp::code_at_step(
+        &key,
+        totp::time_step(OffsetDateTime::now_utc()) + step_offset,
+    )
+}
+
+/// Enroll TOTP for the administrator, returning the secret and recovery codes
+async fn enroll_totp(app: &Router, access_token: &str) -> (String, Vec<String>) {
+    let response = common::send_json(
+        app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp",
+        Some(access_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "totp-enrollments");
+    let secret = json["data"]["attributes"]["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        json["data"]["attributes"]["otpauthUri"]
+            .as_str()
+            .unwrap()
+            .starts_with("otpauth://totp/")
+    );
+
+    let response = common::send_json(
+        app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp/confirm",
+        Some(access_token),
+        json!({ "code": totp_code(&secret, 0) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    let recovery_codes: Vec<String> =
+        serde_json::from_value(json["data"]["attributes"]["recoveryCodes"].clone()).unwrap();
+
+    (secret, recovery_codes)
+}
+
+/// Challenge token of a login that requires a second factor
+async fn mfa_challenge(app: &Router, email: &str) -> String {
+    let json = admin_login(app, email).await;
+    assert_eq!(json["data"]["type"], "mfa-challenges");
+    assert!(json["data"]["attributes"]["accessToken"].is_null());
+    json["data"]["attributes"]["challengeToken"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_totp_enrollment_requires_second_factor_on_login() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    common::create_admin(&pool, "mfa_admin@example.com", "adminpassword").await;
+
+    // Without a second factor the login issues tokens right away
+    let json = admin_login(&app, "mfa_admin@example.com").await;
+    assert_eq!(json["data"]["type"], "auth-tokens");
+    let access_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let (secret, recovery_codes) = enroll_totp(&app, &access_token).await;
+    assert_eq!(recovery_codes.len(), 10);
+
+    let challenge_token = mfa_challenge(&app, "mfa_admin@example.com").await;
+
+    // A wrong code keeps the challenge usable
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": "000000" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // The code used for confirmation cannot be replayed
+    let used_step: i64 = sqlx::query_scalar("SELECT last_used_step FROM administrator_totp")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let key = BASE32_NOPAD.decode(secret.as_bytes()).unwrap();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": totp::code_at_step(&key, used_step) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": totp_code(&secret, 1) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "auth-tokens");
+    assert!(json["data"]["attributes"]["accessToken"].is_string());
+
+    // The challenge is single-use
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": recovery_codes[0] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["detail"],
+        "Invalid or expired MFA challenge"
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_totp_secret_is_encrypted_at_rest() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    common::create_admin(&pool, "mfa_admin@example.com", "adminpassword").await;
+
+    let json = admin_login(&app, "mfa_admin@example.com").await;
+    let access_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (secret, _) = enroll_totp(&app, &access_token).await;
+
+    let stored: String = sqlx::query_scalar("SELECT secret FROM administrator_totp")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(stored.starts_with("v1:"));
+    assert!(!stored.contains(&secret));
+
+    // Secrets stored before encryption keep working and are encrypted on first read
+    sqlx::query("UPDATE administrator_totp SET secret = $1")
+        .bind(&secret)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let challenge_token = mfa_challenge(&app, "mfa_admin@example.com").await;
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": totp_code(&secret, 1) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let stored: String = sqlx::query_scalar("SELECT secret FROM administrator_totp")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert!(stored.starts_with("v1:"));
+    assert!(!stored.contains(&secret));
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_wildcard_admin_without_mfa_must_enroll_before_tokens() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    let (admin_id, _, _) = common::create_admin_with_role(&pool, &["*"]).await;
+    let password_service = caxur::infrastructure::password::PasswordService::new();
+    let hash = password_service.hash_password("adminpassword").unwrap();
+    let email: String = sqlx::query_scalar(
+        "UPDATE user_administrators SET password_hash = $1 WHERE id = $2 RETURNING email",
+    )
+    .bind(hash)
+    .bind(admin_id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    // The password alone never yields tokens for full access
+    let json = admin_login(&app, &email).await;
+    assert_eq!(json["data"]["type"], "mfa-enrollment-challenges");
+    assert!(json["data"]["attributes"]["accessToken"].is_null());
+    let challenge_token = json["data"]["attributes"]["challengeToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // The challenge is no bearer token and can't be exchanged like a login challenge
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp",
+        Some(&challenge_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": "000000" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/enroll",
+        None,
+        json!({ "challenge_token": challenge_token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "totp-enrollments");
+    let secret = json["data"]["attributes"]["secret"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/enroll/confirm",
+        None,
+        json!({ "challenge_token": challenge_token, "code": "000000" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/enroll/confirm",
+        None,
+        json!({ "challenge_token": challenge_token, "code": totp_code(&secret, 0) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "auth-tokens");
+    assert!(json["data"]["attributes"]["accessToken"].is_string());
+    assert_eq!(json["meta"]["recoveryCodes"].as_array().unwrap().len(), 10);
+
+    // Enrolled, so the next login asks for the second factor
+    mfa_challenge(&app, &email).await;
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_mfa_challenge_burned_after_failed_attempts() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    common::create_admin(&pool, "burned_admin@example.com", "adminpassword").await;
+
+    let json = admin_login(&app, "burned_admin@example.com").await;
+    let access_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (secret, _) = enroll_totp(&app, &access_token).await;
+
+    let challenge_token = mfa_challenge(&app, "burned_admin@example.com").await;
+    for _ in 0..MAX_MFA_CHALLENGE_ATTEMPTS {
+        let response = common::send_json(
+            &app,
+            "POST",
+            "/api/v1/admin/auth/mfa/verify",
+            None,
+            json!({ "challenge_token": challenge_token, "code": "000000" }),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    // Even the correct code no longer completes the login
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": totp_code(&secret, 1) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["detail"],
+        "Invalid or expired MFA challenge"
+    );
+
+    // The wrong codes count towards the account lockout
+    let failed_count: i32 = sqlx::query_scalar(
+        "SELECT failed_count FROM login_attempts WHERE scope = 'admin' AND key = $1",
+    )
+    .bind("burned_admin@example.com")
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(failed_count, MAX_MFA_CHALLENGE_ATTEMPTS);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_recovery_codes_are_single_use() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    common::create_admin(&pool, "recovery_admin@example.com", "adminpassword").await;
+
+    let json = admin_login(&app, "recovery_admin@example.com").await;
+    let access_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (_, recovery_codes) = enroll_totp(&app, &access_token).await;
+
+    // Recovery codes are accepted regardless of case and dashes
+    let code = recovery_codes[0].to_uppercase().replace('-', "");
+    let challenge_token = mfa_challenge(&app, "recovery_admin@example.com").await;
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": code }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let challenge_token = mfa_challenge(&app, "recovery_admin@example.com").await;
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/mfa/verify",
+        None,
+        json!({ "challenge_token": challenge_token, "code": recovery_codes[0] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let json = common::json_body(response).await;
+    assert_eq!(json["errors"][0]["detail"], "Invalid verification code");
+
+    let response = common::send_json(
+        &app,
+        "GET",
+        "/api/v1/admin/me/mfa",
+        Some(&access_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["attributes"]["totpEnabled"], true);
+    assert_eq!(json["data"]["attributes"]["recoveryCodesRemaining"], 9);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_regenerate_and_disable_totp() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    common::create_admin(&pool, "disable_admin@example.com", "adminpassword").await;
+
+    let json = admin_login(&app, "disable_admin@example.com").await;
+    let access_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (secret, old_codes) = enroll_totp(&app, &access_token).await;
+
+    // Enrolling again while enabled is rejected
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp",
+        Some(&access_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/mfa/recovery-codes",
+        Some(&access_token),
+        json!({ "code": totp_code(&secret, 1) }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    let new_codes: Vec<String> =
+        serde_json::from_value(json["data"]["attributes"]["recoveryCodes"].clone()).unwrap();
+    assert_eq!(new_codes.len(), 10);
+
+    // Old recovery codes stop working
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp/disable",
+        Some(&access_token),
+        json!({ "code": old_codes[0] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/me/mfa/totp/disable",
+        Some(&access_token),
+        json!({ "code": new_codes[0] }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["totpDisabled"], true);
+
+    let json = admin_login(&app, "disable_admin@example.com").await;
+    assert_eq!(json["data"]["type"], "auth-tokens");
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_mfa_endpoints_reject_client_users() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    let token = common::generate_test_token(Uuid::new_v4());
+
+    let response =
+        common::send_json(&app, "GET", "/api/v1/admin/me/mfa", Some(&token), json!({})).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -1,16 +1,14 @@
 use crate::common;
 
 use axum::{
-    Extension, Router,
+    Router,
     body::Body,
     extract::ConnectInfo,
     http::{HeaderValue, Request, StatusCode},
-    middleware,
     routing::get,
 };
 use caxur::domain::auth::AuthService;
-use caxur::domain::permissions::Permission;
-use caxur::presentation::middleware::auth::{RequiredPermissions, check_permissions};
+use caxur::presentation::extractors::{AdministratorsRead, AnyAdministrator, RequirePermission};
 use serial_test::serial;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use tower::ServiceExt;
@@ -217,26 +215,18 @@ async fn test_cors_middleware_default() {
 
 #[tokio::test]
 #[serial]
-async fn test_auth_middleware_admin_access() {
+async fn test_require_permission_admin_access() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
     let (_admin_id, token) = common::create_admin_with_permissions(&pool).await;
     let state = common::create_test_app_state(pool.clone());
 
-    // Define a route that requires admin permission
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![Permission::Wildcard],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AdministratorsRead>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -257,7 +247,7 @@ async fn test_auth_middleware_admin_access() {
 
 #[tokio::test]
 #[serial]
-async fn test_auth_middleware_insufficient_permissions() {
+async fn test_require_permission_insufficient_permissions() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -281,19 +271,11 @@ async fn test_auth_middleware_insufficient_permissions() {
     let token = common::generate_admin_token(admin_id);
     let state = common::create_test_app_state(pool.clone());
 
-    // Route requires Wildcard, but user has none.
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![Permission::Wildcard],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AdministratorsRead>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -314,7 +296,7 @@ async fn test_auth_middleware_insufficient_permissions() {
 
 #[tokio::test]
 #[serial]
-async fn test_auth_middleware_wrong_user_type() {
+async fn test_require_permission_wrong_user_type() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -324,19 +306,11 @@ async fn test_auth_middleware_wrong_user_type() {
 
     let state = common::create_test_app_state(pool.clone());
 
-    // Even with empty permissions required, user type mismatch should fail if enforcing user_type
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AnyAdministrator>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -357,7 +331,7 @@ async fn test_auth_middleware_wrong_user_type() {
 
 #[tokio::test]
 #[serial]
-async fn test_auth_middleware_rbac_not_implemented() {
+async fn test_require_permission_unknown_user_type() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
@@ -370,19 +344,11 @@ async fn test_auth_middleware_rbac_not_implemented() {
 
     let state = common::create_test_app_state(pool.clone());
 
-    // Route requires "merchant" type, but RBAC logic for merchant is not implemented in middleware
-    let required_permissions = RequiredPermissions {
-        user_type: "merchant",
-        permissions: vec![],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AnyAdministrator>| async { "Success" }),
+        )
         .with_state(state);
 
     let response = app
@@ -396,7 +362,7 @@ async fn test_auth_middleware_rbac_not_implemented() {
         .await
         .unwrap();
 
-    // Should return 403 Forbidden because "RBAC not implemented"
+    // Only administrator tokens are accepted
     assert_eq!(response.status(), StatusCode::FORBIDDEN);
 
     common::cleanup_test_db(&pool).await;
@@ -404,26 +370,18 @@ async fn test_auth_middleware_rbac_not_implemented() {
 
 #[tokio::test]
 #[serial]
-async fn test_auth_middleware_db_error() {
+async fn test_require_permission_db_error() {
     let pool = setup_test_db_or_skip!();
     common::cleanup_test_db(&pool).await;
 
     let (_admin_id, token) = common::create_admin_with_permissions(&pool).await;
     let state = common::create_test_app_state(pool.clone());
 
-    // Route requires "admin" type, so it will try to fetch permissions from DB
-    let required_permissions = RequiredPermissions {
-        user_type: "admin",
-        permissions: vec![Permission::Wildcard],
-    };
-
     let app = Router::new()
-        .route("/protected", get(|| async { "Success" }))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            check_permissions,
-        ))
-        .layer(Extension(required_permissions))
+        .route(
+            "/protected",
+            get(|_: RequirePermission<AdministratorsRead>| async { "Success" }),
+        )
         .with_state(state);
 
     // CLOSE the pool to trigger DB error during permission fetch
@@ -0,0 +1,368 @@
+use crate::common;
+
+use axum::{Router, http::StatusCode};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use p256::ecdsa::{Signature, SigningKey, signature::Signer};
+use p256::elliptic_curve::rand_core::OsRng;
+use serde_json::json;
+use serial_test::serial;
+use sha2::{Digest, Sha256};
+
+const ORIGIN: &str = "http://localhost:3000";
+const RP_ID: &str = "localhost";
+
+/// Software authenticator holding a single ES256 credential
+struct SoftwareAuthenticator {
+    signing_key: SigningKey,
+    credential_id: Vec<u8>,
+    sign_count: u32,
+}
+
+impl SoftwareAuthenticator {
+    fn new() -> Self {
+        Self {
+            signing_key: SigningKey::random(&mut OsRng),
+            credential_id: uuid::Uuid::new_v4().as_bytes().to_vec(),
+            sign_count: 0,
+        }
+    }
+
+    fn credential_id(&self) -> String {
+        URL_SAFE_NO_PAD.encode(&self.credential_id)
+    }
+
+    fn client_data(ceremony: &str, challenge: &str, origin: &str) -> Vec<u8> {
+        json!({ "type": ceremony, "challenge": challenge, "origin": origin })
+            .to_string()
+            .into_bytes()
+    }
+
+    /// Authenticator data with user presence and verification set
+    fn authenticator_data(&self, attested: bool) -> Vec<u8> {
+        let mut data = Sha256::digest(RP_ID.as_bytes()).to_vec();
+        data.push(if attested { 0x45 } else { 0x05 });
+        data.extend_from_slice(&self.sign_count.to_be_bytes());
+
+        if attested {
+            let point = self.signing_key.verifying_key().to_encoded_point(false);
+            data.extend_from_slice(&[0u8; 16]);
+            data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+            data.extend_from_slice(&self.credential_id);
+            // COSE_Key {1: 2, 3: -7, -1: 1, -2: x, -3: y}
+            data.extend_from_slice(&[0xa5, 0x01, 0x02, 0x03, 0x26, 0x20, 0x01, 0x21, 0x58, 0x20]);
+            data.extend_from_slice(point.x().unwrap());
+            data.extend_from_slice(&[0x22, 0x58, 0x20]);
+            data.extend_from_slice(point.y().unwrap());
+        }
+
+        data
+    }
+
+    /// Response to `navigator.credentials.create()`
+    fn register(&self, challenge: &str) -> serde_json::Value {
+        let auth_data = self.authenticator_data(true);
+
+        // {"fmt": "none", "attStmt": {}, "authData": h'...'}
+        let mut attestation = vec![0xa3, 0x63];
+        attestation.extend_from_slice(b"fmt");
+        attestation.push(0x64);
+        attestation.extend_from_slice(b"none");
+        attestation.push(0x67);
+        attestation.extend_from_slice(b"attStmt");
+        attestation.extend_from_slice(&[0xa0, 0x68]);
+        attestation.extend_from_slice(b"authData");
+        attestation.push(0x59);
+        attestation.extend_from_slice(&(auth_data.len() as u16).to_be_bytes());
+        attestation.extend_from_slice(&auth_data);
+
+        json!({
+            "name": "Software key",
+            "client_data_json": URL_SAFE_NO_PAD.encode(Self::client_data("webauthn.create", challenge, ORIGIN)),
+            "attestation_object": URL_SAFE_NO_PAD.encode(attestation),
+        })
+    }
+
+    /// Response to `navigator.credentials.get()`, advancing the signature counter
+    fn assert(&mut self, challenge: &str, origin: &str) -> serde_json::Value {
+        self.sign_count += 1;
+        let auth_data = self.authenticator_data(false);
+        let client_data = Self::client_data("webauthn.get", challenge, origin);
+
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data));
+        let signature: Signature = self.signing_key.sign(&signed);
+
+        json!({
+            "credential_id": self.credential_id(),
+            "client_data_json": URL_SAFE_NO_PAD.encode(client_data),
+            "authenticator_data": URL_SAFE_NO_PAD.encode(auth_data),
+            "signature": URL_SAFE_NO_PAD.encode(signature.to_der().as_bytes()),
+        })
+    }
+}
+
+/// Register the authenticator's credential for the administrator
+async fn register_passkey(app: &Router, access_token: &str, authenticator: &SoftwareAuthenticator) {
+    let response = common::send_json(
+        app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/register/options",
+        Some(access_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    let options = &json["data"]["attributes"];
+    assert_eq!(options["rp"]["id"], RP_ID);
+    assert_eq!(options["pubKeyCredParams"][0]["alg"], -7);
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+
+    let response = common::send_json(
+        app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/register",
+        Some(access_token),
+        authenticator.register(&challenge),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "passkeys");
+    assert_eq!(
+        json["data"]["attributes"]["credentialId"],
+        authenticator.credential_id()
+    );
+}
+
+/// Challenge of a passkey login, optionally scoped to an email
+async fn login_challenge(app: &Router, email: Option<&str>) -> serde_json::Value {
+    let body = match email {
+        Some(email) => json!({ "email": email }),
+        None => json!({}),
+    };
+    let response = common::send_json(
+        app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login/options",
+        None,
+        body,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    common::json_body(response).await["data"]["attributes"].clone()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_passkey_registration_and_login() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    let (admin_id, access_token) = common::create_admin_with_permissions(&pool).await;
+    let email: String = sqlx::query_scalar("SELECT email FROM user_administrators WHERE id = $1")
+        .bind(admin_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+    let mut authenticator = SoftwareAuthenticator::new();
+    register_passkey(&app, &access_token, &authenticator).await;
+
+    // Login scoped to the administrator lists their credential
+    let options = login_challenge(&app, Some(&email)).await;
+    assert_eq!(
+        options["allowCredentials"][0]["id"],
+        authenticator.credential_id()
+    );
+    assert_eq!(options["userVerification"], "required");
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+
+    let assertion = authenticator.assert(&challenge, ORIGIN);
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        assertion.clone(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"]["type"], "auth-tokens");
+    let passkey_token = json["data"]["attributes"]["accessToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    // The issued token belongs to the administrator
+    let response = common::send_json(
+        &app,
+        "GET",
+        "/api/v1/admin/auth/webauthn/credentials",
+        Some(&passkey_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["data"].as_array().unwrap().len(), 1);
+    assert!(json["data"][0]["attributes"]["lastUsedAt"].is_string());
+    let passkey_id = json["data"][0]["id"].as_str().unwrap().to_string();
+
+    // The challenge is single-use
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        assertion,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Discoverable login without an email
+    let options = login_challenge(&app, None).await;
+    assert!(options["allowCredentials"].as_array().unwrap().is_empty());
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        authenticator.assert(&challenge, ORIGIN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = common::send_json(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/auth/webauthn/credentials/{}", passkey_id),
+        Some(&access_token),
+        json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let options = login_challenge(&app, None).await;
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        authenticator.assert(&challenge, ORIGIN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_passkey_login_rejects_tampered_assertions() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let app =
+        caxur::presentation::router::app(common::create_test_app_state(pool.clone())).unwrap();
+    let (_, access_token) = common::create_admin_with_permissions(&pool).await;
+
+    let mut authenticator = SoftwareAuthenticator::new();
+    register_passkey(&app, &access_token, &authenticator).await;
+
+    // Registering the same credential again is rejected
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/register/options",
+        Some(&access_token),
+        json!({}),
+    )
+    .await;
+    let json = common::json_body(response).await;
+    let options = &json["data"]["attributes"];
+    assert_eq!(
+        options["excludeCredentials"][0]["id"],
+        authenticator.credential_id()
+    );
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/register",
+        Some(&access_token),
+        authenticator.register(&challenge),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Assertions made for another origin are rejected
+    let options = login_challenge(&app, None).await;
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        authenticator.assert(&challenge, "https://phishing.example"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // A signature from a different key is rejected, the challenge stays usable
+    let mut impostor = SoftwareAuthenticator::new();
+    impostor.credential_id = authenticator.credential_id.clone();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        impostor.assert(&challenge, ORIGIN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // A counter that does not increase points to a cloned authenticator
+    authenticator.sign_count = 10;
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        authenticator.assert(&challenge, ORIGIN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    authenticator.sign_count = 5;
+    let options = login_challenge(&app, None).await;
+    let challenge = options["challenge"].as_str().unwrap().to_string();
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/login",
+        None,
+        authenticator.assert(&challenge, ORIGIN),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Client tokens cannot register passkeys
+    let user_token = common::generate_test_token(uuid::Uuid::new_v4());
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/auth/webauthn/register/options",
+        Some(&user_token),
+        json!({}),
+    )
+    .await;
+    assert_ne!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
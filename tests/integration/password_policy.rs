@@ -0,0 +1,210 @@
+use crate::common;
+
+use axum::http::StatusCode;
+use caxur::domain::notifications::Notification;
+use caxur::shared::validation::{PasswordPolicies, PasswordPolicy};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Policies with a breached list for users and stricter rules for administrators
+fn test_policies() -> PasswordPolicies {
+    let breached: HashSet<String> = ["password123".to_string()].into_iter().collect();
+
+    PasswordPolicies {
+        users: Arc::new(PasswordPolicy::default().with_breached_passwords(Arc::new(breached))),
+        administrators: Arc::new(PasswordPolicy {
+            min_length: 12,
+            require_uppercase: true,
+            require_digit: true,
+            ..PasswordPolicy::default()
+        }),
+    }
+}
+
+/// Assert a 422 response pointing at the password attribute, returning its detail
+async fn password_error(response: axum::response::Response) -> String {
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["source"]["pointer"],
+        "/data/attributes/password"
+    );
+    json["errors"][0]["detail"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_signup_applies_user_password_policy() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone()).with_password_policies(test_policies());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let signup = |password: &str| {
+        json!({
+            "username": "policyuser",
+            "email": "policy@example.com",
+            "password": password
+        })
+    };
+
+    let detail = password_error(
+        common::send_json(&app, "POST", "/api/v1/users", None, signup("short")).await,
+    )
+    .await;
+    assert_eq!(detail, "Password must be at least 8 characters");
+
+    let detail = password_error(
+        common::send_json(&app, "POST", "/api/v1/users", None, signup("PASSWORD123")).await,
+    )
+    .await;
+    assert!(detail.contains("data breach"));
+
+    let detail = password_error(
+        common::send_json(
+            &app,
+            "POST",
+            "/api/v1/users",
+            None,
+            signup("my-policyuser-pw"),
+        )
+        .await,
+    )
+    .await;
+    assert_eq!(
+        detail,
+        "Password must not contain your username or email address"
+    );
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/users",
+        None,
+        signup("correct horse battery"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_administrators_get_their_own_policy() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone()).with_password_policies(test_policies());
+    let app = caxur::presentation::router::app(state).unwrap();
+    let (_admin_id, token) = common::create_admin_with_permissions(&pool).await;
+
+    let create_admin = |password: &str| {
+        json!({
+            "firstName": "Strict",
+            "lastName": "Policy",
+            "email": "strict@example.com",
+            "password": password
+        })
+    };
+
+    // Fine for users, too weak for administrators
+    let detail = password_error(
+        common::send_json(
+            &app,
+            "POST",
+            "/api/v1/admin/administrators",
+            Some(&token),
+            create_admin("correct horse battery"),
+        )
+        .await,
+    )
+    .await;
+    assert_eq!(detail, "Password must contain an uppercase letter");
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/admin/administrators",
+        Some(&token),
+        create_admin("Correct horse battery 9"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_rejected_reset_password_keeps_token_usable() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app =
+        caxur::presentation::router::app(state.with_password_policies(test_policies())).unwrap();
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/users",
+        None,
+        json!({
+            "username": "resetter",
+            "email": "resetter@example.com",
+            "password": "correct horse battery"
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/forgot-password",
+        None,
+        json!({ "email": "resetter@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let token = notifications
+        .sent()
+        .into_iter()
+        .find_map(|notification| match notification {
+            Notification::PasswordReset { token, .. } => Some(token),
+            _ => None,
+        })
+        .expect("Expected a password reset notification");
+
+    let detail = password_error(
+        common::send_json(
+            &app,
+            "POST",
+            "/api/v1/auth/reset-password",
+            None,
+            json!({ "token": token, "password": "resetter2024" }),
+        )
+        .await,
+    )
+    .await;
+    assert_eq!(
+        detail,
+        "Password must not contain your username or email address"
+    );
+
+    let response = common::send_json(
+        &app,
+        "POST",
+        "/api/v1/auth/reset-password",
+        None,
+        json!({ "token": token, "password": "staple battery horse" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -0,0 +1,284 @@
+use crate::common;
+
+use axum::{Router, http::StatusCode};
+use caxur::domain::notifications::Notification;
+use serde_json::json;
+use serial_test::serial;
+
+/// Helper to attempt a login and return the status code
+async fn login_status(app: &Router, uri: &str, email: &str, password: &str) -> StatusCode {
+    common::login_response(app, uri, email, password)
+        .await
+        .status()
+}
+
+/// Tokens of every password reset notification sent so far, oldest first
+fn reset_tokens(notifications: &common::RecordingNotificationService) -> Vec<String> {
+    notifications
+        .sent()
+        .into_iter()
+        .filter_map(|notification| match notification {
+            Notification::PasswordReset { token, .. } => Some(token),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Token carried by the most recent password reset notification
+fn last_reset_token(notifications: &common::RecordingNotificationService) -> String {
+    reset_tokens(notifications)
+        .pop()
+        .expect("Expected a password reset notification")
+}
+
+#[tokio::test]
+#[serial]
+async fn test_password_reset_flow() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "forgetful@example.com").await;
+    let login_response = common::post_json(
+        &app,
+        "/api/v1/auth/login",
+        json!({ "email": "forgetful@example.com", "password": "password123" }),
+    )
+    .await;
+    let login_json = common::json_body(login_response).await;
+    let refresh_token = login_json["data"]["attributes"]["refreshToken"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/forgot-password",
+        json!({ "email": "forgetful@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["resetRequested"], true);
+
+    let token = last_reset_token(&notifications);
+    assert_eq!(notifications.sent()[0].recipient(), "forgetful@example.com");
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": token, "password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["passwordReset"], true);
+    assert_eq!(json["meta"]["revokedTokens"], 1);
+
+    // Existing sessions are gone and only the new password works
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/refresh",
+        json!({ "refresh_token": refresh_token }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        login_status(
+            &app,
+            "/api/v1/auth/login",
+            "forgetful@example.com",
+            "password123"
+        )
+        .await,
+        StatusCode::UNAUTHORIZED
+    );
+    assert_eq!(
+        login_status(
+            &app,
+            "/api/v1/auth/login",
+            "forgetful@example.com",
+            "newpassword123"
+        )
+        .await,
+        StatusCode::OK
+    );
+
+    // The token is single-use
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": token, "password": "anotherpassword" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_forgot_password_unknown_email_does_not_notify() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/forgot-password",
+        json!({ "email": "nobody@example.com" }),
+    )
+    .await;
+
+    // Same response as for an existing account
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = common::json_body(response).await;
+    assert_eq!(json["meta"]["resetRequested"], true);
+    assert!(notifications.sent().is_empty());
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_reset_password_rejects_invalid_and_expired_tokens() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": "not-a-token", "password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    common::register_user(&app, "expired@example.com").await;
+    common::post_json(
+        &app,
+        "/api/v1/auth/forgot-password",
+        json!({ "email": "expired@example.com" }),
+    )
+    .await;
+    let token = last_reset_token(&notifications);
+
+    sqlx::query("UPDATE one_time_tokens SET expires_at = NOW() - INTERVAL '1 minute'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": token, "password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let json = common::json_body(response).await;
+    assert_eq!(
+        json["errors"][0]["detail"],
+        "Invalid or expired reset token"
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_new_reset_request_invalidates_previous_token() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "twice@example.com").await;
+    for _ in 0..2 {
+        common::post_json(
+            &app,
+            "/api/v1/auth/forgot-password",
+            json!({ "email": "twice@example.com" }),
+        )
+        .await;
+    }
+
+    let tokens = reset_tokens(&notifications);
+    assert_eq!(tokens.len(), 2);
+    let first_token = &tokens[0];
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": first_token, "password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": last_reset_token(&notifications), "password": "newpassword123" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_password_reset_flow() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let (state, notifications) = common::create_test_app_state_with_notifications(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::create_admin(&pool, "admin_reset@example.com", "adminpassword").await;
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/admin/auth/forgot-password",
+        json!({ "email": "admin_reset@example.com" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let token = last_reset_token(&notifications);
+
+    // An administrator token cannot be redeemed on the client endpoint
+    let response = common::post_json(
+        &app,
+        "/api/v1/auth/reset-password",
+        json!({ "token": token, "password": "newadminpassword" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = common::post_json(
+        &app,
+        "/api/v1/admin/auth/reset-password",
+        json!({ "token": token, "password": "newadminpassword" }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+        login_status(
+            &app,
+            "/api/v1/admin/auth/login",
+            "admin_reset@example.com",
+            "newadminpassword"
+        )
+        .await,
+        StatusCode::OK
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
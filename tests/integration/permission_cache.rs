@@ -0,0 +1,174 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use serde_json::json;
+use serial_test::serial;
+use std::time::Duration;
+use tower::ServiceExt;
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: serde_json::Value,
+) -> StatusCode {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+async fn list_users(app: &Router, token: &str) -> StatusCode {
+    send(app, "GET", "/api/v1/admin/users", token, json!({})).await
+}
+
+#[tokio::test]
+#[serial]
+async fn test_detaching_a_permission_takes_effect_immediately() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, super_token) = common::create_admin_with_permissions(&pool).await;
+    let (_, role_id, token) = common::create_admin_with_role(&pool, &["users.read"]).await;
+
+    // Cached by the first request
+    assert_eq!(list_users(&app, &token).await, StatusCode::OK);
+
+    let status = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/roles/{}/permissions", role_id),
+        &super_token,
+        json!({ "permissions": ["users.read"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(list_users(&app, &token).await, StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_detaching_a_role_takes_effect_immediately() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, super_token) = common::create_admin_with_permissions(&pool).await;
+    let (admin_id, role_id, token) = common::create_admin_with_role(&pool, &["users.read"]).await;
+
+    assert_eq!(list_users(&app, &token).await, StatusCode::OK);
+
+    let status = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/administrators/{}/roles", admin_id),
+        &super_token,
+        json!({ "role_ids": [role_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    assert_eq!(list_users(&app, &token).await, StatusCode::FORBIDDEN);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_changes_made_elsewhere_arrive_through_notifications() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let listener = state.permission_cache.clone().listen(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, role_id, token) = common::create_admin_with_role(&pool, &["users.read"]).await;
+
+    // Give the listener time to subscribe before the change is made
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(list_users(&app, &token).await, StatusCode::OK);
+
+    // Another instance removes the permission
+    sqlx::query("DELETE FROM role_permissions WHERE role_id = $1")
+        .bind(role_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let mut status = StatusCode::OK;
+    for _ in 0..20 {
+        status = list_users(&app, &token).await;
+        if status == StatusCode::FORBIDDEN {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    listener.shutdown().await;
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_cache_stats_report_hit_ratio() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+
+    // One miss, then hits for the following requests
+    for _ in 0..3 {
+        assert_eq!(list_users(&app, &token).await, StatusCode::OK);
+    }
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/permissions/cache")
+                .header("authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let stats = &json["data"]["attributes"];
+
+    assert_eq!(stats["misses"], 1);
+    assert_eq!(stats["hits"], 3);
+    assert_eq!(stats["hitRatio"], 0.75);
+
+    common::cleanup_test_db(&pool).await;
+}
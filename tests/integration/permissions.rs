@@ -16,11 +16,14 @@ async fn test_list_permissions() {
     let state = common::create_test_app_state(pool.clone());
     let app = caxur::presentation::router::app(state).unwrap();
 
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+
     let response = app
         .oneshot(
             Request::builder()
                 .uri("/api/v1/admin/permissions")
                 .method("GET")
+                .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -39,10 +42,10 @@ async fn test_list_permissions() {
     assert!(!data.is_empty());
 
     // Check for some known permissions
-    let has_admin_management = data
+    let has_admin_read = data
         .iter()
-        .any(|p| p["attributes"]["name"] == "administrator_management");
-    assert!(has_admin_management);
+        .any(|p| p["attributes"]["name"] == "administrators.read");
+    assert!(has_admin_read);
 
     common::cleanup_test_db(&pool).await;
 }
@@ -34,6 +34,11 @@ async fn test_create_refresh_token() {
         user_id,
         user_type: "user".to_string(),
         token_hash: "test_hash_123".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
         expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
     };
 
@@ -62,6 +67,11 @@ async fn test_find_by_hash_existing() {
         user_id,
         user_type: "user".to_string(),
         token_hash: "find_me_hash".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
         expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
     };
 
@@ -110,6 +120,11 @@ async fn test_find_by_hash_expired() {
         user_id,
         user_type: "user".to_string(),
         token_hash: "expired_hash".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
         expires_at: OffsetDateTime::now_utc() - time::Duration::days(1), // Expired yesterday
     };
 
@@ -138,13 +153,33 @@ async fn test_delete_by_user_id() {
             user_id,
             user_type: "user".to_string(),
             token_hash: format!("hash_{}", i),
+            family_id: Uuid::new_v4(),
+            parent_id: None,
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
             expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
         };
         repo.create(new_token).await.unwrap();
     }
 
+    // An administrator may share the id, their tokens are kept
+    repo.create(NewRefreshToken {
+        user_id,
+        user_type: "admin".to_string(),
+        token_hash: "admin_hash".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
+        expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
+    })
+    .await
+    .unwrap();
+
     // Delete all tokens for this user
-    let result = repo.delete_by_user_id(user_id).await;
+    let result = repo.delete_by_user_id(user_id, "user").await;
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 3);
 
@@ -153,6 +188,7 @@ async fn test_delete_by_user_id() {
         let token = repo.find_by_hash(&format!("hash_{}", i)).await.unwrap();
         assert!(token.is_none());
     }
+    assert!(repo.find_by_hash("admin_hash").await.unwrap().is_some());
 
     common::cleanup_test_db(&pool).await;
 }
@@ -170,6 +206,11 @@ async fn test_delete_by_hash() {
         user_id,
         user_type: "user".to_string(),
         token_hash: "delete_me".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
         expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
     };
 
@@ -178,7 +219,7 @@ async fn test_delete_by_hash() {
     // Delete by hash
     let result = repo.delete_by_hash("delete_me").await;
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), true);
+    assert!(result.unwrap());
 
     // Verify it's deleted
     let token = repo.find_by_hash("delete_me").await.unwrap();
@@ -197,7 +238,7 @@ async fn test_delete_by_hash_nonexistent() {
 
     let result = repo.delete_by_hash("nonexistent").await;
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), false);
+    assert!(!result.unwrap());
 
     common::cleanup_test_db(&pool).await;
 }
@@ -217,6 +258,11 @@ async fn test_delete_expired() {
             user_id,
             user_type: "user".to_string(),
             token_hash: format!("expired_{}", i),
+            family_id: Uuid::new_v4(),
+            parent_id: None,
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
             expires_at: OffsetDateTime::now_utc() - time::Duration::days(1),
         };
         repo.create(new_token).await.unwrap();
@@ -227,6 +273,11 @@ async fn test_delete_expired() {
         user_id,
         user_type: "user".to_string(),
         token_hash: "valid_token".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
         expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
     };
     repo.create(new_token).await.unwrap();
@@ -242,3 +293,154 @@ async fn test_delete_expired() {
 
     common::cleanup_test_db(&pool).await;
 }
+
+#[tokio::test]
+#[serial]
+async fn test_mark_rotated() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let user_id = create_test_user(&pool).await;
+    let repo = PostgresRefreshTokenRepository::new(pool.clone());
+
+    let token = repo
+        .create(NewRefreshToken {
+            user_id,
+            user_type: "user".to_string(),
+            token_hash: "rotate_me".to_string(),
+            family_id: Uuid::new_v4(),
+            parent_id: None,
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
+            expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
+        })
+        .await
+        .unwrap();
+    assert!(token.rotated_at.is_none());
+
+    // First rotation succeeds, second one reports the token was already rotated
+    assert!(repo.mark_rotated(token.id).await.unwrap());
+    assert!(!repo.mark_rotated(token.id).await.unwrap());
+
+    // Rotated tokens are still found so reuse can be detected
+    let found = repo.find_by_hash("rotate_me").await.unwrap().unwrap();
+    assert!(found.rotated_at.is_some());
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_delete_by_family_id() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let user_id = create_test_user(&pool).await;
+    let repo = PostgresRefreshTokenRepository::new(pool.clone());
+    let family_id = Uuid::new_v4();
+
+    let parent = repo
+        .create(NewRefreshToken {
+            user_id,
+            user_type: "user".to_string(),
+            token_hash: "family_parent".to_string(),
+            family_id,
+            parent_id: None,
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
+            expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
+        })
+        .await
+        .unwrap();
+
+    let child = repo
+        .create(NewRefreshToken {
+            user_id,
+            user_type: "user".to_string(),
+            token_hash: "family_child".to_string(),
+            family_id,
+            parent_id: Some(parent.id),
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
+            expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
+        })
+        .await
+        .unwrap();
+    assert_eq!(child.family_id, family_id);
+    assert_eq!(child.parent_id, Some(parent.id));
+
+    // A token from another family is left alone
+    repo.create(NewRefreshToken {
+        user_id,
+        user_type: "user".to_string(),
+        token_hash: "other_family".to_string(),
+        family_id: Uuid::new_v4(),
+        parent_id: None,
+        user_agent: None,
+        ip_address: None,
+        device_name: None,
+        expires_at: OffsetDateTime::now_utc() + time::Duration::days(7),
+    })
+    .await
+    .unwrap();
+
+    let deleted = repo.delete_by_family_id(family_id).await.unwrap();
+    assert_eq!(deleted, 2);
+
+    assert!(repo.find_by_hash("family_child").await.unwrap().is_none());
+    assert!(repo.find_by_hash("other_family").await.unwrap().is_some());
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_maintenance_scheduler_purges_expired_tokens() {
+    use caxur::application::maintenance::purge_expired_tokens::PurgeExpiredRefreshTokensJob;
+    use caxur::infrastructure::maintenance::MaintenanceScheduler;
+    use std::sync::Arc;
+
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let user_id = create_test_user(&pool).await;
+    let repo = Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+
+    for (hash, expires_at) in [
+        (
+            "sweep_expired",
+            OffsetDateTime::now_utc() - time::Duration::hours(1),
+        ),
+        (
+            "sweep_valid",
+            OffsetDateTime::now_utc() + time::Duration::days(7),
+        ),
+    ] {
+        repo.create(NewRefreshToken {
+            user_id,
+            user_type: "user".to_string(),
+            token_hash: hash.to_string(),
+            family_id: Uuid::new_v4(),
+            parent_id: None,
+            user_agent: None,
+            ip_address: None,
+            device_name: None,
+            expires_at,
+        })
+        .await
+        .unwrap();
+    }
+
+    MaintenanceScheduler::new(std::time::Duration::from_secs(3600))
+        .with_job(Arc::new(PurgeExpiredRefreshTokensJob::new(repo.clone())))
+        .run_once()
+        .await;
+
+    assert!(repo.find_by_hash("sweep_expired").await.unwrap().is_none());
+    assert!(repo.find_by_hash("sweep_valid").await.unwrap().is_some());
+
+    common::cleanup_test_db(&pool).await;
+}
@@ -98,7 +98,7 @@ async fn test_get_role() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}", role_id))
+                .uri(format!("/api/v1/admin/roles/{}", role_id))
                 .method("GET")
                 .header("Authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -126,7 +126,7 @@ async fn test_get_role_not_found() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}", Uuid::new_v4()))
+                .uri(format!("/api/v1/admin/roles/{}", Uuid::new_v4()))
                 .method("GET")
                 .header("Authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -247,7 +247,7 @@ async fn test_update_role() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}", role_id))
+                .uri(format!("/api/v1/admin/roles/{}", role_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("Authorization", format!("Bearer {}", token))
@@ -314,7 +314,7 @@ async fn test_delete_role() {
         .clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}", role_id))
+                .uri(format!("/api/v1/admin/roles/{}", role_id))
                 .method("DELETE")
                 .header("Authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -374,14 +374,14 @@ async fn test_role_permissions() {
 
     // 1. Attach permissions
     let attach_request = json!({
-        "permissions": ["administrator_management", "role_management"]
+        "permissions": ["administrators.*", "roles.read"]
     });
 
     let attach_response = app
         .clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}/permissions", role_id))
+                .uri(format!("/api/v1/admin/roles/{}/permissions", role_id))
                 .method("POST")
                 .header("content-type", "application/json")
                 .header("Authorization", format!("Bearer {}", token))
@@ -405,7 +405,7 @@ async fn test_role_permissions() {
         .clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}/permissions", role_id))
+                .uri(format!("/api/v1/admin/roles/{}/permissions", role_id))
                 .method("GET")
                 .header("Authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -420,20 +420,20 @@ async fn test_role_permissions() {
         .await
         .unwrap();
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
-    let data = json["data"].as_array().unwrap();
+    let data = json["data"]["direct"].as_array().unwrap();
     assert_eq!(data.len(), 2);
     // Note: order is not guaranteed, but standard permissions checks
 
     // 3. Detach permissions
     let detach_request = json!({
-        "permissions": ["administrator_management"]
+        "permissions": ["administrators.*"]
     });
 
     let detach_response = app
         .clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}/permissions", role_id))
+                .uri(format!("/api/v1/admin/roles/{}/permissions", role_id))
                 .method("DELETE")
                 .header("content-type", "application/json")
                 .header("Authorization", format!("Bearer {}", token))
@@ -449,7 +449,7 @@ async fn test_role_permissions() {
     let get_response_2 = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/admin/roles/{}/permissions", role_id))
+                .uri(format!("/api/v1/admin/roles/{}/permissions", role_id))
                 .method("GET")
                 .header("Authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -462,9 +462,173 @@ async fn test_role_permissions() {
         .await
         .unwrap();
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
-    let data = json["data"].as_array().unwrap();
+    let data = json["data"]["direct"].as_array().unwrap();
     assert_eq!(data.len(), 1);
-    assert_eq!(data[0], "role_management");
+    assert_eq!(data[0], "roles.read");
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_attach_unknown_permission_is_rejected() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, role_id, _) = common::create_admin_with_role(&pool, &[]).await;
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+
+    for permission in ["users.fly", "administrator_management", "users..read"] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/v1/admin/roles/{}/permissions", role_id))
+                    .method("POST")
+                    .header("content-type", "application/json")
+                    .header("Authorization", format!("Bearer {}", token))
+                    .body(Body::from(
+                        json!({ "permissions": [permission] }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.status(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "{} was accepted",
+            permission
+        );
+    }
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM role_permissions WHERE role_id = $1")
+        .bind(role_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+async fn send(
+    app: &axum::Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("content-type", "application/json")
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or_default())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_role_inheritance() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
+    let (_, child_id, child_token) = common::create_admin_with_role(&pool, &["roles.read"]).await;
+    let (_, parent_id, _) = common::create_admin_with_role(&pool, &["users.read"]).await;
+    let (_, grandparent_id, _) = common::create_admin_with_role(&pool, &["roles.update"]).await;
+
+    // Cached before the parents are attached
+    let (status, _) = send(&app, "GET", "/api/v1/admin/users", &child_token, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    for (role_id, parent) in [(child_id, parent_id), (parent_id, grandparent_id)] {
+        let (status, _) = send(
+            &app,
+            "POST",
+            &format!("/api/v1/admin/roles/{}/parents", role_id),
+            &token,
+            json!({ "parent_ids": [parent] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, _) = send(&app, "GET", "/api/v1/admin/users", &child_token, json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(
+        &app,
+        "GET",
+        &format!("/api/v1/admin/roles/{}/permissions", child_id),
+        &token,
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["direct"], json!(["roles.read"]));
+    let inherited = body["data"]["inherited"].as_array().unwrap();
+    assert_eq!(inherited.len(), 2);
+    assert!(inherited.contains(&json!("roles.update")));
+
+    let (_, body) = send(
+        &app,
+        "GET",
+        &format!("/api/v1/admin/roles/{}", child_id),
+        &token,
+        json!({}),
+    )
+    .await;
+    assert_eq!(
+        body["data"]["attributes"]["parentIds"],
+        json!([parent_id.to_string()])
+    );
+
+    // Cycles are rejected, directly or through ancestors
+    for (role_id, parent) in [(grandparent_id, child_id), (child_id, child_id)] {
+        let (status, _) = send(
+            &app,
+            "POST",
+            &format!("/api/v1/admin/roles/{}/parents", role_id),
+            &token,
+            json!({ "parent_ids": [parent] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (status, _) = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/admin/roles/{}/parents", child_id),
+        &token,
+        json!({ "parent_ids": [parent_id] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&app, "GET", "/api/v1/admin/users", &child_token, json!({})).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
 
     common::cleanup_test_db(&pool).await;
 }
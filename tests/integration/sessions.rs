@@ -0,0 +1,512 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    extract::ConnectInfo,
+    http::{Request, StatusCode},
+};
+use serde_json::json;
+use serial_test::serial;
+use std::net::SocketAddr;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+/// Helper to login from a given device, returns (access_token, refresh_token)
+async fn login(
+    app: &Router,
+    uri: &str,
+    email: &str,
+    password: &str,
+    device_name: &str,
+) -> (String, String) {
+    let login_request = json!({
+        "email": email,
+        "password": password
+    });
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method("POST")
+                .header("content-type", "application/json")
+                .header("User-Agent", "caxur-tests/1.0")
+                .header("X-Device-Name", device_name)
+                .extension(ConnectInfo(SocketAddr::from(([203, 0, 113, 7], 4242))))
+                .body(Body::from(login_request.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = common::json_body(response).await;
+    (
+        json["data"]["attributes"]["accessToken"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+        json["data"]["attributes"]["refreshToken"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+    )
+}
+
+async fn send(app: &Router, method: &str, uri: &str, token: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("Authorization", format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+async fn refresh(app: &Router, refresh_token: &str) -> axum::response::Response {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/refresh")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    json!({ "refresh_token": refresh_token }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_list_sessions_captures_client_details() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "sessions@example.com").await;
+    let (access_token, _) = login(
+        &app,
+        "/api/v1/auth/login",
+        "sessions@example.com",
+        "password123",
+        "Work laptop",
+    )
+    .await;
+
+    let response = send(&app, "GET", "/api/v1/me/sessions", &access_token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = common::json_body(response).await;
+    let sessions = json["data"].as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(json["meta"]["total"], 1);
+    assert_eq!(sessions[0]["type"], "sessions");
+
+    let attributes = &sessions[0]["attributes"];
+    assert_eq!(attributes["userAgent"], "caxur-tests/1.0");
+    assert_eq!(attributes["ipAddress"], "203.0.113.7");
+    assert_eq!(attributes["deviceName"], "Work laptop");
+    assert!(attributes["lastUsedAt"].is_string());
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_trusted_proxy_client_ip() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone()).with_trust_proxy_headers(true);
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "proxied@example.com").await;
+
+    let login_via_proxy = |forwarded_for: &'static str| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .uri("/api/v1/auth/login")
+                        .method("POST")
+                        .header("content-type", "application/json")
+                        .header("X-Forwarded-For", forwarded_for)
+                        .extension(ConnectInfo(SocketAddr::from(([10, 0, 0, 2], 4242))))
+                        .body(Body::from(
+                            json!({
+                                "email": "proxied@example.com",
+                                "password": "password123"
+                            })
+                            .to_string(),
+                        ))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            common::json_body(response).await["data"]["attributes"]["accessToken"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+    };
+
+    // The left-most entries are client supplied, the proxy appends the real address
+    login_via_proxy("198.51.100.1, 192.0.2.44").await;
+    // Unparseable values fall back to the socket address
+    let access_token = login_via_proxy("not-an-ip").await;
+
+    let response = send(&app, "GET", "/api/v1/me/sessions", &access_token).await;
+    let json = common::json_body(response).await;
+    let mut ips: Vec<&str> = json["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|session| session["attributes"]["ipAddress"].as_str().unwrap())
+        .collect();
+    ips.sort();
+    assert_eq!(ips, vec!["10.0.0.2", "192.0.2.44"]);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_refresh_keeps_session() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "rotate@example.com").await;
+    let (access_token, refresh_token) = login(
+        &app,
+        "/api/v1/auth/login",
+        "rotate@example.com",
+        "password123",
+        "Phone",
+    )
+    .await;
+
+    let before =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &access_token).await).await;
+
+    let response = refresh(&app, &refresh_token).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let after =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &access_token).await).await;
+    let sessions = after["data"].as_array().unwrap();
+
+    // Rotation continues the same session and keeps its device name
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["id"], before["data"][0]["id"]);
+    assert_eq!(sessions[0]["attributes"]["deviceName"], "Phone");
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_revoke_session() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "revoke@example.com").await;
+    let (access_token, _) = login(
+        &app,
+        "/api/v1/auth/login",
+        "revoke@example.com",
+        "password123",
+        "Laptop",
+    )
+    .await;
+    let (_, tablet_refresh_token) = login(
+        &app,
+        "/api/v1/auth/login",
+        "revoke@example.com",
+        "password123",
+        "Tablet",
+    )
+    .await;
+
+    let json =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &access_token).await).await;
+    let sessions = json["data"].as_array().unwrap();
+    assert_eq!(sessions.len(), 2);
+
+    let tablet_session = sessions
+        .iter()
+        .find(|s| s["attributes"]["deviceName"] == "Tablet")
+        .unwrap();
+    let tablet_session_id = tablet_session["id"].as_str().unwrap();
+
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/me/sessions/{}", tablet_session_id),
+        &access_token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The revoked device can no longer refresh
+    let response = refresh(&app, &tablet_refresh_token).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let json =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &access_token).await).await;
+    let sessions = json["data"].as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["attributes"]["deviceName"], "Laptop");
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_revoke_session_of_another_user_not_found() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    common::register_user(&app, "victim@example.com").await;
+    common::register_user(&app, "attacker@example.com").await;
+    let (victim_token, _) = login(
+        &app,
+        "/api/v1/auth/login",
+        "victim@example.com",
+        "password123",
+        "Victim phone",
+    )
+    .await;
+    let (attacker_token, _) = login(
+        &app,
+        "/api/v1/auth/login",
+        "attacker@example.com",
+        "password123",
+        "Attacker laptop",
+    )
+    .await;
+
+    let json =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &victim_token).await).await;
+    let victim_session_id = json["data"][0]["id"].as_str().unwrap().to_string();
+
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("/api/v1/me/sessions/{}", victim_session_id),
+        &attacker_token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let json =
+        common::json_body(send(&app, "GET", "/api/v1/me/sessions", &victim_token).await).await;
+    assert_eq!(json["data"].as_array().unwrap().len(), 1);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sessions_require_authentication() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/me/sessions")
+                .method("GET")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_session_routes_require_administrator_management() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (admin_id, _) = common::create_admin_with_permissions(&pool).await;
+    let user_token = common::generate_test_token(Uuid::new_v4());
+    // A valid administrator token without any role
+    let unprivileged_token = common::generate_admin_token(Uuid::new_v4());
+
+    let uris = [
+        format!("/api/v1/admin/administrators/{}/sessions", admin_id),
+        format!("/api/v1/admin/users/{}/sessions", Uuid::new_v4()),
+    ];
+    for uri in &uris {
+        for token in [&user_token, &unprivileged_token] {
+            let response = send(&app, "GET", uri, token).await;
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+            let response = send(
+                &app,
+                "DELETE",
+                &format!("{}/{}", uri, Uuid::new_v4()),
+                token,
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        }
+    }
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_list_and_revoke_user_sessions() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+    let user_id = common::register_user(&app, "managed@example.com").await["data"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (_, refresh_token) = login(
+        &app,
+        "/api/v1/auth/login",
+        "managed@example.com",
+        "password123",
+        "Desktop",
+    )
+    .await;
+
+    let uri = format!("/api/v1/admin/users/{}/sessions", user_id);
+    let json = common::json_body(send(&app, "GET", &uri, &admin_token).await).await;
+    let sessions = json["data"].as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    let session_id = sessions[0]["id"].as_str().unwrap().to_string();
+
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("{}/{}", uri, session_id),
+        &admin_token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = refresh(&app, &refresh_token).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // Unknown session ids are reported as not found
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("{}/{}", uri, Uuid::new_v4()),
+        &admin_token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_sessions_for_self_and_other_administrators() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, manager_token) = common::create_admin_with_permissions(&pool).await;
+
+    let admin_id = common::create_admin(&pool, "admin_sessions@example.com", "adminpassword").await;
+
+    let (admin_access_token, _) = login(
+        &app,
+        "/api/v1/admin/auth/login",
+        "admin_sessions@example.com",
+        "adminpassword",
+        "Admin workstation",
+    )
+    .await;
+
+    // The administrator sees their own session
+    let json = common::json_body(
+        send(
+            &app,
+            "GET",
+            "/api/v1/admin/me/sessions",
+            &admin_access_token,
+        )
+        .await,
+    )
+    .await;
+    let sessions = json["data"].as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["attributes"]["deviceName"], "Admin workstation");
+    let session_id = sessions[0]["id"].as_str().unwrap().to_string();
+
+    // Another administrator can list and revoke it
+    let uri = format!("/api/v1/admin/administrators/{}/sessions", admin_id);
+    let json = common::json_body(send(&app, "GET", &uri, &manager_token).await).await;
+    assert_eq!(json["data"][0]["id"], session_id.as_str());
+
+    let response = send(
+        &app,
+        "DELETE",
+        &format!("{}/{}", uri, session_id),
+        &manager_token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let json = common::json_body(
+        send(
+            &app,
+            "GET",
+            "/api/v1/admin/me/sessions",
+            &admin_access_token,
+        )
+        .await,
+    )
+    .await;
+    assert!(json["data"].as_array().unwrap().is_empty());
+
+    common::cleanup_test_db(&pool).await;
+}
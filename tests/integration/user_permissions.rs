@@ -0,0 +1,200 @@
+use crate::common;
+
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode},
+    routing::get,
+};
+use caxur::presentation::extractors::{ClientUser, PermissionRequirement, UsersDelete};
+use caxur::shared::error::AppError;
+use serde_json::json;
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: serde_json::Value,
+) -> StatusCode {
+    app.clone()
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("authorization", format!("Bearer {}", token))
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_user_permission_grants_access_to_other_accounts() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, _, moderator_token) = common::create_user_with_role(&pool, &["users.update"]).await;
+    let (_, _, plain_token) = common::create_user_with_role(&pool, &[]).await;
+    let (target_id, _, _) = common::create_user_with_role(&pool, &[]).await;
+
+    let uri = format!("/api/v1/users/{}", target_id);
+    let update = json!({ "username": "renamed_by_moderator" });
+
+    assert_eq!(
+        send(&app, "PUT", &uri, &plain_token, update.clone()).await,
+        StatusCode::FORBIDDEN
+    );
+    assert_eq!(
+        send(&app, "PUT", &uri, &moderator_token, update).await,
+        StatusCode::OK
+    );
+    // users.update does not grant users.delete
+    assert_eq!(
+        send(&app, "DELETE", &uri, &moderator_token, json!({})).await,
+        StatusCode::FORBIDDEN
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_admin_assigns_user_roles() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+    let (_, user_role_id, _) = common::create_user_with_role(&pool, &["users.delete"]).await;
+    let (_, admin_role_id, _) = common::create_admin_with_role(&pool, &["users.read"]).await;
+    let (user_id, _, user_token) = common::create_user_with_role(&pool, &[]).await;
+    let (target_id, _, _) = common::create_user_with_role(&pool, &[]).await;
+
+    let roles_uri = format!("/api/v1/admin/users/{}/roles", user_id);
+    let target_uri = format!("/api/v1/users/{}", target_id);
+
+    // Cached before the role is attached
+    assert_eq!(
+        send(&app, "DELETE", &target_uri, &user_token, json!({})).await,
+        StatusCode::FORBIDDEN
+    );
+
+    assert_eq!(
+        send(
+            &app,
+            "POST",
+            &roles_uri,
+            &admin_token,
+            json!({ "role_ids": [admin_role_id] })
+        )
+        .await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(
+        send(
+            &app,
+            "POST",
+            &format!("/api/v1/admin/users/{}/roles", Uuid::new_v4()),
+            &admin_token,
+            json!({ "role_ids": [user_role_id] })
+        )
+        .await,
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        send(
+            &app,
+            "POST",
+            &roles_uri,
+            &admin_token,
+            json!({ "role_ids": [user_role_id] })
+        )
+        .await,
+        StatusCode::OK
+    );
+
+    assert_eq!(
+        send(&app, "DELETE", &target_uri, &user_token, json!({})).await,
+        StatusCode::OK
+    );
+
+    assert_eq!(
+        send(
+            &app,
+            "DELETE",
+            &roles_uri,
+            &admin_token,
+            json!({ "role_ids": [user_role_id] })
+        )
+        .await,
+        StatusCode::OK
+    );
+    let (other_id, _, _) = common::create_user_with_role(&pool, &[]).await;
+    assert_eq!(
+        send(
+            &app,
+            "DELETE",
+            &format!("/api/v1/users/{}", other_id),
+            &user_token,
+            json!({})
+        )
+        .await,
+        StatusCode::FORBIDDEN
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_client_user_permissions_gate_user_routes() {
+    let pool = setup_test_db_or_skip!();
+    common::cleanup_test_db(&pool).await;
+
+    let state = common::create_test_app_state(pool.clone());
+
+    let app = Router::new()
+        .route(
+            "/protected",
+            get(|user: ClientUser| async move {
+                if !user.permissions.allows_any(UsersDelete::PERMISSIONS) {
+                    return Err(AppError::Forbidden("Insufficient permissions".to_string()));
+                }
+                Ok("Success")
+            }),
+        )
+        .with_state(state);
+
+    let (_, _, granted) = common::create_user_with_role(&pool, &["users.*"]).await;
+    let (_, _, denied) = common::create_user_with_role(&pool, &["users.update"]).await;
+    let (_, admin_token) = common::create_admin_with_permissions(&pool).await;
+
+    assert_eq!(
+        send(&app, "GET", "/protected", &granted, json!({})).await,
+        StatusCode::OK
+    );
+    assert_eq!(
+        send(&app, "GET", "/protected", &denied, json!({})).await,
+        StatusCode::FORBIDDEN
+    );
+    // Administrator permissions don't carry over to user routes
+    assert_eq!(
+        send(&app, "GET", "/protected", &admin_token, json!({})).await,
+        StatusCode::FORBIDDEN
+    );
+
+    common::cleanup_test_db(&pool).await;
+}
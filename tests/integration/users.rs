@@ -129,18 +129,11 @@ async fn test_list_users() {
         .await
         .unwrap();
 
-    // Extract user ID to generate token
-    let body = axum::body::to_bytes(create_response.into_body(), usize::MAX)
-        .await
-        .unwrap();
-    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
-    let user_id = json["data"]["id"].as_str().unwrap();
-    let user_uuid = uuid::Uuid::parse_str(user_id).unwrap();
+    assert_eq!(create_response.status(), StatusCode::CREATED);
 
-    // Generate auth token
-    let token = common::generate_test_token(user_uuid);
+    let (_, token) = common::create_admin_with_permissions(&pool).await;
 
-    // List users with authentication
+    // List users as an administrator
     let response = app
         .oneshot(
             Request::builder()
@@ -204,7 +197,7 @@ async fn test_get_user() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user_id))
+                .uri(format!("/api/v1/users/{}", user_id))
                 .method("GET")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -261,7 +254,7 @@ async fn test_get_nonexistent_user() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", fake_id))
+                .uri(format!("/api/v1/users/{}", fake_id))
                 .method("GET")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -322,7 +315,7 @@ async fn test_update_user() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user_id))
+                .uri(format!("/api/v1/users/{}", user_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -379,7 +372,7 @@ async fn test_delete_user() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user_id))
+                .uri(format!("/api/v1/users/{}", user_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -465,7 +458,7 @@ async fn test_update_user_forbidden() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user2_id))
+                .uri(format!("/api/v1/users/{}", user2_id))
                 .method("PUT")
                 .header("content-type", "application/json")
                 .header("authorization", format!("Bearer {}", token))
@@ -547,7 +540,7 @@ async fn test_delete_user_forbidden() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user2_id))
+                .uri(format!("/api/v1/users/{}", user2_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -604,7 +597,7 @@ async fn test_delete_user_not_found() {
     app.clone()
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user_id))
+                .uri(format!("/api/v1/users/{}", user_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
@@ -617,7 +610,7 @@ async fn test_delete_user_not_found() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/v1/users/{}", user_id))
+                .uri(format!("/api/v1/users/{}", user_id))
                 .method("DELETE")
                 .header("authorization", format!("Bearer {}", token))
                 .body(Body::empty())
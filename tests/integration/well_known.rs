@@ -0,0 +1,99 @@
+use crate::common;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use caxur::domain::auth::{AuthService, Claims};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode};
+use serial_test::serial;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn get_json(app: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_jwks_verifies_issued_tokens() {
+    let pool = setup_test_db_or_skip!();
+
+    let state = common::create_test_app_state(pool);
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (status, json) = get_json(app, "/.well-known/jwks.json").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let keys = json["keys"].as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+
+    let key = &keys[0];
+    assert_eq!(key["kty"], "EC");
+    assert_eq!(key["crv"], "P-256");
+    assert_eq!(key["alg"], "ES256");
+    assert_eq!(key["use"], "sig");
+    assert!(!key["kid"].as_str().unwrap().is_empty());
+
+    // A verifier holding only the JWK can validate tokens
+    let decoding_key =
+        DecodingKey::from_ec_components(key["x"].as_str().unwrap(), key["y"].as_str().unwrap())
+            .unwrap();
+
+    let user_id = Uuid::new_v4();
+    let token = common::create_test_auth_service()
+        .generate_access_token(user_id, "user".to_string())
+        .unwrap();
+
+    assert_eq!(
+        jsonwebtoken::decode_header(&token).unwrap().kid.as_deref(),
+        key["kid"].as_str()
+    );
+
+    let mut validation = Validation::new(Algorithm::ES256);
+    validation.set_audience(&[caxur::infrastructure::auth::DEFAULT_CLIENT_AUDIENCE]);
+    let claims = decode::<Claims>(&token, &decoding_key, &validation)
+        .unwrap()
+        .claims;
+    assert_eq!(claims.sub, user_id.to_string());
+}
+
+#[tokio::test]
+#[serial]
+async fn test_openid_configuration() {
+    let pool = setup_test_db_or_skip!();
+
+    let state = common::create_test_app_state(pool);
+    let app = caxur::presentation::router::app(state).unwrap();
+
+    let (status, json) = get_json(app, "/.well-known/openid-configuration").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let issuer = json["issuer"].as_str().unwrap();
+    assert_eq!(
+        json["jwks_uri"],
+        format!("{}/.well-known/jwks.json", issuer)
+    );
+    assert_eq!(
+        json["introspection_endpoint"],
+        format!("{}/api/v1/oauth/introspect", issuer)
+    );
+    assert_eq!(
+        json["id_token_signing_alg_values_supported"],
+        serde_json::json!(["ES256"])
+    );
+    // Login and logout are not RFC 6749 / RFC 7009 endpoints, so neither they nor
+    // the grant types they would imply are advertised
+    assert!(json.get("token_endpoint").is_none());
+    assert!(json.get("revocation_endpoint").is_none());
+    assert!(json.get("grant_types_supported").is_none());
+}